@@ -1,6 +1,7 @@
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
+    encoding::ZcashConfig,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::{
         short_weierstrass::{Affine as SWAffine, Projective as SWProjective, SWCurveConfig},
@@ -62,6 +63,11 @@ impl SWCurveConfig for Config {
         GLVConfig::glv_mul_projective(*p, s)
     }
 
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1SWAffine) -> bool {
+        crate::Config::is_in_g1_assuming_on_curve(p)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G1SWAffine) -> G1SWAffine {
         // Using the effective cofactor.
@@ -72,6 +78,8 @@ impl SWCurveConfig for Config {
     }
 }
 
+impl ZcashConfig for Config {}
+
 impl GLVConfig for Config {
     const ENDO_COEFFS: &[Self::BaseField] = &[
         MontFp!("258664426012969093929703085429980814127835149614277183275038967946009968870203535512256352201271898244626862047231")
@@ -1,6 +1,7 @@
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
+    encoding::ZcashConfig,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective, SWCurveConfig},
@@ -71,6 +72,11 @@ impl SWCurveConfig for Config {
         Self::BaseField::zero()
     }
 
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        crate::Config::is_in_g2_assuming_on_curve(p)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G2Affine) -> G2Affine {
         // Based on Section 4.1 of https://eprint.iacr.org/2017/419.pdf
@@ -102,6 +108,8 @@ impl SWCurveConfig for Config {
     }
 }
 
+impl ZcashConfig for Config {}
+
 impl GLVConfig for Config {
     const ENDO_COEFFS: &'static[Self::BaseField] = &[
         Fq2::new(
@@ -10,6 +10,17 @@ test_group!(g2_glv; G2Projective; glv);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12377G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12377G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
 
+// The cofactor of G1 is even, while that of G2 has no prime factor below
+// 2^16, so that only batches of G2 points are checked with random linear
+// combinations.
+#[test]
+fn batch_subgroup_check_rounds() {
+    use ark_ec::short_weierstrass::batch_subgroup_check_rounds;
+
+    assert_eq!(batch_subgroup_check_rounds::<crate::g1::Config>(), None);
+    assert_eq!(batch_subgroup_check_rounds::<crate::g2::Config>(), Some(8));
+}
+
 #[cfg(test)]
 mod test {
     use ark_ec::{
@@ -57,3 +68,91 @@ mod test {
         wb_hash_arbitrary_string_to_curve::<crate::g2::Config>();
     }
 }
+
+// BLS12-377 points in the ZCash format of BLS12-381: the base field elements
+// are written in 48 big-endian bytes, whose three most significant bits,
+// unused by the 377-bit modulus, hold the flags.
+mod zcash_encoding {
+    use crate::{G1Projective, G2Projective};
+    use ark_ec::{
+        encoding::{Encoding, ZcashCompressed, ZcashConfig, ZcashUncompressed},
+        short_weierstrass::{Affine, Projective},
+        AffineRepr, CurveGroup, PrimeGroup,
+    };
+    use ark_ff::{AdditiveGroup, BigInteger, Field, PrimeField};
+    use ark_serialize::SerializationError;
+    use ark_std::{vec, vec::Vec};
+
+    /// Returns the big-endian encoding of `x`, from its highest to its lowest
+    /// coefficient.
+    fn big_endian<F: Field>(x: F) -> Vec<u8> {
+        let mut coeffs: Vec<_> = x.to_base_prime_field_elements().collect();
+        coeffs.reverse();
+        coeffs
+            .into_iter()
+            .flat_map(|c| c.into_bigint().to_bytes_be())
+            .collect()
+    }
+
+    fn zcash_round_trip<P: ZcashConfig>(size: usize) {
+        let mut points = vec![Affine::<P>::zero()];
+        let mut e = Projective::<P>::generator();
+        for _ in 0..100 {
+            points.push(e.into_affine());
+            e.double_in_place();
+            e += Projective::<P>::generator();
+        }
+        for point in points {
+            let mut compressed = vec![];
+            Encoding::<ZcashCompressed>::encode(&point, &mut compressed).unwrap();
+            let mut uncompressed = vec![];
+            Encoding::<ZcashUncompressed>::encode(&point, &mut uncompressed).unwrap();
+            assert_eq!(compressed.len(), size);
+            assert_eq!(uncompressed.len(), 2 * size);
+
+            // Flags: compressed, infinity, and whether `y` is the largest root.
+            let flags = compressed[0] >> 5;
+            assert_eq!(uncompressed[0] >> 5, flags & 0b010);
+            match point.xy() {
+                None => {
+                    assert_eq!(flags, 0b110);
+                    assert!(compressed[1..].iter().all(|b| *b == 0));
+                    assert!(uncompressed[1..].iter().all(|b| *b == 0));
+                },
+                Some((x, y)) => {
+                    assert_eq!(flags, 0b100 | u8::from(y > -y));
+                    let mut expected = big_endian(x);
+                    assert_eq!(compressed[1..], expected[1..]);
+                    assert_eq!(compressed[0] & 0x1f, expected[0]);
+                    expected.extend(big_endian(y));
+                    assert_eq!(uncompressed, expected);
+                },
+            }
+
+            assert_eq!(
+                <Affine<P> as Encoding<ZcashCompressed>>::decode(&compressed[..]).unwrap(),
+                point
+            );
+            assert_eq!(
+                <Affine<P> as Encoding<ZcashUncompressed>>::decode(&uncompressed[..]).unwrap(),
+                point
+            );
+
+            // Decoding with the other format's flags fails.
+            assert!(matches!(
+                <Affine<P> as Encoding<ZcashUncompressed>>::decode(&compressed.repeat(2)[..]),
+                Err(SerializationError::UnexpectedFlags)
+            ));
+        }
+    }
+
+    #[test]
+    fn g1_zcash_encoding() {
+        zcash_round_trip::<<G1Projective as CurveGroup>::Config>(48);
+    }
+
+    #[test]
+    fn g2_zcash_encoding() {
+        zcash_round_trip::<<G2Projective as CurveGroup>::Config>(96);
+    }
+}
@@ -1,6 +1,7 @@
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
+    encoding::{Eip2537Config, ZcashConfig},
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
@@ -9,12 +10,13 @@ use ark_ec::{
 };
 use ark_ff::{AdditiveGroup, BigInt, MontFp, PrimeField, Zero};
 use ark_serialize::{Compress, SerializationError};
-use ark_std::{ops::Neg, One};
+use ark_std::{ops::Neg, vec::*, One};
 
 use super::g1_swu_iso;
 use crate::{
     util::{
-        read_g1_compressed, read_g1_uncompressed, serialize_fq, EncodingFlags, G1_SERIALIZED_SIZE,
+        batch_decompress, read_g1_compressed, read_g1_compressed_x, read_g1_uncompressed,
+        serialize_fq, EncodingFlags, G1_SERIALIZED_SIZE,
     },
     Fq, Fr,
 };
@@ -112,6 +114,24 @@ impl SWCurveConfig for Config {
         Ok(p)
     }
 
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        if compress == Compress::Yes {
+            let xs = bytes
+                .chunks(G1_SERIALIZED_SIZE)
+                .map(read_g1_compressed_x)
+                .collect::<Result<Vec<_>, _>>()?;
+            batch_decompress(&xs)
+        } else {
+            bytes
+                .chunks(2 * G1_SERIALIZED_SIZE)
+                .map(read_g1_uncompressed)
+                .collect()
+        }
+    }
+
     fn serialize_with_mode<W: ark_serialize::Write>(
         item: &Affine<Self>,
         mut writer: W,
@@ -155,6 +175,10 @@ impl SWCurveConfig for Config {
     }
 }
 
+impl ZcashConfig for Config {}
+
+impl Eip2537Config for Config {}
+
 impl GLVConfig for Config {
     const ENDO_COEFFS: &'static[Self::BaseField] = &[
         MontFp!("793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350")
@@ -1,8 +1,9 @@
-use ark_std::ops::Neg;
+use ark_std::{ops::Neg, vec::*};
 
 use ark_ec::{
     bls12,
     bls12::Bls12Config,
+    encoding::{Eip2537Config, ZcashConfig},
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
@@ -17,7 +18,7 @@ use super::{
     util::{serialize_fq, EncodingFlags, G2_SERIALIZED_SIZE},
 };
 use crate::{
-    util::{read_g2_compressed, read_g2_uncompressed},
+    util::{batch_decompress, read_g2_compressed, read_g2_compressed_x, read_g2_uncompressed},
     *,
 };
 
@@ -139,6 +140,24 @@ impl SWCurveConfig for Config {
         Ok(p)
     }
 
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        if compress == Compress::Yes {
+            let xs = bytes
+                .chunks(G2_SERIALIZED_SIZE)
+                .map(read_g2_compressed_x)
+                .collect::<Result<Vec<_>, _>>()?;
+            batch_decompress(&xs)
+        } else {
+            bytes
+                .chunks(2 * G2_SERIALIZED_SIZE)
+                .map(read_g2_uncompressed)
+                .collect()
+        }
+    }
+
     fn serialize_with_mode<W: ark_serialize::Write>(
         item: &Affine<Self>,
         mut writer: W,
@@ -191,6 +210,10 @@ impl SWCurveConfig for Config {
     }
 }
 
+impl ZcashConfig for Config {}
+
+impl Eip2537Config for Config {}
+
 impl GLVConfig for Config {
     const ENDO_COEFFS: &'static[Self::BaseField] = &[
         Fq2::new(
@@ -14,6 +14,7 @@ test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_381>; msm);
 test_pairing!(pairing; crate::Bls12_381);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12381G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12381G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
+test_h2c!(encode; sw; g1_encode; "./src/curves/tests"; "BLS12381G1"; crate::g1::Config; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::wb::WBMap<crate::g1::Config>; "SSWU"; ark_algebra_test_templates::Sha256, 128; "SHA-256");
 
 #[test]
 fn test_g1_endomorphism_beta() {
@@ -121,3 +122,132 @@ fn g2_uncompressed_valid_test_vectors() {
     let bytes: &'static [u8] = include_bytes!("g2_uncompressed_valid_test_vectors.dat");
     test_vectors!(G2Projective, G2Affine, Compress::No, bytes);
 }
+
+mod encoding {
+    use crate::{G1Affine, G1Projective, G2Affine, G2Projective};
+    use ark_ec::{
+        encoding::{Eip2537, Encoding, ZcashCompressed, ZcashUncompressed},
+        AffineRepr, PrimeGroup,
+    };
+    use ark_serialize::SerializationError;
+    use ark_std::{vec, vec::Vec, Zero};
+
+    // The same vectors as `test_vectors!`, since `CanonicalSerialize` encodes
+    // points of this curve in the ZCash format: the first 1000 multiples of the
+    // generator.
+    macro_rules! zcash_test_vectors {
+        ($projective:ident, $affine:ident, $format:ident, $expected:ident) => {
+            let mut e = $projective::zero();
+            let mut expected = $expected;
+            for _ in 0..1000 {
+                let e_affine = $affine::from(e);
+                let mut encoded = vec![];
+                Encoding::<$format>::encode(&e_affine, &mut encoded).unwrap();
+                assert_eq!(encoded.len(), Encoding::<$format>::encoded_size(&e_affine));
+                assert_eq!(&encoded[..], &expected[..encoded.len()]);
+
+                let decoded = <$affine as Encoding<$format>>::decode(&expected[..]).unwrap();
+                assert_eq!(e_affine, decoded);
+                expected = &expected[encoded.len()..];
+
+                e += &$projective::generator();
+            }
+            assert!(expected.is_empty());
+        };
+    }
+
+    #[test]
+    fn g1_zcash_compressed_test_vectors() {
+        let bytes: &'static [u8] = include_bytes!("g1_compressed_valid_test_vectors.dat");
+        zcash_test_vectors!(G1Projective, G1Affine, ZcashCompressed, bytes);
+    }
+
+    #[test]
+    fn g1_zcash_uncompressed_test_vectors() {
+        let bytes: &'static [u8] = include_bytes!("g1_uncompressed_valid_test_vectors.dat");
+        zcash_test_vectors!(G1Projective, G1Affine, ZcashUncompressed, bytes);
+    }
+
+    #[test]
+    fn g2_zcash_compressed_test_vectors() {
+        let bytes: &'static [u8] = include_bytes!("g2_compressed_valid_test_vectors.dat");
+        zcash_test_vectors!(G2Projective, G2Affine, ZcashCompressed, bytes);
+    }
+
+    #[test]
+    fn g2_zcash_uncompressed_test_vectors() {
+        let bytes: &'static [u8] = include_bytes!("g2_uncompressed_valid_test_vectors.dat");
+        zcash_test_vectors!(G2Projective, G2Affine, ZcashUncompressed, bytes);
+    }
+
+    #[test]
+    fn zcash_rejects_invalid_flags() {
+        let mut bytes = vec![];
+        Encoding::<ZcashCompressed>::encode(&G1Affine::generator(), &mut bytes).unwrap();
+
+        // Missing compression flag.
+        let mut invalid = bytes.clone();
+        invalid[0] &= 0x7f;
+        assert!(matches!(
+            <G1Affine as Encoding<ZcashCompressed>>::decode(&invalid[..]),
+            Err(SerializationError::UnexpectedFlags)
+        ));
+
+        // Infinity with a non-zero `x`.
+        let mut invalid = bytes.clone();
+        invalid[0] |= 0x40;
+        invalid[0] &= 0xdf;
+        assert!(<G1Affine as Encoding<ZcashCompressed>>::decode(&invalid[..]).is_err());
+
+        // `x` larger than the modulus.
+        let invalid = [0x9f; 48];
+        assert!(<G1Affine as Encoding<ZcashCompressed>>::decode(&invalid[..]).is_err());
+    }
+
+    fn eip2537_test_vector<P: Encoding<Eip2537> + PartialEq + core::fmt::Debug>(
+        point: P,
+        coordinates: &[&str],
+    ) {
+        let expected: Vec<u8> = coordinates
+            .iter()
+            .flat_map(|c| {
+                let mut bytes = vec![0u8; 16];
+                bytes.extend(hex::decode(c).unwrap());
+                bytes
+            })
+            .collect();
+        let mut encoded = vec![];
+        point.encode(&mut encoded).unwrap();
+        assert_eq!(encoded, expected);
+        assert_eq!(P::decode(&expected[..]).unwrap(), point);
+    }
+
+    // Generators as listed in EIP-2537.
+    #[test]
+    fn eip2537_test_vectors() {
+        eip2537_test_vector(G1Affine::generator(), &[
+            "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+            "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+        ]);
+        eip2537_test_vector(G2Affine::generator(), &[
+            "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+            "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+            "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+            "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+        ]);
+
+        let zero = vec![0u8; 128];
+        let mut encoded = vec![];
+        Encoding::<Eip2537>::encode(&G1Affine::zero(), &mut encoded).unwrap();
+        assert_eq!(encoded, zero);
+        assert!(<G1Affine as Encoding<Eip2537>>::decode(&zero[..])
+            .unwrap()
+            .is_zero());
+
+        // Non-zero padding is rejected.
+        let mut invalid = vec![];
+        Encoding::<Eip2537>::encode(&G1Affine::generator(), &mut invalid).unwrap();
+        invalid[0] = 1;
+        assert!(<G1Affine as Encoding<Eip2537>>::decode(&invalid[..]).is_err());
+    }
+}
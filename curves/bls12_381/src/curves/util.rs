@@ -1,6 +1,10 @@
-use ark_ec::{short_weierstrass::Affine, AffineRepr};
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
 use ark_ff::{BigInteger384, PrimeField};
 use ark_serialize::SerializationError;
+use ark_std::vec::*;
 
 use crate::{g1::Config as G1Config, g2::Config as G2Config, Fq, Fq2, G1Affine, G2Affine};
 
@@ -101,8 +105,22 @@ fn read_bytes_with_offset(bytes: &[u8], offset: usize, mask: bool) -> [u8; G1_SE
 }
 
 pub(crate) fn read_g1_compressed<R: ark_serialize::Read>(
-    mut reader: R,
+    reader: R,
 ) -> Result<Affine<G1Config>, ark_serialize::SerializationError> {
+    match read_g1_compressed_x(reader)? {
+        Some((x, greatest)) => {
+            G1Affine::get_point_from_x_unchecked(x, greatest).ok_or(SerializationError::InvalidData)
+        },
+        None => Ok(G1Affine::zero()),
+    }
+}
+
+/// Reads the x-coordinate of a compressed G1 point and whether its
+/// y-coordinate is the lexicographically largest, or `None` for the point at
+/// infinity.
+pub(crate) fn read_g1_compressed_x<R: ark_serialize::Read>(
+    mut reader: R,
+) -> Result<Option<(Fq, bool)>, ark_serialize::SerializationError> {
     let mut bytes = [0u8; G1_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -126,14 +144,11 @@ pub(crate) fn read_g1_compressed<R: ark_serialize::Read>(
             return Err(SerializationError::InvalidData);
         }
 
-        return Ok(G1Affine::zero());
+        return Ok(None);
     }
 
     let x = deserialize_fq(x_bytes).ok_or(SerializationError::InvalidData)?;
-    let p = G1Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
-        .ok_or(SerializationError::InvalidData)?;
-
-    Ok(p)
+    Ok(Some((x, flags.is_lexographically_largest)))
 }
 
 pub(crate) fn read_g1_uncompressed<R: ark_serialize::Read>(
@@ -172,8 +187,22 @@ pub(crate) fn read_g1_uncompressed<R: ark_serialize::Read>(
 }
 
 pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
-    mut reader: R,
+    reader: R,
 ) -> Result<Affine<G2Config>, ark_serialize::SerializationError> {
+    match read_g2_compressed_x(reader)? {
+        Some((x, greatest)) => {
+            G2Affine::get_point_from_x_unchecked(x, greatest).ok_or(SerializationError::InvalidData)
+        },
+        None => Ok(G2Affine::zero()),
+    }
+}
+
+/// Reads the x-coordinate of a compressed G2 point and whether its
+/// y-coordinate is the lexicographically largest, or `None` for the point at
+/// infinity.
+pub(crate) fn read_g2_compressed_x<R: ark_serialize::Read>(
+    mut reader: R,
+) -> Result<Option<(Fq2, bool)>, ark_serialize::SerializationError> {
     let mut bytes = [0u8; G2_SERIALIZED_SIZE];
     reader
         .read_exact(&mut bytes)
@@ -194,7 +223,7 @@ pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
         if xc1_bytes != [0u8; 48] || xc0_bytes != [0u8; 48] {
             return Err(SerializationError::InvalidData);
         }
-        return Ok(G2Affine::zero());
+        return Ok(None);
     }
 
     // Attempt to obtain the x-coordinate
@@ -202,10 +231,7 @@ pub(crate) fn read_g2_compressed<R: ark_serialize::Read>(
     let xc0 = deserialize_fq(xc0_bytes).ok_or(SerializationError::InvalidData)?;
     let x = Fq2::new(xc0, xc1);
 
-    let p = G2Affine::get_point_from_x_unchecked(x, flags.is_lexographically_largest)
-        .ok_or(SerializationError::InvalidData)?;
-
-    Ok(p)
+    Ok(Some((x, flags.is_lexographically_largest)))
 }
 
 pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
@@ -256,3 +282,22 @@ pub(crate) fn read_g2_uncompressed<R: ark_serialize::Read>(
 
     Ok(p)
 }
+
+/// Decompresses the points read by [`read_g1_compressed_x`] or
+/// [`read_g2_compressed_x`] at once, with
+/// [`Affine::batch_get_points_from_x_unchecked`].
+pub(crate) fn batch_decompress<P: SWCurveConfig>(
+    xs: &[Option<(P::BaseField, bool)>],
+) -> Result<Vec<Affine<P>>, SerializationError> {
+    let finite: Vec<_> = xs.iter().flatten().copied().collect();
+    let mut points = Affine::batch_get_points_from_x_unchecked(&finite).into_iter();
+    xs.iter()
+        .map(|x| match x {
+            Some(_) => points
+                .next()
+                .flatten()
+                .ok_or(SerializationError::InvalidData),
+            None => Ok(Affine::identity()),
+        })
+        .collect()
+}
@@ -1,5 +1,7 @@
 use ark_ec::{
     bn,
+    encoding::Eip196Config,
+    hashing::curve_maps::svdw::SvdwConfig,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
@@ -62,6 +64,8 @@ impl SWCurveConfig for Config {
     }
 }
 
+impl Eip196Config for Config {}
+
 impl GLVConfig for Config {
     const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
         "21888242871839275220042445260109153167277707414472061641714758635765020556616"
@@ -89,6 +93,27 @@ impl GLVConfig for Config {
     }
 }
 
+// Parameters from [RFC 9380, section 6.6.1](https://datatracker.ietf.org/doc/html/rfc9380#section-6.6.1),
+// with `Z` found by `find_z_svdw`.
+impl SvdwConfig for Config {
+    /// Z = 1
+    const Z: Fq = Fq::ONE;
+
+    /// C1 = g(Z) = 4
+    const C1: Fq = MontFp!("4");
+
+    /// C2 = -Z / 2
+    const C2: Fq =
+        MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291");
+
+    /// C3 = sqrt(-g(Z) * 3 * Z^2), with sgn0(C3) = 0
+    const C3: Fq = MontFp!("8815841940592487685674414971303048083897117035520822607866");
+
+    /// C4 = -4 * g(Z) / (3 * Z^2)
+    const C4: Fq =
+        MontFp!("7296080957279758407415468581752425029565437052432607887563012631548408736189");
+}
+
 /// G1_GENERATOR_X = 1
 pub const G1_GENERATOR_X: Fq = Fq::ONE;
 
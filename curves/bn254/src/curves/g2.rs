@@ -1,5 +1,7 @@
-use ark_ec::AffineRepr;
 use ark_ec::{
+    bn::BnConfig,
+    encoding::Eip196Config,
+    hashing::curve_maps::svdw::SvdwConfig,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
@@ -58,16 +60,17 @@ impl SWCurveConfig for Config {
     }
 
     fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
-        // Subgroup check from section 4.3 of https://eprint.iacr.org/2022/352.pdf.
-        //
-        // Checks that [p]P = [6X^2]P
+        crate::Config::is_in_g2_assuming_on_curve(point)
+    }
 
-        let x_times_point = point.mul_bigint(SIX_X_SQUARED);
-        let p_times_point = p_power_endomorphism(point);
-        x_times_point.eq(&p_times_point)
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        crate::Config::clear_cofactor_g2(p)
     }
 }
 
+impl Eip196Config for Config {}
+
 impl GLVConfig for Config {
     const ENDO_COEFFS: &'static [Self::BaseField] = &[Fq2::new(
         MontFp!("21888242871839275220042445260109153167277707414472061641714758635765020556616"),
@@ -97,6 +100,37 @@ impl GLVConfig for Config {
     }
 }
 
+// Parameters from [RFC 9380, section 6.6.1](https://datatracker.ietf.org/doc/html/rfc9380#section-6.6.1),
+// with `Z` found by `find_z_svdw`.
+impl SvdwConfig for Config {
+    /// Z = 1
+    const Z: Fq2 = Fq2::ONE;
+
+    /// C1 = g(Z) = 1 + COEFF_B
+    const C1: Fq2 = Fq2::new(
+        MontFp!("19485874751759354771024239261021720505790618469301721065564631296452457478374"),
+        MontFp!("266929791119991161246907387137283842545076965332900288569378510910307636690"),
+    );
+
+    /// C2 = -Z / 2
+    const C2: Fq2 = Fq2::new(
+        MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291"),
+        Fq::ZERO,
+    );
+
+    /// C3 = sqrt(-g(Z) * 3 * Z^2), with sgn0(C3) = 0
+    const C3: Fq2 = Fq2::new(
+        MontFp!("18992192239972082890849143911285057164064277369389217330423471574879236301292"),
+        MontFp!("21819008332247140148575583693947636719449476128975323941588917397607662637108"),
+    );
+
+    /// C4 = -4 * g(Z) / (3 * Z^2)
+    const C4: Fq2 = Fq2::new(
+        MontFp!("10499238450719652342378357227399831140106360636427411350395554762472100376473"),
+        MontFp!("6940174569119770192419592065569379906172001098655407502803841283667998553941"),
+    );
+}
+
 pub const G2_GENERATOR_X: Fq2 = Fq2::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = Fq2::new(G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
@@ -120,55 +154,13 @@ pub const G2_GENERATOR_Y_C0: Fq =
 pub const G2_GENERATOR_Y_C1: Fq =
     MontFp!("4082367875863433681332203403145435568316851327593401208105741076214120093531");
 
-// PSI_X = (u+9)^((p-1)/3) = TWIST_MUL_BY_Q_X
-const P_POWER_ENDOMORPHISM_COEFF_0: Fq2 = Fq2::new(
-    MontFp!("21575463638280843010398324269430826099269044274347216827212613867836435027261"),
-    MontFp!("10307601595873709700152284273816112264069230130616436755625194854815875713954"),
-);
-
-// PSI_Y = (u+9)^((p-1)/2) = TWIST_MUL_BY_Q_Y
-const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
-    MontFp!("2821565182194536844548159561693502659359617185244120367078079554186484126554"),
-    MontFp!("3505843767911556378687030309984248845540243509899259641013678093033130930403"),
-);
-
-// Integer representation of 6x^2 = t - 1
-const SIX_X_SQUARED: [u64; 2] = [17887900258952609094, 8020209761171036667];
-
-/// psi(P) is the untwist-Frobenius-twist endomorphism on E'(Fq2)
-fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
-    // Maps (x,y) -> (x^p * (u+9)^((p-1)/3), y^p * (u+9)^((p-1)/2))
-
-    let mut res = *p;
-    res.x.frobenius_map_in_place(1);
-    res.y.frobenius_map_in_place(1);
-
-    res.x *= P_POWER_ENDOMORPHISM_COEFF_0;
-    res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
-
-    res
-}
-
 #[cfg(test)]
 mod test {
 
     use super::*;
     use crate::g2;
-    use ark_std::{rand::Rng, UniformRand};
-
-    fn sample_unchecked() -> Affine<g2::Config> {
-        let mut rng = ark_std::test_rng();
-        loop {
-            let x1 = Fq::rand(&mut rng);
-            let x2 = Fq::rand(&mut rng);
-            let greatest = rng.gen();
-            let x = Fq2::new(x1, x2);
-
-            if let Some(p) = Affine::get_point_from_x_unchecked(x, greatest) {
-                return p;
-            }
-        }
-    }
+    use ark_algebra_test_templates::cofactor;
+    use ark_ec::AffineRepr;
 
     fn naive_is_in_subgroup_assuming_on_curve(p: &Affine<g2::Config>) -> bool {
         <g2::Config as SWCurveConfig>::mul_affine(
@@ -182,7 +174,7 @@ mod test {
     fn test_is_in_subgroup_assuming_on_curve() {
         const SAMPLES: usize = 100;
         for _ in 0..SAMPLES {
-            let p: Affine<g2::Config> = sample_unchecked();
+            let p: Affine<g2::Config> = cofactor::sample_unchecked();
             assert!(p.is_on_curve());
 
             assert_eq!(
@@ -194,4 +186,21 @@ mod test {
             assert!(cleared.is_in_correct_subgroup_assuming_on_curve());
         }
     }
+
+    #[test]
+    fn test_cofactor_clearing() {
+        // The multiple of the cofactor by which the endomorphism-based
+        // cofactor clearing multiplies.
+        let h_eff: &'static [u64] = &[
+            0x211b707a3206c1a3,
+            0xa429874c0aeac6a1,
+            0x29d14f3f70f8afb4,
+            0x8d79b3f773024740,
+            0x4517bf2834b384f3,
+            0x052d4015a2223c4d,
+            0x489cc347d5abc08e,
+            0x0925c4b8763cbf9c,
+        ];
+        cofactor::cofactor_clearing::<g2::Config>(h_eff);
+    }
 }
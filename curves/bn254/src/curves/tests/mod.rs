@@ -0,0 +1,69 @@
+use ark_algebra_test_templates::*;
+use ark_ff::fields::Field;
+
+use crate::{Bn254, G1Projective, G2Projective};
+
+test_group!(g1; G1Projective; sw);
+test_group!(g2; G2Projective; sw);
+test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bn254>; msm);
+test_pairing!(pairing; crate::Bn254);
+test_group!(g1_glv; G1Projective; glv);
+test_group!(g2_glv; G2Projective; glv);
+test_h2c!(g1_h2c; "./src/curves/tests"; "BN254G1"; crate::g1::Config; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::svdw::SvdwMap<crate::g1::Config>; "SVDW");
+test_h2c!(g2_h2c; "./src/curves/tests"; "BN254G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2; ark_ec::hashing::curve_maps::svdw::SvdwMap<crate::g2::Config>; "SVDW");
+
+// G1 has cofactor one, and the smallest prime factor of the cofactor of G2,
+// 10069, has 13 bits, so that batches of G2 points are checked with 10 random
+// linear combinations.
+#[test]
+fn batch_subgroup_check_rounds() {
+    use ark_ec::short_weierstrass::batch_subgroup_check_rounds;
+
+    assert_eq!(batch_subgroup_check_rounds::<crate::g1::Config>(), None);
+    assert_eq!(batch_subgroup_check_rounds::<crate::g2::Config>(), Some(10));
+}
+
+// The generators, as encoded in the EIP-196 and EIP-197 precompiles.
+#[test]
+fn eip196_test_vectors() {
+    use crate::{G1Affine, G2Affine};
+    use ark_ec::{
+        encoding::{Eip196, Encoding},
+        AffineRepr,
+    };
+    use ark_std::vec;
+
+    let g1 = hex::decode(concat!(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+    ))
+    .unwrap();
+    let g2 = hex::decode(concat!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    ))
+    .unwrap();
+
+    let mut encoded = vec![];
+    Encoding::<Eip196>::encode(&G1Affine::generator(), &mut encoded).unwrap();
+    assert_eq!(encoded, g1);
+    assert_eq!(
+        <G1Affine as Encoding<Eip196>>::decode(&g1[..]).unwrap(),
+        G1Affine::generator()
+    );
+
+    let mut encoded = vec![];
+    Encoding::<Eip196>::encode(&G2Affine::generator(), &mut encoded).unwrap();
+    assert_eq!(encoded, g2);
+    assert_eq!(
+        <G2Affine as Encoding<Eip196>>::decode(&g2[..]).unwrap(),
+        G2Affine::generator()
+    );
+
+    let zero = vec![0u8; 64];
+    assert!(<G1Affine as Encoding<Eip196>>::decode(&zero[..])
+        .unwrap()
+        .is_zero());
+}
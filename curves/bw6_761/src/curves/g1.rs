@@ -1,4 +1,5 @@
 use ark_ec::{
+    bw6::BW6Config,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{Affine, Projective},
@@ -54,6 +55,16 @@ impl SWCurveConfig for Config {
         use ark_ff::Zero;
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        crate::Config::is_in_g1_assuming_on_curve(p)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        crate::Config::clear_cofactor_g1(p)
+    }
 }
 
 impl GLVConfig for Config {
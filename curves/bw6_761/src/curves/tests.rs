@@ -8,3 +8,33 @@ test_group!(pairing_output; ark_ec::pairing::PairingOutput<BW6_761>; msm);
 test_pairing!(pairing; crate::BW6_761);
 test_group!(g1_glv; G1Projective; glv);
 test_group!(g2_glv; G2Projective; glv);
+
+#[test]
+fn test_g1_cofactor_clearing() {
+    cofactor::cofactor_clearing::<crate::g1::Config>(&[
+        0xc21a7fffffffff84,
+        0x75db6a256fffffc4,
+        0x03de2d285bcf6436,
+        0x09e7fb6f3a17c02c,
+        0x048921a6aa874ce9,
+        0x22307fde5ad0866a,
+        0xb3614d475afc330a,
+        0xd3c5cd47279693dd,
+        0x184ac835122297cb,
+    ]);
+}
+
+#[test]
+fn test_g2_cofactor_clearing() {
+    cofactor::cofactor_clearing::<crate::g2::Config>(&[
+        0xc21a7fffffffff8b,
+        0xadfe1cf58fffffc4,
+        0x0862a6333a6df032,
+        0x09e7fb6f3a17c02b,
+        0x048921a6aa874ce9,
+        0x22307fde5ad0866a,
+        0xb3614d475afc330a,
+        0xd3c5cd47279693dd,
+        0x184ac835122297cb,
+    ]);
+}
@@ -1,4 +1,5 @@
 use ark_ec::{
+    bw6::BW6Config,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     short_weierstrass::{Affine, Projective},
 };
@@ -53,6 +54,16 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_elem: Self::BaseField) -> Self::BaseField {
         Self::BaseField::ZERO
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        crate::Config::is_in_g1_assuming_on_curve(p)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        crate::Config::clear_cofactor_g1(p)
+    }
 }
 
 /// G1_GENERATOR_X =
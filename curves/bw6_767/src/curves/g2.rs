@@ -1,4 +1,5 @@
 use ark_ec::{
+    bw6::BW6Config,
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     short_weierstrass::{Affine, Projective},
 };
@@ -53,6 +54,16 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_elem: Self::BaseField) -> Self::BaseField {
         Self::BaseField::ZERO
     }
+
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        crate::Config::is_in_g2_assuming_on_curve(p)
+    }
+
+    #[inline]
+    fn clear_cofactor(p: &G2Affine) -> G2Affine {
+        crate::Config::clear_cofactor_g2(p)
+    }
 }
 
 /// G2_GENERATOR_X =
@@ -6,3 +6,39 @@ test_group!(g1; G1Projective; sw);
 test_group!(g2; G2Projective; sw);
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<BW6_767>; msm);
 test_pairing!(pairing; crate::BW6_767);
+
+#[test]
+fn test_g1_cofactor_clearing() {
+    cofactor::cofactor_clearing::<crate::g1::Config>(&[
+        0xdfbaaabb1c538e50,
+        0x8c75b7354dfcfacd,
+        0x15a0705585d5a913,
+        0xda6f6dd799e984d2,
+        0x09582e1f74f54bd3,
+        0x6249cc54457f9c91,
+        0xa88b4604f8395218,
+        0x665e4360b872007a,
+        0x30f8d18f41e3fc19,
+        0xc36dc4098befce42,
+        0x38259ea59a063294,
+        0x51e2bcf25fa89922,
+    ]);
+}
+
+#[test]
+fn test_g2_cofactor_clearing() {
+    cofactor::cofactor_clearing::<crate::g2::Config>(&[
+        0xe7d0aabb1c6238ec,
+        0x3b11b743af60fad9,
+        0xa687636aec3b1586,
+        0x894f2f212331b698,
+        0xcf27f94a90b4de92,
+        0x443e0743cd00b3ef,
+        0xa88b4604f8395217,
+        0x665e4360b872007a,
+        0x30f8d18f41e3fc19,
+        0xc36dc4098befce42,
+        0x38259ea59a063294,
+        0x51e2bcf25fa89922,
+    ]);
+}
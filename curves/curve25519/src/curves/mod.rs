@@ -1,9 +1,11 @@
 use crate::{Fq, Fr};
 use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Config,
     models::CurveConfig,
-    twisted_edwards::{Affine, MontCurveConfig, MontgomeryAffine, Projective, TECurveConfig},
+    montgomery::{MontCurveConfig, MontgomeryAffine, XZProjective},
+    twisted_edwards::{Affine, Projective, TECurveConfig},
 };
-use ark_ff::MontFp;
+use ark_ff::{BigInteger, ConstantTimeField, MontFp, PrimeField};
 
 #[cfg(test)]
 mod tests;
@@ -11,6 +13,7 @@ mod tests;
 pub type EdwardsAffine = Affine<Curve25519Config>;
 pub type EdwardsProjective = Projective<Curve25519Config>;
 pub type NonZeroMontgomeryAffine = MontgomeryAffine<Curve25519Config>;
+pub type MontgomeryXZProjective = XZProjective<Curve25519Config>;
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Curve25519Config;
@@ -59,6 +62,17 @@ impl MontCurveConfig for Curve25519Config {
     type TECurveConfig = Curve25519Config;
 }
 
+// curve25519_XMD:SHA-512_ELL2_RO_ of RFC 9380, Section 8.5.
+impl Elligator2Config for Curve25519Config {
+    const Z: Fq = MontFp!("2");
+
+    /// This must be equal to 1/(MontCurveConfig::COEFF_B)^2;
+    const ONE_OVER_COEFF_B_SQUARE: Fq = MontFp!("1");
+
+    /// This must be equal to MontCurveConfig::COEFF_A/MontCurveConfig::COEFF_B;
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("486662");
+}
+
 /// GENERATOR_X =
 /// 19682211724289367445990778417013818358151178695569199618971391691394964886553
 pub const GENERATOR_X: Fq =
@@ -69,3 +83,33 @@ pub const GENERATOR_X: Fq =
 /// 46316835694926478169428394003475163141307993866256225615783033603165251855960
 pub const GENERATOR_Y: Fq =
     MontFp!("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+
+/// The X25519 function of [\[RFC7748, Section 5\]], which multiplies the
+/// point with Montgomery `u`-coordinate `u` by the clamped `scalar`, with both
+/// the inputs and the output encoded as 32 little-endian bytes.
+///
+/// The multiplication uses [`XZProjective::ladder`], whose field arithmetic
+/// is not constant time, and returns all zeroes if `u` is a point of small
+/// order.
+///
+/// [\[RFC7748, Section 5\]]: <https://www.rfc-editor.org/rfc/rfc7748#section-5>
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let mut k = scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    let k: [u64; 4] =
+        core::array::from_fn(|i| u64::from_le_bytes(k[8 * i..8 * (i + 1)].try_into().unwrap()));
+
+    // Implementations must mask the most significant bit of `u`, and accept
+    // non-canonical values.
+    let mut u = u;
+    u[31] &= 127;
+    let u = Fq::from_le_bytes_mod_order(&u);
+
+    let p = MontgomeryXZProjective::from_x(u).mul_bigint(k);
+    let x = p.x * p.z.ct_inverse();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&x.into_bigint().to_bytes_le());
+    out
+}
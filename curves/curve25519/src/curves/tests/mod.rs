@@ -0,0 +1,86 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+
+test_group!(te; EdwardsProjective; te);
+test_h2c!(mont; h2c; "./src/curves/tests"; "curve25519"; crate::Curve25519Config; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::elligator2::Elligator2Map<crate::Curve25519Config>; "ELL2"; ark_algebra_test_templates::Sha512, 128; "SHA-512");
+test_constant_time_map!(ct_map; crate::EdwardsProjective; ark_ec::hashing::curve_maps::elligator2::ConstantTimeElligator2Map<crate::Curve25519Config>; ark_ec::hashing::curve_maps::elligator2::Elligator2Map<crate::Curve25519Config>);
+
+fn decode(hex: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+// Test vectors of RFC 7748, Section 5.2.
+#[test]
+fn test_x25519() {
+    let vectors = [
+        (
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+        ),
+        (
+            "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+            "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+            "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+        ),
+    ];
+    for (scalar, u, expected) in vectors {
+        assert_eq!(x25519(decode(scalar), decode(u)), decode(expected));
+    }
+}
+
+// Iterated test vectors of RFC 7748, Section 5.2, without the one million
+// iterations case.
+#[test]
+fn test_x25519_iterated() {
+    let mut k = decode("0900000000000000000000000000000000000000000000000000000000000000");
+    let mut u = k;
+    for i in 1..=1000 {
+        (k, u) = (x25519(k, u), k);
+        if i == 1 {
+            assert_eq!(
+                k,
+                decode("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+            );
+        }
+    }
+    assert_eq!(
+        k,
+        decode("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+    );
+}
+
+// Diffie-Hellman test vectors of RFC 7748, Section 6.1.
+#[test]
+fn test_x25519_diffie_hellman() {
+    let base = decode("0900000000000000000000000000000000000000000000000000000000000000");
+    let alice = decode("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob = decode("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+    let alice_public = x25519(alice, base);
+    let bob_public = x25519(bob, base);
+    assert_eq!(
+        alice_public,
+        decode("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+    );
+    assert_eq!(
+        bob_public,
+        decode("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+    );
+    let shared = decode("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    assert_eq!(x25519(alice, bob_public), shared);
+    assert_eq!(x25519(bob, alice_public), shared);
+}
+
+#[test]
+fn test_x25519_small_order() {
+    let zero = [0u8; 32];
+    let mut one = zero;
+    one[0] = 1;
+    let scalar = decode("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+    assert_eq!(x25519(scalar, zero), zero);
+    assert_eq!(x25519(scalar, one), zero);
+}
@@ -18,6 +18,9 @@
 //! * Curve equation: B * y^2 = x^3 + A * x^2 + x, where
 //!    * A = 486662
 //!    * B = 1
+//!
+//! The [`x25519`] function implements the Diffie-Hellman function of RFC 7748
+//! with a Montgomery ladder over [`MontgomeryXZProjective`].
 
 #[cfg(feature = "r1cs")]
 pub mod constraints;
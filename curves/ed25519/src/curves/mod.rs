@@ -1,5 +1,6 @@
 use crate::{Fq, Fr};
 use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Config,
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
@@ -60,6 +61,26 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+// edwards25519_XMD:SHA-512_ELL2_RO_ of RFC 9380, Section 8.5, maps to
+// curve25519 and then to edwards25519 with the rational map of its
+// Appendix D.1, which scales x by sqrt(-486664).
+impl Elligator2Config for EdwardsConfig {
+    const Z: Fq = MontFp!("2");
+
+    /// This must be equal to 1/(MontCurveConfig::COEFF_B)^2;
+    const ONE_OVER_COEFF_B_SQUARE: Fq =
+        MontFp!("26275489784976167817362209157607329205684801499805794531044402710564697354998");
+
+    /// This must be equal to MontCurveConfig::COEFF_A/MontCurveConfig::COEFF_B;
+    const COEFF_A_OVER_COEFF_B: Fq =
+        MontFp!("18547852967334719671569041754377282594771056939921609508194392766542970141777");
+
+    /// SQRT_COEFF_B = sqrt(-486664), with sgn0 equal to 0
+    const SQRT_COEFF_B: Option<Fq> = Some(MontFp!(
+        "6853475219497561581579357271197624642482790079785650197046958215289687604742"
+    ));
+}
+
 /// GENERATOR_X =
 /// 15112221349535400772501151409588531511454012693041857206046113283949847762202
 pub const GENERATOR_X: Fq =
@@ -0,0 +1,7 @@
+use crate::*;
+use ark_algebra_test_templates::*;
+
+test_group!(te; EdwardsProjective; te);
+test_h2c!(te; h2c; "./src/curves/tests"; "edwards25519"; crate::EdwardsConfig; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::elligator2::Elligator2Map<crate::EdwardsConfig>; "ELL2"; ark_algebra_test_templates::Sha512, 128; "SHA-512");
+test_h2c!(encode; te; encode; "./src/curves/tests"; "edwards25519"; crate::EdwardsConfig; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::elligator2::Elligator2Map<crate::EdwardsConfig>; "ELL2"; ark_algebra_test_templates::Sha512, 128; "SHA-512");
+test_constant_time_map!(ct_map; crate::EdwardsProjective; ark_ec::hashing::curve_maps::elligator2::ConstantTimeElligator2Map<crate::EdwardsConfig>; ark_ec::hashing::curve_maps::elligator2::Elligator2Map<crate::EdwardsConfig>);
@@ -3,6 +3,7 @@
 
 use crate::{fq::Fq, fr::Fr};
 use ark_ec::{
+    hashing::curve_maps::svdw::SvdwConfig,
     models::CurveConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
@@ -50,6 +51,27 @@ impl SWCurveConfig for GrumpkinConfig {
     }
 }
 
+// Parameters from [RFC 9380, section 6.6.1](https://datatracker.ietf.org/doc/html/rfc9380#section-6.6.1),
+// with `Z` found by `find_z_svdw`.
+impl SvdwConfig for GrumpkinConfig {
+    /// Z = 1
+    const Z: Fq = Fq::ONE;
+
+    /// C1 = g(Z) = -16
+    const C1: Fq = MontFp!("-16");
+
+    /// C2 = -Z / 2
+    const C2: Fq =
+        MontFp!("10944121435919637611123202872628637544274182200208017171849102093287904247808");
+
+    /// C3 = sqrt(-g(Z) * 3 * Z^2), with sgn0(C3) = 0
+    const C3: Fq = MontFp!("17631683881184975371348829942606096167675058198229016842588");
+
+    /// C4 = -4 * g(Z) / (3 * Z^2)
+    const C4: Fq =
+        MontFp!("14592161914559516814830937163504850059032242933610689562465469457717205663766");
+}
+
 /// G_GENERATOR_X = 1
 pub const G_GENERATOR_X: Fq = MontFp!("1");
 
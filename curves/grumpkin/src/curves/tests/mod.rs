@@ -0,0 +1,5 @@
+use crate::Projective;
+use ark_algebra_test_templates::*;
+
+test_group!(g1; Projective; sw);
+test_h2c!(h2c; "./src/curves/tests"; "Grumpkin"; crate::GrumpkinConfig; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::svdw::SvdwMap<crate::GrumpkinConfig>; "SVDW");
@@ -1,4 +1,5 @@
 use ark_ec::{
+    hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
@@ -7,6 +8,7 @@ use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
+mod swu_iso;
 #[cfg(test)]
 mod tests;
 
@@ -84,6 +86,13 @@ impl GLVConfig for PallasConfig {
     }
 }
 
+impl WBConfig for PallasConfig {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_PALLAS;
+}
+
 /// G_GENERATOR_X = -1
 pub const G_GENERATOR_X: Fq = MontFp!("-1");
 
@@ -0,0 +1,99 @@
+use crate::{Fq, Fr, PallasConfig};
+use ark_ec::{
+    hashing::curve_maps::{swu::SWUConfig, wb::IsogenyMap},
+    models::{
+        short_weierstrass::{Affine, SWCurveConfig},
+        CurveConfig,
+    },
+};
+use ark_ff::{Field, MontFp};
+
+type IsoAffine = Affine<SwuIsoConfig>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct SwuIsoConfig;
+
+impl CurveConfig for SwuIsoConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// COFACTOR = 1, as the curve is isogenous to a curve of prime order.
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+// The curve E' and Z used by the `pasta_curves` crate for hashing to Pallas,
+// following the simplified SWU map for AB = 0 of RFC 9380, Section 6.6.3:
+// * E': y'^2 = x'^3 + A' * x' + B', where
+//      -  A' = 0x18354a2eb0ea8c9c49be2d7258370742b74134581a27a59f92bb4b0b657a014b
+//      -  B' = 1265
+//      -  A' = 10949663248450308183708987909873589833737836120165333298109615750520499732811
+//  * Z: -13
+impl SWCurveConfig for SwuIsoConfig {
+    const COEFF_A: Fq =
+        MontFp!("10949663248450308183708987909873589833737836120165333298109615750520499732811");
+
+    const COEFF_B: Fq = MontFp!("1265");
+
+    const GENERATOR: IsoAffine = IsoAffine::new_unchecked(G_GENERATOR_X, G_GENERATOR_Y);
+
+    /// Correctness:
+    /// Substituting (0, 0) into the curve equation gives 0^2 = b.
+    /// Since b is not zero, the point (0, 0) is not on the curve.
+    /// Therefore, we can safely use (0, 0) as a flag for the zero point.
+    type ZeroFlag = ();
+}
+
+/// Lexicographically smallest, valid x-coordinate of a point P on the curve,
+/// with the smallest corresponding y. P_x = 1
+/// P_y = 181637241052482785468502922954224147219384682169221362737776065992881747347
+const G_GENERATOR_X: Fq = MontFp!("1");
+const G_GENERATOR_Y: Fq =
+    MontFp!("181637241052482785468502922954224147219384682169221362737776065992881747347");
+
+impl SWUConfig for SwuIsoConfig {
+    const ZETA: Fq = MontFp!("-13");
+}
+
+pub const ISOGENY_MAP_TO_PALLAS: IsogenyMap<'_, SwuIsoConfig, PallasConfig> = IsogenyMap {
+    x_map_numerator: &[
+        MontFp!("12865787693035132824841220556520878650383580658640693651535411895266652280192"),
+        MontFp!("10492611921771203378452795982353351666191589197598957448093274638589204800759"),
+        MontFp!("23989696149150192365340222745168215001509815558210986772351135915822265203574"),
+        MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+    ],
+
+    x_map_denominator: &[
+        MontFp!("22768321103861051515190775253992702316905399997697804654926324362758820947460"),
+        MontFp!("13271109177048389296812780941310096270046944650307955939477485891950613419807"),
+        MontFp!("1"),
+    ],
+
+    y_map_numerator: &[
+        MontFp!("1072148974419594402070101713043406554198631721553391137627950991272221023311"),
+        MontFp!("28823569610051396102362669851238297121581474897215657071023781420043761726004"),
+        MontFp!("11994848074575096182670111372584107500754907779105493386175567957911132601787"),
+        MontFp!("11793638718615538422771118843477472096184948937087302513907460903994431256804"),
+    ],
+
+    y_map_denominator: &[
+        MontFp!("28948022309329048855892746252171976963363056481941560715954676764349967629797"),
+        MontFp!("10408918692925056833786833257634153023990087029210292532869619559576527581706"),
+        MontFp!("5432652610908059517272798285879155923388888734491153551238890455750936314542"),
+        MontFp!("1"),
+    ],
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gen() {
+        let gen: IsoAffine = SwuIsoConfig::GENERATOR;
+        assert!(gen.is_on_curve());
+        assert!(gen.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
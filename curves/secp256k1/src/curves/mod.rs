@@ -1,4 +1,6 @@
 use ark_ec::{
+    encoding::Sec1Config,
+    hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
@@ -6,6 +8,7 @@ use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
+mod swu_iso;
 #[cfg(test)]
 mod tests;
 
@@ -49,6 +52,15 @@ impl SWCurveConfig for Config {
     }
 }
 
+impl Sec1Config for Config {}
+
+impl WBConfig for Config {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_SECP256K1;
+}
+
 /// G_GENERATOR_X =
 /// 55066263022277343669578718895168534326250603453777594175500187360389116729240
 pub const G_GENERATOR_X: Fq =
@@ -0,0 +1,6 @@
+use crate::Projective;
+use ark_algebra_test_templates::*;
+
+test_group!(g1; Projective; sw);
+test_h2c!(h2c; "./src/curves/tests"; "secp256k1"; crate::Config; crate::Fq; crate::Fq; 1);
+test_constant_time_map!(ct_map; crate::Projective; ark_ec::hashing::curve_maps::wb::ConstantTimeWBMap<crate::Config>; ark_ec::hashing::curve_maps::wb::WBMap<crate::Config>);
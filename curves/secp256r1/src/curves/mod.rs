@@ -1,4 +1,6 @@
 use ark_ec::{
+    encoding::Sec1Config,
+    hashing::curve_maps::swu::SWUConfig,
     models::CurveConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
@@ -45,6 +47,14 @@ impl SWCurveConfig for Config {
     type ZeroFlag = ();
 }
 
+impl Sec1Config for Config {}
+
+// P256_XMD:SHA-256_SSWU_RO_ of RFC 9380, Section 8.2, maps to the curve
+// itself, with Z = -10.
+impl SWUConfig for Config {
+    const ZETA: Fq = MontFp!("-10");
+}
+
 /// G_GENERATOR_X =
 /// 0x6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
 pub const G_GENERATOR_X: Fq =
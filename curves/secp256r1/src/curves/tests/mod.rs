@@ -0,0 +1,53 @@
+use crate::Projective;
+use ark_algebra_test_templates::*;
+
+test_group!(g1; Projective; sw);
+test_h2c!(h2c; "./src/curves/tests"; "P256"; crate::Config; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::swu::SWUMap<crate::Config>; "SSWU");
+test_h2c!(encode; sw; encode; "./src/curves/tests"; "P256"; crate::Config; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::swu::SWUMap<crate::Config>; "SSWU"; ark_algebra_test_templates::Sha256, 128; "SHA-256");
+test_constant_time_map!(ct_map; crate::Projective; ark_ec::hashing::curve_maps::swu::ConstantTimeSWUMap<crate::Config>; ark_ec::hashing::curve_maps::swu::SWUMap<crate::Config>);
+
+// The affine coordinates of `G` and `2G`.
+#[test]
+fn sec1_test_vectors() {
+    use crate::Affine;
+    use ark_ec::{
+        encoding::{Encoding, Sec1Compressed, Sec1Uncompressed},
+        AffineRepr, CurveGroup,
+    };
+    use ark_std::vec;
+
+    let g = Affine::generator();
+    for (point, x, y) in [
+        (
+            g,
+            "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+            "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+        ),
+        (
+            (g + g).into_affine(),
+            "7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+            "07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1",
+        ),
+    ] {
+        let (x, y) = (hex::decode(x).unwrap(), hex::decode(y).unwrap());
+
+        let tag = if y[31] & 1 == 1 { 0x03 } else { 0x02 };
+        let compressed = [&[tag][..], &x].concat();
+        let mut encoded = vec![];
+        Encoding::<Sec1Compressed>::encode(&point, &mut encoded).unwrap();
+        assert_eq!(encoded, compressed);
+        assert_eq!(
+            <Affine as Encoding<Sec1Compressed>>::decode(&compressed[..]).unwrap(),
+            point
+        );
+
+        let uncompressed = [&[0x04][..], &x, &y].concat();
+        let mut encoded = vec![];
+        Encoding::<Sec1Uncompressed>::encode(&point, &mut encoded).unwrap();
+        assert_eq!(encoded, uncompressed);
+        assert_eq!(
+            <Affine as Encoding<Sec1Uncompressed>>::decode(&uncompressed[..]).unwrap(),
+            point
+        );
+    }
+}
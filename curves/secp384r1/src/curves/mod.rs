@@ -1,4 +1,6 @@
 use ark_ec::{
+    encoding::Sec1Config,
+    hashing::curve_maps::swu::SWUConfig,
     models::CurveConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
@@ -45,6 +47,14 @@ impl SWCurveConfig for Config {
     const GENERATOR: Affine = Affine::new_unchecked(G_GENERATOR_X, G_GENERATOR_Y);
 }
 
+impl Sec1Config for Config {}
+
+// P384_XMD:SHA-384_SSWU_RO_ of RFC 9380, Section 8.3, maps to the curve
+// itself, with Z = -12.
+impl SWUConfig for Config {
+    const ZETA: Fq = MontFp!("-12");
+}
+
 /// G_GENERATOR_X =
 /// 26247035095799689268623156744566981891852923491109213387815615900925518854738050089022388053975719786650872476732087
 pub const G_GENERATOR_X: Fq =
@@ -0,0 +1,44 @@
+use crate::Projective;
+use ark_algebra_test_templates::*;
+
+test_group!(g1; Projective; sw);
+test_h2c!(sw; h2c; "./src/curves/tests"; "P384"; crate::Config; crate::Fq; crate::Fq; 1; ark_ec::hashing::curve_maps::swu::SWUMap<crate::Config>; "SSWU"; ark_algebra_test_templates::Sha384, 192; "SHA-384");
+
+// The affine coordinates of `G`.
+#[test]
+fn sec1_test_vectors() {
+    use crate::Affine;
+    use ark_ec::{
+        encoding::{Encoding, Sec1Compressed, Sec1Uncompressed},
+        AffineRepr,
+    };
+    use ark_std::vec;
+
+    let g = Affine::generator();
+    let x = hex::decode(
+        "AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A385502F25DBF55296C3A545E3872760AB7",
+    )
+    .unwrap();
+    let y = hex::decode(
+        "3617DE4A96262C6F5D9E98BF9292DC29F8F41DBD289A147CE9DA3113B5F0B8C00A60B1CE1D7E819D7A431D7C90EA0E5F",
+    )
+    .unwrap();
+
+    let compressed = [&[0x03][..], &x].concat();
+    let mut encoded = vec![];
+    Encoding::<Sec1Compressed>::encode(&g, &mut encoded).unwrap();
+    assert_eq!(encoded, compressed);
+    assert_eq!(
+        <Affine as Encoding<Sec1Compressed>>::decode(&compressed[..]).unwrap(),
+        g
+    );
+
+    let uncompressed = [&[0x04][..], &x, &y].concat();
+    let mut encoded = vec![];
+    Encoding::<Sec1Uncompressed>::encode(&g, &mut encoded).unwrap();
+    assert_eq!(encoded, uncompressed);
+    assert_eq!(
+        <Affine as Encoding<Sec1Uncompressed>>::decode(&uncompressed[..]).unwrap(),
+        g
+    );
+}
@@ -1,11 +1,13 @@
 use crate::{fq::Fq, fr::Fr};
 use ark_ec::{
+    hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
+mod swu_iso;
 #[cfg(test)]
 mod tests;
 
@@ -82,6 +84,13 @@ impl GLVConfig for VestaConfig {
     }
 }
 
+impl WBConfig for VestaConfig {
+    type IsogenousCurve = swu_iso::SwuIsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self> =
+        swu_iso::ISOGENY_MAP_TO_VESTA;
+}
+
 /// G_GENERATOR_X = -1
 /// Encoded in Montgomery form, so the value here is -R mod p.
 pub const G_GENERATOR_X: Fq = MontFp!("-1");
@@ -3,3 +3,4 @@ use ark_algebra_test_templates::*;
 
 test_group!(g1; Projective; sw);
 test_group!(g1_glv; Projective; glv);
+test_h2c!(h2c; "./src/curves/tests"; "vesta"; crate::VestaConfig; crate::Fq; crate::Fq; 1);
@@ -0,0 +1,519 @@
+//! Standard encodings of curve points, used to interoperate with other
+//! libraries and protocols.
+//!
+//! Unlike [`CanonicalSerialize`](ark_serialize::CanonicalSerialize), which
+//! uses arkworks' own little-endian layout, each format here follows an
+//! external specification:
+//!
+//! * [`Sec1Compressed`] and [`Sec1Uncompressed`]: [SEC 1, §2.3.3](https://www.secg.org/sec1-v2.pdf),
+//!   as used for secp256k1, P-256 and P-384.
+//! * [`ZcashCompressed`] and [`ZcashUncompressed`]: the [ZCash BLS12-381 format](https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization),
+//!   also used for BLS12-381 in the [IETF BLS signature draft](https://datatracker.ietf.org/doc/draft-irtf-cfrg-bls-signature/).
+//! * [`Eip196`]: the BN254 precompile format of [EIP-196](https://eips.ethereum.org/EIPS/eip-196)
+//!   and [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+//! * [`Eip2537`]: the BLS12-381 precompile format of [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+//!
+//! The points of a curve can only be encoded in the formats specified for it,
+//! which its configuration opts into by implementing [`Sec1Config`],
+//! [`ZcashConfig`], [`Eip196Config`] or [`Eip2537Config`].
+//!
+//! ```
+//! use ark_ec::{encoding::{Encoding, Sec1Compressed}, AffineRepr};
+//! use ark_test_curves::secp256k1::G1Affine;
+//!
+//! let g = G1Affine::generator();
+//! let mut bytes = Vec::new();
+//! Encoding::<Sec1Compressed>::encode(&g, &mut bytes).unwrap();
+//! assert_eq!(bytes.len(), 33);
+//! assert_eq!(bytes[0], 0x02);
+//!
+//! let decoded = <G1Affine as Encoding<Sec1Compressed>>::decode(&bytes[..]).unwrap();
+//! assert_eq!(decoded, g);
+//! ```
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::{SerializationError, Valid, Validate};
+use ark_std::{
+    io::{Read, Write},
+    vec,
+    vec::*,
+};
+
+use crate::{
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+
+/// Encodes and decodes a curve point in the standard format `E`.
+pub trait Encoding<E>: Sized {
+    /// Returns the number of bytes `self` is encoded into.
+    fn encoded_size(&self) -> usize;
+
+    /// Writes `self` into `writer` in the format `E`.
+    fn encode<W: Write>(&self, writer: W) -> Result<(), SerializationError>;
+
+    /// Reads a point in the format `E` from `reader`.
+    ///
+    /// If `validate` is `Validate::Yes`, the point is checked to lie on the
+    /// curve and in the prime-order subgroup.
+    fn decode_with_mode<R: Read>(reader: R, validate: Validate)
+        -> Result<Self, SerializationError>;
+
+    /// Reads a point in the format `E` from `reader`, checking that it lies
+    /// on the curve and in the prime-order subgroup.
+    fn decode<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::decode_with_mode(reader, Validate::Yes)
+    }
+
+    /// Reads a point in the format `E` from `reader` without checking it.
+    fn decode_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::decode_with_mode(reader, Validate::No)
+    }
+}
+
+/// The SEC1 compressed format: the tag `0x02` or `0x03` for an even or odd
+/// `y`, followed by the big-endian `x`. The point at infinity is the single
+/// byte `0x00`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sec1Compressed;
+
+/// The SEC1 uncompressed format: the tag `0x04`, followed by the big-endian
+/// `x` and `y`. The point at infinity is the single byte `0x00`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sec1Uncompressed;
+
+/// The ZCash compressed format: the big-endian `x`, whose three most
+/// significant bits are flags. From the most significant one, they are set if
+/// the point is compressed, if it is the point at infinity, and if `y` is the
+/// lexicographically largest of `y` and `-y`.
+///
+/// Elements of extension fields are written from the highest to the lowest
+/// coefficient, i.e. `c1 || c0` for `Fq2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZcashCompressed;
+
+/// The ZCash uncompressed format: the big-endian `x` and `y`, with the flags of
+/// [`ZcashCompressed`] in the three most significant bits of `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZcashUncompressed;
+
+/// The format of the BN254 precompiles of EIP-196 and EIP-197: the big-endian
+/// `x` and `y`. Elements of `Fq2` are written as `c1 || c0`, and the point at
+/// infinity is encoded as zeros.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Eip196;
+
+/// The format of the BLS12-381 precompiles of EIP-2537: the big-endian `x` and
+/// `y`, where each element of the base prime field is left-padded with zeros
+/// to 64 bytes. Elements of `Fq2` are written as `c0 || c1`, and the point at
+/// infinity is encoded as zeros.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Eip2537;
+
+/// Marks the curves whose points may be encoded in the SEC1 formats
+/// [`Sec1Compressed`] and [`Sec1Uncompressed`], such as secp256k1, P-256 and
+/// P-384. Curve crates implement it for the curves the formats are specified
+/// for.
+pub trait Sec1Config: SWCurveConfig {}
+
+/// Marks the curves whose points may be encoded in the ZCash formats
+/// [`ZcashCompressed`] and [`ZcashUncompressed`], such as the groups of
+/// BLS12-381 and BLS12-377.
+pub trait ZcashConfig: SWCurveConfig {}
+
+/// Marks the groups of BN254, whose points may be encoded in the [`Eip196`]
+/// format.
+pub trait Eip196Config: SWCurveConfig {}
+
+/// Marks the groups of BLS12-381, whose points may be encoded in the
+/// [`Eip2537`] format.
+pub trait Eip2537Config: SWCurveConfig {}
+
+/// The size of the big-endian encoding of an element of `F`.
+const fn prime_field_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize).div_ceil(8)
+}
+
+/// Writes `elem` as a big-endian integer of `len` bytes.
+fn write_prime_field<F: PrimeField>(elem: F, len: usize, bytes: &mut Vec<u8>) {
+    let repr = elem.into_bigint().to_bytes_be();
+    if repr.len() >= len {
+        bytes.extend_from_slice(&repr[repr.len() - len..]);
+    } else {
+        bytes.resize(bytes.len() + len - repr.len(), 0);
+        bytes.extend_from_slice(&repr);
+    }
+}
+
+/// Reads a big-endian integer, and rejects it unless it is smaller than the
+/// modulus of `F`.
+fn read_prime_field<F: PrimeField>(bytes: &[u8]) -> Result<F, SerializationError> {
+    let elem = F::from_be_bytes_mod_order(bytes);
+    let mut canonical = Vec::with_capacity(bytes.len());
+    write_prime_field(elem, bytes.len(), &mut canonical);
+    if canonical == bytes {
+        Ok(elem)
+    } else {
+        Err(SerializationError::InvalidData)
+    }
+}
+
+/// Writes each base prime field coefficient of `elem` as a big-endian integer
+/// of `len` bytes, from the highest coefficient if `reverse` is set.
+fn write_field<F: Field>(elem: &F, len: usize, reverse: bool, bytes: &mut Vec<u8>) {
+    let mut coeffs: Vec<_> = elem.to_base_prime_field_elements().collect();
+    if reverse {
+        coeffs.reverse();
+    }
+    for coeff in coeffs {
+        write_prime_field(coeff, len, bytes);
+    }
+}
+
+/// Reads an element written by [`write_field`].
+fn read_field<F: Field>(bytes: &[u8], len: usize, reverse: bool) -> Result<F, SerializationError> {
+    let mut coeffs = bytes
+        .chunks(len)
+        .map(read_prime_field)
+        .collect::<Result<Vec<_>, _>>()?;
+    if reverse {
+        coeffs.reverse();
+    }
+    F::from_base_prime_field_elems(coeffs).ok_or(SerializationError::InvalidData)
+}
+
+/// Checks that `point` is on the curve and in the prime-order subgroup if
+/// `validate` is `Validate::Yes`.
+fn validated<P: SWCurveConfig>(
+    point: Affine<P>,
+    validate: Validate,
+) -> Result<Affine<P>, SerializationError> {
+    if validate == Validate::Yes {
+        point.check()?;
+    }
+    Ok(point)
+}
+
+/// Returns the point with `x`-coordinate `x` and the given `y`-coordinate
+/// choice, which is on the curve by construction.
+fn point_from_x<P: SWCurveConfig>(
+    x: P::BaseField,
+    greatest: bool,
+) -> Result<Affine<P>, SerializationError> {
+    Affine::get_point_from_x_unchecked(x, greatest).ok_or(SerializationError::InvalidData)
+}
+
+fn read_exact_vec<R: Read>(mut reader: R, len: usize) -> Result<Vec<u8>, SerializationError> {
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+const fn sec1_field_size<P: Sec1Config>() -> usize
+where
+    P::BaseField: PrimeField,
+{
+    prime_field_size::<P::BaseField>()
+}
+
+impl<P: Sec1Config> Encoding<Sec1Compressed> for Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn encoded_size(&self) -> usize {
+        if self.is_zero() {
+            1
+        } else {
+            1 + sec1_field_size::<P>()
+        }
+    }
+
+    fn encode<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        let mut bytes = Vec::with_capacity(Encoding::<Sec1Compressed>::encoded_size(self));
+        match self.xy() {
+            None => bytes.push(0x00),
+            Some((x, y)) => {
+                bytes.push(if y.into_bigint().is_odd() { 0x03 } else { 0x02 });
+                write_prime_field(x, sec1_field_size::<P>(), &mut bytes);
+            },
+        }
+        Ok(writer.write_all(&bytes)?)
+    }
+
+    fn decode_with_mode<R: Read>(
+        mut reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut tag = [0u8];
+        reader.read_exact(&mut tag)?;
+        let odd = match tag[0] {
+            0x00 => return Ok(Self::identity()),
+            0x02 => false,
+            0x03 => true,
+            _ => return Err(SerializationError::UnexpectedFlags),
+        };
+        let x = read_prime_field(&read_exact_vec(reader, sec1_field_size::<P>())?)?;
+        let point = point_from_x::<P>(x, false)?;
+        let point = if point.y.into_bigint().is_odd() == odd {
+            point
+        } else {
+            -point
+        };
+        validated(point, validate)
+    }
+}
+
+impl<P: Sec1Config> Encoding<Sec1Uncompressed> for Affine<P>
+where
+    P::BaseField: PrimeField,
+{
+    fn encoded_size(&self) -> usize {
+        if self.is_zero() {
+            1
+        } else {
+            1 + 2 * sec1_field_size::<P>()
+        }
+    }
+
+    fn encode<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        let mut bytes = Vec::with_capacity(Encoding::<Sec1Uncompressed>::encoded_size(self));
+        match self.xy() {
+            None => bytes.push(0x00),
+            Some((x, y)) => {
+                bytes.push(0x04);
+                write_prime_field(x, sec1_field_size::<P>(), &mut bytes);
+                write_prime_field(y, sec1_field_size::<P>(), &mut bytes);
+            },
+        }
+        Ok(writer.write_all(&bytes)?)
+    }
+
+    fn decode_with_mode<R: Read>(
+        mut reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut tag = [0u8];
+        reader.read_exact(&mut tag)?;
+        match tag[0] {
+            0x00 => return Ok(Self::identity()),
+            0x04 => {},
+            _ => return Err(SerializationError::UnexpectedFlags),
+        }
+        let len = sec1_field_size::<P>();
+        let bytes = read_exact_vec(reader, 2 * len)?;
+        let x = read_prime_field(&bytes[..len])?;
+        let y = read_prime_field(&bytes[len..])?;
+        validated(Self::new_unchecked(x, y), validate)
+    }
+}
+
+const ZCASH_COMPRESSED: u8 = 1 << 7;
+const ZCASH_INFINITY: u8 = 1 << 6;
+const ZCASH_LARGEST: u8 = 1 << 5;
+const ZCASH_FLAGS: u8 = ZCASH_COMPRESSED | ZCASH_INFINITY | ZCASH_LARGEST;
+
+/// Returns the size of the ZCash encoding of an element of `F`, or
+/// `NotEnoughSpace` if its most significant byte cannot hold the flags.
+fn zcash_field_size<F: Field>() -> Result<usize, SerializationError> {
+    let bits = F::BasePrimeField::MODULUS_BIT_SIZE as usize;
+    let len = prime_field_size::<F::BasePrimeField>();
+    if 8 * len - bits < 3 {
+        return Err(SerializationError::NotEnoughSpace);
+    }
+    Ok(len * F::extension_degree() as usize)
+}
+
+fn zcash_encode<P: SWCurveConfig, W: Write>(
+    point: &Affine<P>,
+    compressed: bool,
+    mut writer: W,
+) -> Result<(), SerializationError> {
+    let len = zcash_field_size::<P::BaseField>()?;
+    let prime_len = prime_field_size::<<P::BaseField as Field>::BasePrimeField>();
+    let num_coordinates = if compressed { 1 } else { 2 };
+    let mut bytes = Vec::with_capacity(num_coordinates * len);
+    let mut flags = if compressed { ZCASH_COMPRESSED } else { 0 };
+    match point.xy() {
+        None => {
+            bytes.resize(num_coordinates * len, 0);
+            flags |= ZCASH_INFINITY;
+        },
+        Some((x, y)) => {
+            write_field(&x, prime_len, true, &mut bytes);
+            if compressed {
+                if y > -y {
+                    flags |= ZCASH_LARGEST;
+                }
+            } else {
+                write_field(&y, prime_len, true, &mut bytes);
+            }
+        },
+    }
+    bytes[0] |= flags;
+    Ok(writer.write_all(&bytes)?)
+}
+
+fn zcash_decode<P: SWCurveConfig, R: Read>(
+    reader: R,
+    compressed: bool,
+    validate: Validate,
+) -> Result<Affine<P>, SerializationError> {
+    let len = zcash_field_size::<P::BaseField>()?;
+    let prime_len = prime_field_size::<<P::BaseField as Field>::BasePrimeField>();
+    let num_coordinates = if compressed { 1 } else { 2 };
+    let mut bytes = read_exact_vec(reader, num_coordinates * len)?;
+
+    let flags = bytes[0] & ZCASH_FLAGS;
+    bytes[0] &= !ZCASH_FLAGS;
+    let is_compressed = flags & ZCASH_COMPRESSED != 0;
+    let is_infinity = flags & ZCASH_INFINITY != 0;
+    let is_largest = flags & ZCASH_LARGEST != 0;
+    if is_compressed != compressed || (is_largest && (!compressed || is_infinity)) {
+        return Err(SerializationError::UnexpectedFlags);
+    }
+
+    if is_infinity {
+        return if bytes.iter().all(|b| *b == 0) {
+            Ok(Affine::identity())
+        } else {
+            Err(SerializationError::InvalidData)
+        };
+    }
+    let x = read_field(&bytes[..len], prime_len, true)?;
+    let point = if compressed {
+        point_from_x::<P>(x, is_largest)?
+    } else {
+        let y = read_field(&bytes[len..], prime_len, true)?;
+        Affine::new_unchecked(x, y)
+    };
+    validated(point, validate)
+}
+
+impl<P: ZcashConfig> Encoding<ZcashCompressed> for Affine<P> {
+    fn encoded_size(&self) -> usize {
+        zcash_field_size::<P::BaseField>().unwrap_or(0)
+    }
+
+    fn encode<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        zcash_encode(self, true, writer)
+    }
+
+    fn decode_with_mode<R: Read>(
+        reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        zcash_decode(reader, true, validate)
+    }
+}
+
+impl<P: ZcashConfig> Encoding<ZcashUncompressed> for Affine<P> {
+    fn encoded_size(&self) -> usize {
+        2 * zcash_field_size::<P::BaseField>().unwrap_or(0)
+    }
+
+    fn encode<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        zcash_encode(self, false, writer)
+    }
+
+    fn decode_with_mode<R: Read>(
+        reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        zcash_decode(reader, false, validate)
+    }
+}
+
+/// Encodes `point` as its coordinates, each base prime field element taking
+/// `prime_len` bytes, with the point at infinity encoded as zeros.
+fn eip_encode<P: SWCurveConfig, W: Write>(
+    point: &Affine<P>,
+    prime_len: usize,
+    reverse: bool,
+    mut writer: W,
+) -> Result<(), SerializationError> {
+    let len = prime_len * P::BaseField::extension_degree() as usize;
+    let mut bytes = Vec::with_capacity(2 * len);
+    match point.xy() {
+        None => bytes.resize(2 * len, 0),
+        Some((x, y)) => {
+            write_field(&x, prime_len, reverse, &mut bytes);
+            write_field(&y, prime_len, reverse, &mut bytes);
+        },
+    }
+    Ok(writer.write_all(&bytes)?)
+}
+
+fn eip_decode<P: SWCurveConfig, R: Read>(
+    reader: R,
+    prime_len: usize,
+    reverse: bool,
+    validate: Validate,
+) -> Result<Affine<P>, SerializationError> {
+    let len = prime_len * P::BaseField::extension_degree() as usize;
+    let bytes = read_exact_vec(reader, 2 * len)?;
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(Affine::identity());
+    }
+    let x = read_field(&bytes[..len], prime_len, reverse)?;
+    let y = read_field(&bytes[len..], prime_len, reverse)?;
+    validated(Affine::new_unchecked(x, y), validate)
+}
+
+impl<P: Eip196Config> Encoding<Eip196> for Affine<P> {
+    fn encoded_size(&self) -> usize {
+        let prime_len = prime_field_size::<<P::BaseField as Field>::BasePrimeField>();
+        2 * prime_len * P::BaseField::extension_degree() as usize
+    }
+
+    fn encode<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        let prime_len = prime_field_size::<<P::BaseField as Field>::BasePrimeField>();
+        eip_encode(self, prime_len, true, writer)
+    }
+
+    fn decode_with_mode<R: Read>(
+        reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let prime_len = prime_field_size::<<P::BaseField as Field>::BasePrimeField>();
+        eip_decode(reader, prime_len, true, validate)
+    }
+}
+
+/// The size of an element of the base prime field in the EIP-2537 format.
+const EIP2537_FIELD_SIZE: usize = 64;
+
+impl<P: Eip2537Config> Encoding<Eip2537> for Affine<P> {
+    fn encoded_size(&self) -> usize {
+        2 * EIP2537_FIELD_SIZE * P::BaseField::extension_degree() as usize
+    }
+
+    fn encode<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        eip_encode(self, EIP2537_FIELD_SIZE, false, writer)
+    }
+
+    fn decode_with_mode<R: Read>(
+        reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        eip_decode(reader, EIP2537_FIELD_SIZE, false, validate)
+    }
+}
+
+impl<P: SWCurveConfig, E> Encoding<E> for Projective<P>
+where
+    Affine<P>: Encoding<E>,
+{
+    fn encoded_size(&self) -> usize {
+        Encoding::<E>::encoded_size(&self.into_affine())
+    }
+
+    fn encode<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        Encoding::<E>::encode(&self.into_affine(), writer)
+    }
+
+    fn decode_with_mode<R: Read>(
+        reader: R,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        <Affine<P> as Encoding<E>>::decode_with_mode(reader, validate).map(Into::into)
+    }
+}
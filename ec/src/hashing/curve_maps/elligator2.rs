@@ -1,5 +1,5 @@
 use crate::models::twisted_edwards::{MontCurveConfig, TECurveConfig};
-use ark_ff::{Field, One, Zero};
+use ark_ff::{ConstantTimeField, Field, One, Zero};
 use core::marker::PhantomData;
 
 use crate::{
@@ -26,6 +26,15 @@ pub trait Elligator2Config: TECurveConfig + MontCurveConfig {
 
     /// This must be equal to MontCurveConfig::COEFF_A/MontCurveConfig::COEFF_B;
     const COEFF_A_OVER_COEFF_B: Self::BaseField;
+
+    /// A square root `c` of MontCurveConfig::COEFF_B with `sgn0(c) == 0`, if
+    /// the map should be computed on the Montgomery curve with `b = 1` and
+    /// then scaled by `c`, as for `edwards25519` in \[HSSWW23, Section 6.8.2\].
+    ///
+    /// When `None`, the map is computed on the Montgomery curve with
+    /// coefficient `b`, which maps some inputs to the negation of the points of
+    /// the former.
+    const SQRT_COEFF_B: Option<Self::BaseField> = None;
 }
 
 /// Represents the Elligator2 hash-to-curve map defined by `P`.
@@ -57,6 +66,15 @@ impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
             <P as MontCurveConfig>::COEFF_A / <P as MontCurveConfig>::COEFF_B,
             "COEFF_A_OVER_COEFF_B is not equal to COEFF_A/COEFF_B in Montgomery form"
         );
+
+        if let Some(c) = P::SQRT_COEFF_B {
+            debug_assert_eq!(
+                c.square(),
+                <P as MontCurveConfig>::COEFF_B,
+                "SQRT_COEFF_B is not a square root of COEFF_B in Montgomery form"
+            );
+            debug_assert!(!parity(&c), "SQRT_COEFF_B should have sgn0 equal to 0");
+        }
         Ok(())
     }
 
@@ -75,9 +93,18 @@ impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
 
         // ark a is irtf J
         // ark b is irtf k
-        let k = <P as MontCurveConfig>::COEFF_B;
-        let j_on_k = P::COEFF_A_OVER_COEFF_B;
-        let ksq_inv = P::ONE_OVER_COEFF_B_SQUARE;
+        let (k, j_on_k, ksq_inv) = match P::SQRT_COEFF_B {
+            Some(_) => (
+                <P::BaseField as One>::one(),
+                <P as MontCurveConfig>::COEFF_A,
+                <P::BaseField as One>::one(),
+            ),
+            None => (
+                <P as MontCurveConfig>::COEFF_B,
+                P::COEFF_A_OVER_COEFF_B,
+                P::ONE_OVER_COEFF_B_SQUARE,
+            ),
+        };
 
         let den_1 = <P::BaseField as One>::one() + P::Z * element.square();
 
@@ -137,6 +164,97 @@ impl<P: Elligator2Config> MapToCurve<Projective<P>> for Elligator2Map<P> {
             let w = tv2_inv * t * (s - <P::BaseField as One>::one());
             (v, w)
         };
+        // `(s, t / c)` is on the Montgomery curve with coefficient `b = c^2`.
+        let v = match P::SQRT_COEFF_B {
+            Some(c) => v * c,
+            None => v,
+        };
+
+        let point_on_curve = Affine::new_unchecked(v, w);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "Elligator2 mapped to a point off the curve"
+        );
+        Ok(point_on_curve)
+    }
+}
+
+/// Represents the Elligator2 hash-to-curve map defined by `P`, computed after
+/// the straight-line implementation of \[HSSWW23, Appendix F.3\].
+///
+/// It maps every element to the same point as [`Elligator2Map`]. Its only
+/// branches are on the parameters of the map, but the field arithmetic it uses
+/// branches on its operands (see [`ConstantTimeField`]).
+///
+/// - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+pub struct ConstantTimeElligator2Map<P: TECurveConfig>(PhantomData<fn() -> P>);
+
+impl<P: Elligator2Config> MapToCurve<Projective<P>> for ConstantTimeElligator2Map<P> {
+    /// Checks if `P` represents a valid Elligator2 map. Panics otherwise.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        Elligator2Map::<P>::check_parameters()
+    }
+
+    /// Map an arbitrary base field element `element` to a curve point, with
+    /// the operations of [`ConstantTimeField`].
+    fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let one = <P::BaseField as One>::one();
+        let (k, j_on_k, ksq_inv) = match P::SQRT_COEFF_B {
+            Some(_) => (one, <P as MontCurveConfig>::COEFF_A, one),
+            None => (
+                <P as MontCurveConfig>::COEFF_B,
+                P::COEFF_A_OVER_COEFF_B,
+                P::ONE_OVER_COEFF_B_SQUARE,
+            ),
+        };
+
+        // 1. tv1 = Z * u^2
+        // 2. x1 = -(J / K) * inv0(1 + tv1)
+        // 3. If x1 == 0, set x1 = -(J / K)
+        // 4. gx1 = x1^3 + (J / K) * x1^2 + x1 / K^2
+        let tv1 = P::Z * element.square();
+        let x1 = -j_on_k * (one + tv1).ct_inverse();
+        let x1 = P::BaseField::ct_select(&-j_on_k, &x1, x1.ct_is_zero());
+        let gx1 = ((x1 + j_on_k) * x1 + ksq_inv) * x1;
+
+        // 5. x2 = -x1 - (J / K) = tv1 * x1, unless x1 was set in step 3
+        // 6. gx2 = tv1 * gx1 = u^2 * Z * gx1
+        //
+        // When gx1 is not a square, `sqrt_ratio` returns a square root y1 of
+        // Z * gx1, and so u * y1 is a square root of gx2, or zero when
+        // x1 was set in step 3, where x2 = 0.
+        let x2 = -x1 - j_on_k;
+        let (e1, y1) = P::BaseField::ct_sqrt_ratio(&gx1, &one, &P::Z);
+        let y2 = P::BaseField::ct_select(
+            &<P::BaseField as Zero>::zero(),
+            &(element * y1),
+            x2.ct_is_zero(),
+        );
+
+        // 7. If is_square(gx1), set x = x1, y = sqrt(gx1) with sgn0(y) == 1.
+        // 8. Else set x = x2, y = sqrt(gx2) with sgn0(y) == 0.
+        let x = P::BaseField::ct_select(&x1, &x2, e1);
+        let y = P::BaseField::ct_select(&y1, &y2, e1);
+        let y = P::BaseField::ct_select(&y, &-y, y.ct_sgn0() == e1);
+
+        // 9. s = x * K
+        // 10. t = y * K
+        let s = x * k;
+        let t = y * k;
+
+        // The rational map to the twisted Edwards curve of [HSSWW23, Appendix
+        // D.1], where inv0 maps the exceptional points to `(0, 1)`.
+        let tv1 = s + one;
+        let tv2 = tv1 * t;
+        let tv2_inv = tv2.ct_inverse();
+        let v = tv2_inv * tv1 * s;
+        let w = tv2_inv * t * (s - one);
+        let w = P::BaseField::ct_select(&one, &w, tv2.ct_is_zero());
+        // `(s, t / c)` is on the Montgomery curve with coefficient `b = c^2`.
+        let v = match P::SQRT_COEFF_B {
+            Some(c) => v * c,
+            None => v,
+        };
 
         let point_on_curve = Affine::new_unchecked(v, w);
         debug_assert!(
@@ -306,4 +424,20 @@ mod test {
             "a constant hash function is not good."
         );
     }
+
+    /// The straight-line map must map every element to the same point as the
+    /// variable-time one.
+    #[test]
+    fn constant_time_map_field_to_curve_elligator2() {
+        ConstantTimeElligator2Map::<TestElligator2MapToCurveConfig>::check_parameters().unwrap();
+
+        for i in 0..101u64 {
+            let element = F101::from(i);
+            assert_eq!(
+                ConstantTimeElligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve(element)
+                    .unwrap(),
+                Elligator2Map::<TestElligator2MapToCurveConfig>::map_to_curve(element).unwrap(),
+            );
+        }
+    }
 }
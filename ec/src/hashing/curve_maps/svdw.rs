@@ -0,0 +1,243 @@
+use crate::models::short_weierstrass::SWCurveConfig;
+use ark_ff::{AdditiveGroup, Field, One, Zero};
+use ark_std::string::ToString;
+use core::marker::PhantomData;
+
+use crate::{
+    hashing::{curve_maps::parity, map_to_curve_hasher::MapToCurve, HashToCurveError},
+    models::short_weierstrass::{Affine, Projective},
+};
+
+/// Trait defining the necessary parameters for the Shallue–van de Woestijne
+/// hash-to-curve method for curves of Weierstrass form of:
+/// `y^2 = x^3 + a*x + b`, from [\[SvdW06\]], according to [\[HSSWW23\]].
+///
+/// Unlike [`SWUConfig`](super::swu::SWUConfig) and
+/// [`WBConfig`](super::wb::WBConfig), it applies to any curve, including
+/// curves with `a = 0` and no convenient isogeny, such as BN254.
+///
+/// Writing `g(x) = x^3 + a*x + b`, the constants are those of
+/// \[HSSWW23, Section 6.6.1\]:
+///
+/// - [\[SvdW06\]] <https://doi.org/10.1007/11792086_36>
+/// - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+pub trait SvdwConfig: SWCurveConfig {
+    /// An element of the base field such that `g(Z) != 0`,
+    /// `-(3 * Z^2 + 4 * a) / (4 * g(Z))` is a non-zero square, and at least
+    /// one of `g(Z)` and `g(-Z / 2)` is a square. [\[HSSWW23\]] mandates the
+    /// one found by `find_z_svdw` in its Appendix H.1.
+    const Z: Self::BaseField;
+
+    /// This must be equal to `g(Z)`.
+    const C1: Self::BaseField;
+
+    /// This must be equal to `-Z / 2`.
+    const C2: Self::BaseField;
+
+    /// This must be equal to `sqrt(-g(Z) * (3 * Z^2 + 4 * a))`, with
+    /// `sgn0(C3) == 0`.
+    const C3: Self::BaseField;
+
+    /// This must be equal to `-4 * g(Z) / (3 * Z^2 + 4 * a)`.
+    const C4: Self::BaseField;
+}
+
+/// Represents the Shallue–van de Woestijne hash-to-curve map defined by `P`.
+pub struct SvdwMap<P: SvdwConfig>(PhantomData<fn() -> P>);
+
+/// Returns `x^3 + a*x + b`.
+fn curve_rhs<P: SWCurveConfig>(x: P::BaseField) -> P::BaseField {
+    (x.square() + P::COEFF_A) * x + P::COEFF_B
+}
+
+impl<P: SvdwConfig> MapToCurve<Projective<P>> for SvdwMap<P> {
+    /// Checks if `P` represents a valid map.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        let three_z2_four_a = P::Z.square().double() + P::Z.square() + P::COEFF_A.double().double();
+        let gz = curve_rhs::<P>(P::Z);
+        if gz.is_zero()
+            || three_z2_four_a.is_zero()
+            || (-three_z2_four_a / gz.double().double())
+                .legendre()
+                .is_qnr()
+        {
+            return Err(HashToCurveError::MapToCurveError(
+                "Z does not satisfy the requirements of the SvdW map".to_string(),
+            ));
+        }
+        let gz2 = curve_rhs::<P>(P::C2);
+        if gz.legendre().is_qnr() && gz2.legendre().is_qnr() {
+            return Err(HashToCurveError::MapToCurveError(
+                "neither g(Z) nor g(-Z / 2) is a square".to_string(),
+            ));
+        }
+
+        debug_assert_eq!(P::C1, gz, "C1 is not equal to g(Z)");
+        debug_assert_eq!(
+            P::C2,
+            -P::Z / P::BaseField::from(2u8),
+            "C2 is not equal to -Z / 2"
+        );
+        debug_assert_eq!(
+            P::C3.square(),
+            -gz * three_z2_four_a,
+            "C3 is not a square root of -g(Z) * (3 * Z^2 + 4 * a)"
+        );
+        debug_assert!(!parity(&P::C3), "C3 should have sgn0 equal to 0");
+        debug_assert_eq!(
+            P::C4,
+            -gz.double().double() / three_z2_four_a,
+            "C4 is not equal to -4 * g(Z) / (3 * Z^2 + 4 * a)"
+        );
+        Ok(())
+    }
+
+    /// Map an arbitrary base field element `element` to a curve point.
+    fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        // The straight-line implementation of [HSSWW23, Appendix F.1]:
+        //
+        // 1. tv1 = u^2 * c1
+        // 2. tv2 = 1 + tv1
+        // 3. tv1 = 1 - tv1
+        // 4. tv3 = inv0(tv1 * tv2)
+        // 5. tv4 = u * tv1 * tv3 * c3
+        // 6. x1 = c2 - tv4
+        // 7. x2 = c2 + tv4
+        // 8. x3 = (tv2^2 * tv3)^2 * c4 + Z
+        // 9. If is_square(g(x1)), set x = x1
+        // 10. Else if is_square(g(x2)), set x = x2
+        // 11. Else set x = x3
+        // 12. y = sqrt(g(x)), with sgn0(y) == sgn0(u)
+        let one = <P::BaseField as One>::one();
+        let tv1 = element.square() * P::C1;
+        let tv2 = one + tv1;
+        let tv1 = one - tv1;
+        // inv0 maps zero to zero.
+        let tv3 = (tv1 * tv2).inverse().unwrap_or_default();
+        let tv4 = element * tv1 * tv3 * P::C3;
+
+        let x1 = P::C2 - tv4;
+        let gx1 = curve_rhs::<P>(x1);
+        let x2 = P::C2 + tv4;
+        let gx2 = curve_rhs::<P>(x2);
+        let (x, gx) = if !gx1.legendre().is_qnr() {
+            (x1, gx1)
+        } else if !gx2.legendre().is_qnr() {
+            (x2, gx2)
+        } else {
+            let x3 = (tv2.square() * tv3).square() * P::C4 + P::Z;
+            (x3, curve_rhs::<P>(x3))
+        };
+
+        let y = gx.sqrt().ok_or_else(|| {
+            HashToCurveError::MapToCurveError(
+                "g(x3) is not a square: the SvdW map is misconfigured".to_string(),
+            )
+        })?;
+        let y = if parity(&y) == parity(&element) {
+            y
+        } else {
+            -y
+        };
+        let point_on_curve = Affine::new_unchecked(x, y);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "svdw mapped to a point off the curve"
+        );
+        Ok(point_on_curve)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        hashing::{map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+        CurveConfig,
+    };
+    use ark_ff::field_hashers::DefaultFieldHasher;
+    use ark_std::vec::*;
+
+    use super::*;
+    use ark_ff::{fields::Fp64, MontBackend, MontFp};
+    use sha2::Sha256;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "127"]
+    #[generator = "6"]
+    pub(crate) struct F127Config;
+    pub(crate) type F127 = Fp64<MontBackend<F127Config, 1>>;
+
+    const F127_ONE: F127 = MontFp!("1");
+
+    struct TestSvdwMapToCurveConfig;
+
+    impl CurveConfig for TestSvdwMapToCurveConfig {
+        const COFACTOR: &[u64] = &[1];
+
+        const COFACTOR_INV: F127 = F127_ONE;
+
+        type BaseField = F127;
+        type ScalarField = F127;
+    }
+
+    /// y^2 = x^3 + 3, which has 127 points and `a = 0`, so that neither the
+    /// SWU nor the WB map apply.
+    impl SWCurveConfig for TestSvdwMapToCurveConfig {
+        /// COEFF_A = 0
+        const COEFF_A: F127 = MontFp!("0");
+
+        /// COEFF_B = 3
+        const COEFF_B: F127 = MontFp!("3");
+
+        /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(MontFp!("1"), MontFp!("2"));
+
+        type ZeroFlag = ();
+    }
+
+    impl SvdwConfig for TestSvdwMapToCurveConfig {
+        const Z: F127 = MontFp!("1");
+        const C1: F127 = MontFp!("4");
+        const C2: F127 = MontFp!("63");
+        const C3: F127 = MontFp!("78");
+        const C4: F127 = MontFp!("37");
+    }
+
+    /// The point of the test is to get a simple SvdW compatible curve and make
+    /// simple hash
+    #[test]
+    fn hash_arbitrary_string_to_curve_svdw() {
+        let test_svdw_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestSvdwMapToCurveConfig>,
+            DefaultFieldHasher<Sha256, 128>,
+            SvdwMap<TestSvdwMapToCurveConfig>,
+        >::new(&[1])
+        .unwrap();
+
+        let hash_result = test_svdw_to_curve_hasher
+            .hash(b"if you stick a Babel fish in your ear you can instantly understand anything said to you in any form of language.")
+            .expect("fail to hash the string to curve");
+
+        assert!(
+            hash_result.is_on_curve(),
+            "hash results into a point off the curve"
+        );
+    }
+
+    /// Map the whole field to the curve: every element, including the
+    /// exceptional ones where `1 - u^2 * c1` or `1 + u^2 * c1` is zero, must
+    /// be mapped to a point on the curve, and the map must not be constant.
+    #[test]
+    fn map_field_to_curve_svdw() {
+        SvdwMap::<TestSvdwMapToCurveConfig>::check_parameters().unwrap();
+
+        let map_range: Vec<Affine<TestSvdwMapToCurveConfig>> = (0..127u64)
+            .map(|i| SvdwMap::map_to_curve(F127::from(i)).unwrap())
+            .collect();
+        assert!(map_range.iter().all(|p| p.is_on_curve()));
+        assert!(
+            map_range.iter().any(|p| *p != map_range[0]),
+            "a constant hash function is not good."
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::models::short_weierstrass::SWCurveConfig;
-use ark_ff::{Field, One, Zero};
+use ark_ff::{ConstantTimeField, Field, One, Zero};
 use core::marker::PhantomData;
 
 use crate::{
@@ -140,6 +140,80 @@ impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
     }
 }
 
+/// Represents the SWU hash-to-curve map defined by `P`, computed with the
+/// straight-line implementation of \[HSSWW23, Appendix F.2\].
+///
+/// It maps every element to the same point as [`SWUMap`], and is slower. It
+/// replaces the branches of [`SWUMap`] on the element with the operations of
+/// [`ConstantTimeField`], whose field arithmetic still branches on its
+/// operands: it is not constant time.
+///
+/// - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+pub struct ConstantTimeSWUMap<P: SWUConfig>(PhantomData<fn() -> P>);
+
+impl<P: SWUConfig> MapToCurve<Projective<P>> for ConstantTimeSWUMap<P> {
+    /// Checks if `P` represents a valid map.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        SWUMap::<P>::check_parameters()
+    }
+
+    /// Map an arbitrary base field element to a curve point, without
+    /// branching on the element.
+    fn map_to_curve(element: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let (a, b) = (P::COEFF_A, P::COEFF_B);
+        // `CMOV(x, y, c)` of [HSSWW23] is `F::ct_select(y, x, c)`.
+        //
+        // 1. tv1 = u^2
+        // 2. tv1 = Z * tv1
+        // 3. tv2 = tv1^2
+        // 4. tv2 = tv2 + tv1
+        // 5. tv3 = tv2 + 1
+        // 6. tv3 = B * tv3
+        let tv1 = P::ZETA * element.square();
+        let tv2 = tv1.square() + tv1;
+        let tv3 = b * (tv2 + <P::BaseField as One>::one());
+        // 7. tv4 = CMOV(Z, -tv2, tv2 != 0)
+        // 8. tv4 = A * tv4
+        let tv4 = a * P::BaseField::ct_select(&P::ZETA, &-tv2, tv2.ct_is_zero());
+        // 9. tv2 = tv3^2
+        // 10. tv6 = tv4^2
+        // 11. tv5 = A * tv6
+        // 12. tv2 = tv2 + tv5
+        // 13. tv2 = tv2 * tv3
+        // 14. tv6 = tv6 * tv4
+        // 15. tv5 = B * tv6
+        // 16. tv2 = tv2 + tv5
+        let tv6 = tv4.square();
+        let tv2 = (tv3.square() + a * tv6) * tv3;
+        let tv6 = tv6 * tv4;
+        let tv2 = tv2 + b * tv6;
+        // 17. x = tv1 * tv3
+        // 18. (is_gx1_square, y1) = sqrt_ratio(tv2, tv6)
+        // 19. y = tv1 * u
+        // 20. y = y * y1
+        // 21. x = CMOV(x, tv3, is_gx1_square)
+        // 22. y = CMOV(y, y1, is_gx1_square)
+        let x = tv1 * tv3;
+        let (is_gx1_square, y1) = P::BaseField::ct_sqrt_ratio(&tv2, &tv6, &P::ZETA);
+        let y = tv1 * element * y1;
+        let x = P::BaseField::ct_select(&tv3, &x, is_gx1_square);
+        let y = P::BaseField::ct_select(&y1, &y, is_gx1_square);
+        // 23. e1 = sgn0(u) == sgn0(y)
+        // 24. y = CMOV(-y, y, e1)
+        // 25. x = x / tv4
+        let e1 = element.ct_sgn0() == y.ct_sgn0();
+        let y = P::BaseField::ct_select(&y, &-y, e1);
+        let x = x * tv4.ct_inverse();
+
+        let point_on_curve = Affine::new_unchecked(x, y);
+        debug_assert!(
+            point_on_curve.is_on_curve(),
+            "swu mapped to a point off the curve"
+        );
+        Ok(point_on_curve)
+    }
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(all(
@@ -293,4 +367,19 @@ mod test {
             "a constant hash function is not good."
         );
     }
+
+    /// The straight-line map must map every element to the same point as the
+    /// variable-time one.
+    #[test]
+    fn constant_time_map_field_to_curve_swu() {
+        ConstantTimeSWUMap::<TestSWUMapToCurveConfig>::check_parameters().unwrap();
+
+        for i in 0..127u64 {
+            let element = F127::from(i);
+            assert_eq!(
+                ConstantTimeSWUMap::<TestSWUMapToCurveConfig>::map_to_curve(element).unwrap(),
+                SWUMap::<TestSWUMapToCurveConfig>::map_to_curve(element).unwrap(),
+            );
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 
 use crate::{models::short_weierstrass::SWCurveConfig, CurveConfig};
-use ark_ff::batch_inversion;
+use ark_ff::{batch_inversion, AdditiveGroup, ConstantTimeField};
 use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
 
 use crate::{
@@ -10,7 +10,7 @@ use crate::{
     AffineRepr,
 };
 
-use super::swu::{SWUConfig, SWUMap};
+use super::swu::{ConstantTimeSWUMap, SWUConfig, SWUMap};
 type BaseField<MP> = <MP as CurveConfig>::BaseField;
 
 /// [`IsogenyMap`] defines an isogeny between curves of
@@ -63,6 +63,28 @@ where
             None => Ok(Affine::identity()),
         }
     }
+
+    /// Applies the isogeny to the affine point `(x, y)` without branching on
+    /// it, evaluating the polynomials with Horner's rule and inverting both
+    /// denominators at once with [`ConstantTimeField::ct_inverse`].
+    fn apply_ct(&self, (x, y): (BaseField<Domain>, BaseField<Domain>)) -> Affine<Codomain> {
+        let evaluate = |coeffs: &[BaseField<Domain>]| {
+            coeffs
+                .iter()
+                .rev()
+                .fold(BaseField::<Domain>::ZERO, |acc, c| acc * x + c)
+        };
+        let (x_num, x_den) = (
+            evaluate(self.x_map_numerator),
+            evaluate(self.x_map_denominator),
+        );
+        let (y_num, y_den) = (
+            evaluate(self.y_map_numerator),
+            evaluate(self.y_map_denominator),
+        );
+        let inv = (x_den * y_den).ct_inverse();
+        Affine::new_unchecked(x_num * y_den * inv, y_num * y * x_den * inv)
+    }
 }
 
 /// Trait defining the necessary parameters for the WB hash-to-curve method.
@@ -115,15 +137,45 @@ impl<P: WBConfig> MapToCurve<Projective<P>> for WBMap<P> {
     }
 }
 
+/// Represents the WB hash-to-curve map defined by `P`, computed with
+/// [`ConstantTimeSWUMap`] on the isogenous curve.
+///
+/// It maps every element to the same point as [`WBMap`], except for the
+/// negligible fraction of elements that are mapped to the kernel of the
+/// isogeny. Like [`ConstantTimeSWUMap`], it does not branch on the element,
+/// but is not constant time.
+pub struct ConstantTimeWBMap<P: WBConfig> {
+    swu_field_curve_hasher: PhantomData<ConstantTimeSWUMap<P::IsogenousCurve>>,
+    curve_params: PhantomData<fn() -> P>,
+}
+
+impl<P: WBConfig> MapToCurve<Projective<P>> for ConstantTimeWBMap<P> {
+    /// Checks if `P` represents a valid map.
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        WBMap::<P>::check_parameters()
+    }
+
+    /// Map random field point to a random curve point, without branching on
+    /// it.
+    fn map_to_curve(
+        element: <Affine<P> as AffineRepr>::BaseField,
+    ) -> Result<Affine<P>, HashToCurveError> {
+        let point_on_isogenous_curve =
+            ConstantTimeSWUMap::<P::IsogenousCurve>::map_to_curve(element)?;
+        // The SWU map never outputs the point at infinity.
+        Ok(P::ISOGENY_MAP.apply_ct((point_on_isogenous_curve.x, point_on_isogenous_curve.y)))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
         hashing::{
             curve_maps::{
                 swu::SWUConfig,
-                wb::{IsogenyMap, WBConfig, WBMap},
+                wb::{ConstantTimeWBMap, IsogenyMap, WBConfig, WBMap},
             },
-            map_to_curve_hasher::MapToCurveBasedHasher,
+            map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
             HashToCurve,
         },
         models::short_weierstrass::SWCurveConfig,
@@ -337,4 +389,19 @@ mod test {
             "hash results into a point off the curve"
         );
     }
+
+    /// The straight-line map must map every element to the same point as the
+    /// variable-time one.
+    #[test]
+    fn constant_time_map_field_to_curve_wb() {
+        ConstantTimeWBMap::<TestWBF127MapToCurveConfig>::check_parameters().unwrap();
+
+        for i in 0..127u64 {
+            let element = F127::from(i);
+            assert_eq!(
+                ConstantTimeWBMap::<TestWBF127MapToCurveConfig>::map_to_curve(element).unwrap(),
+                WBMap::<TestWBF127MapToCurveConfig>::map_to_curve(element).unwrap(),
+            );
+        }
+    }
 }
@@ -71,4 +71,24 @@ where
 
         Ok(rand_subgroup_elem)
     }
+
+    /// Produce a nonuniform encoding of the message, using the hash to field
+    /// and map to curve traits, as the IETF hash to curve's specification for
+    /// nonuniform encoding (encode_to_curve).
+    /// See <https://datatracker.ietf.org/doc/html/rfc9380#section-3>
+    fn encode(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        // IETF spec of encode_to_curve, from hash_to_field and map_to_curve
+        // sub-components
+        // 1. u = hash_to_field(msg, 1)
+        // 2. Q = map_to_curve(u[0])
+        // 3. P = clear_cofactor(Q)
+        // 4. return P
+
+        let [rand_field_elem] = self.field_hasher.hash_to_field::<1>(msg);
+
+        let rand_curve_elem = M2C::map_to_curve(rand_field_elem)?;
+        let rand_subgroup_elem = rand_curve_elem.clear_cofactor();
+
+        Ok(rand_subgroup_elem)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::CurveGroup;
+use ark_ff::field_hashers::HashToField;
 use ark_std::string::*;
 use core::fmt;
 
@@ -14,6 +15,28 @@ pub trait HashToCurve<T: CurveGroup>: Sized {
     /// The output of the hash is a curve point in the prime order subgroup
     /// of the given elliptic curve.
     fn hash(&self, message: &[u8]) -> Result<T::Affine, HashToCurveError>;
+
+    /// Produce a nonuniform encoding of the message, which also depends on the
+    /// domain. The output is a curve point in the prime order subgroup, but,
+    /// unlike [`HashToCurve::hash`], its distribution is not uniform: it is
+    /// cheaper to compute, and should only be used where a protocol allows it.
+    fn encode(&self, message: &[u8]) -> Result<T::Affine, HashToCurveError>;
+}
+
+/// Hashes `message` to an element of the scalar field of `T`, with the
+/// `hash_to_field` function of the field hasher `H2F` and the domain `domain`.
+///
+/// With `H2F` a [`DefaultFieldHasher`](ark_ff::field_hashers::DefaultFieldHasher),
+/// this is the `HashToScalar` function of the VOPRF and ECVRF ciphersuites,
+/// which hash to the scalar field as [\[HSSWW23\]] hashes to the base field.
+///
+/// - [\[HSSWW23\]] <https://datatracker.ietf.org/doc/html/rfc9380>
+pub fn hash_to_scalar<T: CurveGroup, H2F: HashToField<T::ScalarField>>(
+    domain: &[u8],
+    message: &[u8],
+) -> T::ScalarField {
+    let [scalar] = H2F::new(domain).hash_to_field::<1>(message);
+    scalar
 }
 
 /// This is an error that could occur during the hash to curve process
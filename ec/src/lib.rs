@@ -38,6 +38,8 @@ pub mod hashing;
 
 pub mod pairing;
 
+pub mod encoding;
+
 /// Represents (elements of) a group of prime order `r`.
 pub trait PrimeGroup: AdditiveGroup<Scalar = Self::ScalarField> {
     /// The scalar field `F_r`, where `r` is the order of this group.
@@ -1,4 +1,7 @@
-use ark_ff::{AdditiveGroup, BitIteratorBE, Field, Fp2};
+use ark_ff::{
+    fields::{fp12_2over3over2::Fp12Config, fp6_3over2::Fp6Config},
+    AdditiveGroup, BitIteratorBE, Field, Fp2,
+};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{vec::*, One};
 use educe::Educe;
@@ -147,3 +150,26 @@ impl<P: Bls12Config> G2HomProjective<P> {
         }
     }
 }
+
+/// The untwist-Frobenius-twist endomorphism `ψ` of the curve of G2, which acts
+/// on G2 as the multiplication by `p`.
+pub(crate) fn p_power_endomorphism<P: Bls12Config>(q: &G2Affine<P>) -> G2Affine<P> {
+    // The untwisting isomorphism maps (x, y) to (x * w^2, y * w^3) for a D-twist,
+    // and to (x / w^2, y / w^3) for an M-twist, where w^6 = ξ is the non-residue
+    // of Fp6. ψ thus maps (x, y) to (x^p * ξ^((p - 1) / 3), y^p * ξ^((p - 1) / 2))
+    // for a D-twist, and to the inverse coefficients for an M-twist.
+    let coeff_x = P::Fp6Config::FROBENIUS_COEFF_FP6_C1[1];
+    let coeff_y = P::Fp12Config::FROBENIUS_COEFF_FP12_C1[1];
+    let coeff_y = coeff_y * coeff_y.square();
+    let (coeff_x, coeff_y) = match P::TWIST_TYPE {
+        TwistType::M => (coeff_x.inverse().unwrap(), coeff_y.inverse().unwrap()),
+        TwistType::D => (coeff_x, coeff_y),
+    };
+
+    let mut res = *q;
+    res.x.frobenius_map_in_place(1);
+    res.x *= coeff_x;
+    res.y.frobenius_map_in_place(1);
+    res.y *= coeff_y;
+    res
+}
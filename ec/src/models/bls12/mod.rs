@@ -1,7 +1,10 @@
 use crate::{
-    models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    models::{
+        short_weierstrass::{self, SWCurveConfig},
+        CurveConfig,
+    },
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
-    AffineRepr,
+    AffineRepr, PrimeGroup,
 };
 use ark_ff::{
     fields::{
@@ -155,6 +158,55 @@ pub trait Bls12Config: 'static + Sized {
             PairingOutput(r)
         })
     }
+
+    /// Checks that `p`, a point of the curve of G1, is in G1.
+    ///
+    /// The endomorphism `φ(x, y) = (ωx, y)`, for a primitive cube root of
+    /// unity `ω`, acts on G1 as the multiplication by `-X^2` for one of the
+    /// two choices of `ω`. As `X^4 - X^2 + 1` is the order `r` of G1, the
+    /// kernels of `φ + [X^2]` and of `φ^2 + [X^2]` have order `r`, so that a
+    /// point of the curve is in G1 if and only if it is in one of them
+    /// (<https://eprint.iacr.org/2021/1130>).
+    fn is_in_g1_assuming_on_curve(p: &G1Affine<Self>) -> bool {
+        // ω = ξ^((p^2 - 1) / 3), where ξ is the cubic non-residue of Fp6.
+        let omega = Self::Fp6Config::FROBENIUS_COEFF_FP6_C1[2].c0;
+        let x_square_p = p.mul_bigint(Self::X).mul_bigint(Self::X);
+        short_weierstrass::is_cube_root_endomorphism_image(&-x_square_p, &(*p).into(), omega)
+    }
+
+    /// Checks that `p`, a point of the curve of G2, is in G2.
+    ///
+    /// The untwist-Frobenius-twist endomorphism `ψ` acts on G2 as the
+    /// multiplication by `p`, which is `X` modulo `r`, and a point of the
+    /// curve is in G2 if and only if `ψ(p) = [X]p`
+    /// (<https://eprint.iacr.org/2021/1130>).
+    fn is_in_g2_assuming_on_curve(p: &G2Affine<Self>) -> bool {
+        let mut x_times_p = p.mul_bigint(Self::X);
+        if Self::X_IS_NEGATIVE {
+            x_times_p = -x_times_p;
+        }
+        x_times_p == g2::p_power_endomorphism::<Self>(p)
+    }
+
+    /// Checks that `f` is in the target group of the pairing.
+    ///
+    /// `f` is in the cyclotomic subgroup of order `Φ12(p) = p^4 - p^2 + 1` if
+    /// and only if `f^(p^4) * f = f^(p^2)`, and an element of the cyclotomic
+    /// subgroup is in the target group if and only if `f^p = f^X`, as the
+    /// greatest common divisor of `p - X` and `Φ12(p)` is `r`
+    /// (<https://eprint.iacr.org/2021/1130>).
+    fn is_in_target_group(f: &Fp12<Self::Fp12Config>) -> bool {
+        if f.is_zero() {
+            return false;
+        }
+        let f_p2 = f.frobenius_map(2);
+        if f_p2.frobenius_map(2) * f != f_p2 {
+            return false;
+        }
+        let mut f_x = Fp12::zero();
+        Bls12::<Self>::exp_by_x(f, &mut f_x);
+        f.frobenius_map(1) == f_x
+    }
 }
 
 pub mod g1;
@@ -221,4 +273,8 @@ impl<P: Bls12Config> Pairing for Bls12<P> {
     fn final_exponentiation(f: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
         P::final_exponentiation(f)
     }
+
+    fn is_in_target_group(f: &Self::TargetField) -> bool {
+        P::is_in_target_group(f)
+    }
 }
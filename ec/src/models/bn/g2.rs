@@ -168,7 +168,7 @@ impl<P: BnConfig> G2Prepared<P> {
     }
 }
 
-fn mul_by_char<P: BnConfig>(r: G2Affine<P>) -> G2Affine<P> {
+pub(crate) fn mul_by_char<P: BnConfig>(r: G2Affine<P>) -> G2Affine<P> {
     // multiply by field characteristic
 
     let mut s = r;
@@ -179,3 +179,18 @@ fn mul_by_char<P: BnConfig>(r: G2Affine<P>) -> G2Affine<P> {
 
     s
 }
+
+/// Applies the untwist-Frobenius-twist endomorphism `ψ` to `r`, in Jacobian
+/// coordinates.
+pub(crate) fn mul_by_char_projective<P: BnConfig>(r: &G2Projective<P>) -> G2Projective<P> {
+    // As the Frobenius is a field automorphism, it can be applied to the
+    // projective coordinates.
+    let mut s = *r;
+    s.x.frobenius_map_in_place(1);
+    s.x *= &P::TWIST_MUL_BY_Q_X;
+    s.y.frobenius_map_in_place(1);
+    s.y *= &P::TWIST_MUL_BY_Q_Y;
+    s.z.frobenius_map_in_place(1);
+
+    s
+}
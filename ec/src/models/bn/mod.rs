@@ -1,6 +1,7 @@
 use crate::{
     models::{short_weierstrass::SWCurveConfig, CurveConfig},
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    AdditiveGroup, AffineRepr, CurveGroup, PrimeGroup,
 };
 use ark_ff::{
     fields::{
@@ -14,7 +15,7 @@ use ark_ff::{
 use ark_std::{cfg_chunks_mut, marker::PhantomData, vec::*};
 use educe::Educe;
 use itertools::Itertools;
-use num_traits::One;
+use num_traits::{One, Zero};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -164,6 +165,62 @@ pub trait BnConfig: 'static + Sized {
             PairingOutput(y16)
         })
     }
+
+    /// Checks that `p`, a point of the curve of G1, is in G1, which is always
+    /// the case as BN curves have prime order.
+    fn is_in_g1_assuming_on_curve(_p: &G1Affine<Self>) -> bool {
+        true
+    }
+
+    /// Checks that `p`, a point of the curve of G2, is in G2.
+    ///
+    /// The untwist-Frobenius-twist endomorphism `ψ` acts on G2 as the
+    /// multiplication by `p`, which is `6X^2` modulo `r`, and a point of the
+    /// curve is in G2 if and only if `ψ(p) = [6X^2]p`
+    /// (<https://eprint.iacr.org/2022/352>, Section 4.3).
+    fn is_in_g2_assuming_on_curve(p: &G2Affine<Self>) -> bool {
+        let x_square_p = p.mul_bigint(Self::X).mul_bigint(Self::X);
+        let three_x_square_p = x_square_p.double() + x_square_p;
+        three_x_square_p.double() == g2::mul_by_char::<Self>(*p)
+    }
+
+    /// Maps `p`, a point of the curve of G2, to G2.
+    ///
+    /// Computes `[X]p + ψ([3X]p) + ψ^2([X]p) + ψ^3(p)`, which is the
+    /// multiplication by a multiple of the cofactor, with multiplications by
+    /// `X` only instead of by the cofactor, whose bit length is four times
+    /// that of `X` (Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, "Faster
+    /// hashing to G2", Section 6.1).
+    fn clear_cofactor_g2(p: &G2Affine<Self>) -> G2Affine<Self> {
+        let psi = g2::mul_by_char_projective::<Self>;
+        let mut x_p = p.mul_bigint(Self::X);
+        if Self::X_IS_NEGATIVE {
+            x_p = -x_p;
+        }
+        let psi_3x_p = psi(&(x_p.double() + x_p));
+        let psi2_x_p = psi(&psi(&x_p));
+        let psi3_p = psi(&psi(&psi(&p.into_group())));
+        (x_p + psi_3x_p + psi2_x_p + psi3_p).into_affine()
+    }
+
+    /// Checks that `f` is in the target group of the pairing.
+    ///
+    /// `f` is in the cyclotomic subgroup of order `Φ12(p) = p^4 - p^2 + 1` if
+    /// and only if `f^(p^4) * f = f^(p^2)`, and as `p - 6X^2 = r`, an element
+    /// of the cyclotomic subgroup is in the target group if and only if
+    /// `f^p = f^(6X^2)`.
+    fn is_in_target_group(f: &Fp12<Self::Fp12Config>) -> bool {
+        if f.is_zero() {
+            return false;
+        }
+        let f_p2 = f.frobenius_map(2);
+        if f_p2.frobenius_map(2) * f != f_p2 {
+            return false;
+        }
+        let f_x_square = f.cyclotomic_exp(Self::X).cyclotomic_exp(Self::X);
+        let f_two_x_square = f_x_square.cyclotomic_square();
+        f.frobenius_map(1) == f_two_x_square.cyclotomic_square() * f_two_x_square
+    }
 }
 
 pub mod g1;
@@ -229,4 +286,8 @@ impl<P: BnConfig> Pairing for Bn<P> {
     fn final_exponentiation(f: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
         P::final_exponentiation(f)
     }
+
+    fn is_in_target_group(f: &Self::TargetField) -> bool {
+        P::is_in_target_group(f)
+    }
 }
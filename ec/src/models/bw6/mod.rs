@@ -1,6 +1,10 @@
 use crate::{
-    models::{short_weierstrass::SWCurveConfig, CurveConfig},
+    models::{
+        short_weierstrass::{self, Affine, Projective, SWCurveConfig},
+        CurveConfig,
+    },
     pairing::{MillerLoopOutput, Pairing, PairingOutput},
+    AdditiveGroup, AffineRepr, CurveGroup, PrimeGroup,
 };
 use ark_ff::{
     fields::{
@@ -13,7 +17,7 @@ use ark_ff::{
 use ark_std::cfg_chunks_mut;
 use educe::Educe;
 use itertools::Itertools;
-use num_traits::One;
+use num_traits::{One, Zero};
 
 use ark_std::{marker::PhantomData, vec::*};
 
@@ -39,7 +43,6 @@ pub trait BW6Config: 'static + Eq + Sized {
     const H_T: i64;
     const H_Y: i64;
     const T_MOD_R_IS_ZERO: bool;
-
     type Fp: PrimeField + Into<<Self::Fp as PrimeField>::BigInt>;
     type Fp3Config: Fp3Config<Fp = Self::Fp>;
     type Fp6Config: Fp6Config<Fp3Config = Self::Fp3Config>;
@@ -177,6 +180,87 @@ pub trait BW6Config: 'static + Eq + Sized {
 
         MillerLoopOutput(f_1 * &f_2)
     }
+
+    /// Checks that `p`, a point of the curve of G1, is in G1.
+    ///
+    /// With `t = (X - 1) / 3`, `a = -(9t^3 + 6t^2 + 2t + 1)` and
+    /// `b = 9t^3 + 6t^2 - t - 1`, `a^2 - ab + b^2` is `r`. The endomorphism
+    /// `φ(x, y) = (ωx, y)`, for a primitive cube root of unity `ω`, acts on
+    /// G1 as the multiplication by a cube root of unity modulo `r`, and
+    /// `a + bφ` is zero on G1 for one of the two choices of `ω`. The kernels
+    /// of `a + bφ` and of `a + bφ^2` have order `r`, so that a point of the
+    /// curve is in G1 if and only if it is in one of them
+    /// (see <https://eprint.iacr.org/2022/352>).
+    fn is_in_g1_assuming_on_curve(p: &G1Affine<Self>) -> bool {
+        BW6::<Self>::is_in_subgroup_assuming_on_curve(p)
+    }
+
+    /// Checks that `p`, a point of the curve of G2, is in G2, in the same way
+    /// as [`Self::is_in_g1_assuming_on_curve`].
+    fn is_in_g2_assuming_on_curve(p: &G2Affine<Self>) -> bool {
+        BW6::<Self>::is_in_subgroup_assuming_on_curve(p)
+    }
+
+    /// Maps `p`, a point of the curve of G1, to G1.
+    ///
+    /// With `φ` acting as `ω`, the Frobenius of the curve is an element `π` of
+    /// `Z[ω]`, and the group of points of the curve is `Z[ω] / (π - 1)`. With
+    /// `a` and `b` as in [`Self::is_in_g1_assuming_on_curve`], `π - 1` is
+    /// `ργ` for `ρ = a + bω`, so that multiplying by `γ` maps the curve onto
+    /// G1. `π` is `π_0 + rc` for `c = (H_T + H_Y) / 2 + H_Yω`, and
+    /// `γ = γ_0 + ρ̄c`, where `γ_0 = (π_0 - 1) / ρ` is `9t^2 - ω` if
+    /// `Self::T_MOD_R_IS_ZERO`, and `-(9t^2 + 1) - 9t^2ω` otherwise. The
+    /// coefficients of `γ` are about half as long as the cofactor
+    /// (see <https://eprint.iacr.org/2022/352>).
+    fn clear_cofactor_g1(p: &G1Affine<Self>) -> G1Affine<Self> {
+        let c = ((Self::H_T + Self::H_Y) / 2, Self::H_Y);
+        BW6::<Self>::clear_cofactor(p, c, BW6::<Self>::cube_root_of_unity(true))
+    }
+
+    /// Maps `p`, a point of the curve of G2, to G2, in the same way as
+    /// [`Self::clear_cofactor_g1`], where `c` is `-H_Y - (H_T + H_Y) / 2 ω` if
+    /// `Self::T_MOD_R_IS_ZERO`, and `(H_T + H_Y) / 2 + (H_T - H_Y) / 2 ω`
+    /// otherwise.
+    fn clear_cofactor_g2(p: &G2Affine<Self>) -> G2Affine<Self> {
+        let c = if Self::T_MOD_R_IS_ZERO {
+            (-Self::H_Y, -(Self::H_T + Self::H_Y) / 2)
+        } else {
+            ((Self::H_T + Self::H_Y) / 2, (Self::H_T - Self::H_Y) / 2)
+        };
+        BW6::<Self>::clear_cofactor(p, c, BW6::<Self>::cube_root_of_unity(false))
+    }
+
+    /// Checks that `f` is in the target group of the pairing.
+    ///
+    /// `f` is in the cyclotomic subgroup of order `Φ6(p) = p^2 - p + 1` if and
+    /// only if `f^(p^2) * f = f^p`. The Frobenius acts on the target group as
+    /// the exponentiation by `p`, and `-p` is a primitive cube root of unity
+    /// modulo `r`, so that with `a` and `b` as in
+    /// [`Self::is_in_g1_assuming_on_curve`], either `a - bp` or
+    /// `(a - b) + bp` is a multiple of `r`. The greatest common divisor of
+    /// each of them and `Φ6(p)` is either `r` or `1`, so that an element of
+    /// the cyclotomic subgroup is in the target group if and only if
+    /// `f^a = (f^b)^p` or `f^(a - b) * (f^b)^p = 1`.
+    fn is_in_target_group(f: &Fp6<Self::Fp6Config>) -> bool {
+        if f.is_zero() {
+            return false;
+        }
+        let f_p = f.frobenius_map(1);
+        if f_p.frobenius_map(1) * f != f_p {
+            return false;
+        }
+        let f_t = BW6::<Self>::exp_by_x_minus_1_div_3(f);
+        let f_t2 = BW6::<Self>::exp_by_x_minus_1_div_3(&f_t);
+        let f_t3 = BW6::<Self>::exp_by_x_minus_1_div_3(&f_t2);
+        // f^(9t^3 + 6t^2)
+        let f_s = f_t3.cyclotomic_square() * f_t3 * f_t2.cyclotomic_square();
+        let f_s = f_s.cyclotomic_square() * f_s;
+        // f^(-a) and f^b
+        let f_minus_a = f_s * f_t.cyclotomic_square() * f;
+        let f_b = f_s * (f_t * f).cyclotomic_inverse().unwrap();
+        let f_b_p = f_b.frobenius_map(1);
+        (f_minus_a * f_b_p).is_one() || f_b_p == f_minus_a * f_b
+    }
 }
 
 pub mod g1;
@@ -192,6 +276,100 @@ pub use self::{
 pub struct BW6<P: BW6Config>(PhantomData<fn() -> P>);
 
 impl<P: BW6Config> BW6<P> {
+    /// Returns `[t^2]p`, `[-a]p` and `[b]p`, with `t`, `a` and `b` as in
+    /// [`BW6Config::is_in_g1_assuming_on_curve`].
+    fn endomorphism_multiples<C: SWCurveConfig<BaseField = P::Fp>>(
+        p: &Projective<C>,
+    ) -> (Projective<C>, Projective<C>, Projective<C>) {
+        let mul_by_t = |q: &Projective<C>| {
+            let t_q = q.mul_bigint(P::X_MINUS_1_DIV_3);
+            if P::X_IS_NEGATIVE {
+                -t_q
+            } else {
+                t_q
+            }
+        };
+        let t_p = mul_by_t(p);
+        let t2_p = mul_by_t(&t_p);
+        let t3_p = mul_by_t(&t2_p);
+        // [9t^3 + 6t^2]p
+        let s_p = t3_p.double() + t3_p + t2_p.double();
+        let s_p = s_p.double() + s_p;
+        // [-a]p and [b]p
+        let minus_a_p = s_p + t_p.double() + p;
+        let b_p = s_p - t_p - p;
+        (t2_p, minus_a_p, b_p)
+    }
+
+    /// Checks that `p`, a point of the curve of G1 or of G2, is in the
+    /// subgroup of order `r`, as described in
+    /// [`BW6Config::is_in_g1_assuming_on_curve`].
+    fn is_in_subgroup_assuming_on_curve<C: SWCurveConfig<BaseField = P::Fp>>(
+        p: &Affine<C>,
+    ) -> bool {
+        // ω = ν^((p - 1) / 3), where ν is the cubic non-residue of Fp3.
+        let omega = P::Fp3Config::FROBENIUS_COEFF_FP3_C1[1];
+        let (_, minus_a_p, b_p) = Self::endomorphism_multiples(&p.into_group());
+        short_weierstrass::is_cube_root_endomorphism_image(&minus_a_p, &b_p, omega)
+    }
+
+    /// Maps `p`, a point of the curve of G1 or of G2, to the subgroup of
+    /// order `r`, as described in [`BW6Config::clear_cofactor_g1`], where `c`
+    /// is the coefficient of `r` in the Frobenius of the curve, and `omega`
+    /// is the cube root of unity for which `φ` acts on the subgroup as the
+    /// multiplication by `-a / b`.
+    fn clear_cofactor<C: SWCurveConfig<BaseField = P::Fp>>(
+        p: &Affine<C>,
+        (c0, c1): (i64, i64),
+        omega: P::Fp,
+    ) -> Affine<C> {
+        let mul_by_small = |q: &Projective<C>, c: i64| {
+            let c_q = q.mul_bigint([c.unsigned_abs()]);
+            if c < 0 {
+                -c_q
+            } else {
+                c_q
+            }
+        };
+        let p = p.into_group();
+        let (t2_p, minus_a_p, b_p) = Self::endomorphism_multiples(&p);
+        // The components of ρ̄ = (a - b) - bω.
+        let rho_0_p = -(minus_a_p + b_p);
+        let rho_1_p = -b_p;
+        // The components of γ_0.
+        let nine_t2_p = mul_by_small(&t2_p, 9);
+        let (gamma_0_p, gamma_1_p) = if P::T_MOD_R_IS_ZERO {
+            (nine_t2_p, -p)
+        } else {
+            (-(nine_t2_p + p), -nine_t2_p)
+        };
+        // The components of γ = γ_0 + ρ̄c.
+        let gamma_0_p = gamma_0_p + mul_by_small(&rho_0_p, c0) - mul_by_small(&rho_1_p, c1);
+        let mut gamma_1_p =
+            gamma_1_p + mul_by_small(&rho_1_p, c0 - c1) + mul_by_small(&rho_0_p, c1);
+        gamma_1_p.x *= omega;
+        (gamma_0_p + gamma_1_p).into_affine()
+    }
+
+    /// Returns the cube root of unity `ω` for which `φ(x, y) = (ωx, y)` acts
+    /// on G1, if `g1` is true, or on G2 otherwise, as the multiplication by
+    /// `-a / b`.
+    fn cube_root_of_unity(g1: bool) -> P::Fp {
+        // ν^((p - 1) / 3) and ν^((p^2 - 1) / 3) = ν^(2(p - 1) / 3), where ν is
+        // the cubic non-residue of Fp3. φ acts on G1 as the multiplication by
+        // `-a / b` for the first one if and only if `φ([b]g) = [-a]g` for
+        // the generator `g` of G1, and `φ^2` then does so on G2.
+        let omega = P::Fp3Config::FROBENIUS_COEFF_FP3_C1[1];
+        let g = G1Affine::<P>::generator().into_group();
+        let (_, minus_a_g, mut phi_b_g) = Self::endomorphism_multiples(&g);
+        phi_b_g.x *= omega;
+        if g1 == (phi_b_g == minus_a_g) {
+            omega
+        } else {
+            P::Fp3Config::FROBENIUS_COEFF_FP3_C1[2]
+        }
+    }
+
     // Evaluate the line function at point p.
     fn ell(f: &mut Fp6<P::Fp6Config>, coeffs: &(P::Fp, P::Fp, P::Fp), p: &G1Affine<P>) {
         let mut c0 = coeffs.0;
@@ -354,4 +532,8 @@ impl<P: BW6Config> Pairing for BW6<P> {
     ) -> MillerLoopOutput<Self> {
         P::multi_miller_loop(a, b)
     }
+
+    fn is_in_target_group(f: &Self::TargetField) -> bool {
+        P::is_in_target_group(f)
+    }
 }
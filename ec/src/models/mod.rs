@@ -6,6 +6,7 @@ pub mod bw6;
 pub mod mnt4;
 pub mod mnt6;
 
+pub mod montgomery;
 pub mod short_weierstrass;
 pub mod twisted_edwards;
 
@@ -0,0 +1,114 @@
+use ark_std::fmt::{Display, Formatter, Result as FmtResult};
+
+use ark_ff::{fields::Field, AdditiveGroup};
+use educe::Educe;
+use num_traits::{One, Zero};
+
+use super::{MontCurveConfig, XZProjective};
+use crate::models::twisted_edwards::Affine as TEAffine;
+
+/// Affine coordinates for a point on a Montgomery curve, over the base field
+/// `P::BaseField`.
+///
+/// The point at infinity has no affine coordinates, and is not representable.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct MontgomeryAffine<P: MontCurveConfig> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+}
+
+impl<P: MontCurveConfig> Display for MontgomeryAffine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "MontgomeryAffine(x={}, y={})", self.x, self.y)
+    }
+}
+
+impl<P: MontCurveConfig> MontgomeryAffine<P> {
+    pub const fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        Self { x, y }
+    }
+
+    /// Checks if `self` is a valid point on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        P::COEFF_B * self.y.square() == (x2 + P::COEFF_A * self.x + P::BaseField::one()) * self.x
+    }
+
+    /// Returns the image of the twisted Edwards point `p` under the map
+    /// `(x, y) ↦ ((1 + y) / (1 - y), (1 + y) / ((1 - y) * x))`, which sends
+    /// `(0, -1)` to `(0, 0)`, or `None` if `p` is the identity, which has no
+    /// affine image.
+    pub fn from_te(p: &TEAffine<P::TECurveConfig>) -> Option<Self> {
+        let one = P::BaseField::one();
+        if p.x.is_zero() {
+            return (p.y == -one).then(|| Self::new(P::BaseField::zero(), P::BaseField::zero()));
+        }
+        let u = (one + p.y) * (one - p.y).inverse()?;
+        let v = u * p.x.inverse()?;
+        Some(Self::new(u, v))
+    }
+
+    /// Returns the image of `self` on the twisted Edwards curve under the map
+    /// `(u, v) ↦ (u / v, (u - 1) / (u + 1))`, the inverse of
+    /// [`MontgomeryAffine::from_te`], or `None` if `self` is one of the
+    /// exceptional points with `v = 0` or `u = -1`, other than `(0, 0)`.
+    pub fn to_te(&self) -> Option<TEAffine<P::TECurveConfig>> {
+        let one = P::BaseField::one();
+        if self.x.is_zero() {
+            return self
+                .y
+                .is_zero()
+                .then(|| TEAffine::new_unchecked(P::BaseField::zero(), -one));
+        }
+        let x = self.x * self.y.inverse()?;
+        let y = (self.x - one) * (self.x + one).inverse()?;
+        Some(TEAffine::new_unchecked(x, y))
+    }
+
+    /// Returns `[k] * self`, where `k` is the integer whose little-endian limbs
+    /// are `by`, or `None` if it is the point at infinity.
+    ///
+    /// The multiple is computed with [`XZProjective::ladder`],
+    /// after which its `y`-coordinate is recovered with the formula of
+    /// [\[OS01\]](https://doi.org/10.1007/3-540-44709-1_12).
+    pub fn mul_bigint(&self, by: impl AsRef<[u64]>) -> Option<Self> {
+        let by = by.as_ref();
+        if self.y.is_zero() {
+            // `self` has order 2, and the ladder does not apply.
+            let is_odd = by.first().is_some_and(|limb| limb & 1 == 1);
+            return is_odd.then_some(*self);
+        }
+        let (q, r) = XZProjective::from(*self).ladder(by);
+        self.recover(&q, &r)
+    }
+
+    /// Returns the affine point `q`, given the `x`-only points `q` and
+    /// `r = q + self`, or `None` if `q` is the point at infinity.
+    ///
+    /// The sign of `q` is fixed by `r`, since `q - self` and `q + self` differ.
+    ///
+    /// `self` must not have order 2.
+    fn recover(&self, q: &XZProjective<P>, r: &XZProjective<P>) -> Option<Self> {
+        let (x, y) = (self.x, self.y);
+        if r.is_zero() {
+            return Some(Self::new(x, -y));
+        }
+        let mut t1 = x * q.z;
+        let mut t2 = q.x + t1;
+        let t3 = (q.x - t1).square() * r.x;
+        t1 = P::COEFF_A.double() * q.z;
+        t2 += t1;
+        let t4 = x * q.x + q.z;
+        t2 *= t4;
+        t1 *= q.z;
+        t2 -= t1;
+        t2 *= r.z;
+        let y_q = t2 - t3;
+        t1 = P::COEFF_B.double() * y * q.z * r.z;
+        let x_q = t1 * q.x;
+        let z_q = t1 * q.z;
+        let z_inv = z_q.inverse()?;
+        Some(Self::new(x_q * z_inv, y_q * z_inv))
+    }
+}
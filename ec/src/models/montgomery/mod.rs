@@ -0,0 +1,26 @@
+use super::twisted_edwards::TECurveConfig;
+
+mod affine;
+pub use affine::*;
+
+mod xz;
+pub use xz::*;
+
+/// Constants and convenience functions that collectively define the [Montgomery model](https://www.hyperelliptic.org/EFD/g1p/auto-montgom.html)
+/// of the curve.
+///
+/// In this model, the curve equation is `b * y² = x³ + a * x² + x`, for constants `a` and `b`.
+///
+/// The model is birationally equivalent to the twisted Edwards curve with
+/// coefficients `(a + 2) / b` and `(a - 2) / b`, via the maps of
+/// [\[BBJLP08, Theorem 3.2\]](https://eprint.iacr.org/2008/013.pdf).
+pub trait MontCurveConfig: super::CurveConfig {
+    /// Coefficient `a` of the curve equation.
+    const COEFF_A: Self::BaseField;
+    /// Coefficient `b` of the curve equation.
+    const COEFF_B: Self::BaseField;
+
+    /// Model parameters for the Twisted Edwards curve that is birationally
+    /// equivalent to this curve.
+    type TECurveConfig: TECurveConfig<BaseField = Self::BaseField>;
+}
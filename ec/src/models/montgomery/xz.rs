@@ -0,0 +1,204 @@
+use ark_std::fmt::{Display, Formatter, Result as FmtResult};
+
+use ark_ff::{fields::Field, AdditiveGroup, ConstantTimeField};
+use educe::Educe;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+use super::{MontCurveConfig, MontgomeryAffine};
+use crate::models::twisted_edwards::Affine as TEAffine;
+
+/// `XZProjective` implements the x-only projective coordinates `(X : Z)` of
+/// [\[Mon87\]](https://doi.org/10.1090/S0025-5718-1987-0866113-7), which
+/// represent the points `±(X / Z, y)` of a Montgomery curve, with `(1 : 0)`
+/// being the point at infinity.
+///
+/// Since `P` and `-P` share a representation, there is no addition: the sum
+/// `P + Q` is computed by [`XZProjective::differential_add`] from the
+/// difference `P - Q`, which suffices for the
+/// [Montgomery ladder](XZProjective::ladder), as used by
+/// [X25519](https://www.rfc-editor.org/rfc/rfc7748).
+///
+/// As the arithmetic only depends on the `x`-coordinate, it is the same on the
+/// curve and on its quadratic twist: any `X / Z` represents a point of one of
+/// them.
+#[derive(Educe)]
+#[educe(Copy, Clone, Debug)]
+#[must_use]
+pub struct XZProjective<P: MontCurveConfig> {
+    /// `X` coordinate, such that the affine `x` coordinate is `X / Z`
+    pub x: P::BaseField,
+    /// `Z` coordinate
+    pub z: P::BaseField,
+}
+
+impl<P: MontCurveConfig> Display for XZProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.x() {
+            Some(x) => write!(f, "XZProjective(x={})", x),
+            None => write!(f, "XZProjective(infinity)"),
+        }
+    }
+}
+
+impl<P: MontCurveConfig> PartialEq for XZProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        // `(0 : 0)` is not a valid representation, and equals nothing.
+        self.x * other.z == other.x * self.z && !(self.x.is_zero() && self.z.is_zero())
+    }
+}
+
+impl<P: MontCurveConfig> Eq for XZProjective<P> {}
+
+impl<P: MontCurveConfig> Zeroize for XZProjective<P> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: MontCurveConfig> Default for XZProjective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: MontCurveConfig> XZProjective<P> {
+    /// Constructs a new point from its `X` and `Z` coordinates.
+    pub const fn new(x: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, z }
+    }
+
+    /// Returns the point at infinity, `(1 : 0)`.
+    pub fn zero() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero())
+    }
+
+    /// Returns `true` if `self` is the point at infinity.
+    pub fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Returns the point `(x : 1)`.
+    pub fn from_x(x: P::BaseField) -> Self {
+        Self::new(x, P::BaseField::one())
+    }
+
+    /// Returns the affine `x` coordinate of `self`, or `None` if `self` is the
+    /// point at infinity.
+    pub fn x(&self) -> Option<P::BaseField> {
+        self.z.inverse().map(|z_inv| self.x * z_inv)
+    }
+
+    /// Returns `[2] * self`.
+    ///
+    /// The formula is the doubling step of the ladder of
+    /// [\[RFC7748, Section 5\]](https://www.rfc-editor.org/rfc/rfc7748#section-5),
+    /// generalized to any coefficient `a`.
+    pub fn double(&self) -> Self {
+        self.double_with(&a24::<P>())
+    }
+
+    /// Returns `self + other`, given their difference `difference = self - other`.
+    ///
+    /// The result is only meaningful if `difference` is neither the point at
+    /// infinity nor the point `(0, 0)` of order 2, as in the ladder, whose
+    /// difference is the base point.
+    pub fn differential_add(&self, other: &Self, difference: &Self) -> Self {
+        let d = (self.x - self.z) * (other.x + other.z);
+        let c = (self.x + self.z) * (other.x - other.z);
+        Self::new(
+            difference.z * (d + c).square(),
+            difference.x * (d - c).square(),
+        )
+    }
+
+    /// Returns `([k] * self, [k + 1] * self)`, where `k` is the integer whose
+    /// little-endian limbs are `by`, with the Montgomery ladder of
+    /// [\[RFC7748, Section 5\]](https://www.rfc-editor.org/rfc/rfc7748#section-5).
+    ///
+    /// The ladder performs the same field operations for every bit of `by`,
+    /// including its leading zeros, and swaps its registers with
+    /// [`ConstantTimeField::ct_select`], so that its sequence of field
+    /// operations only depends on the number of limbs of `by`, and not on
+    /// their values. The field arithmetic itself is not constant time (see
+    /// [`ConstantTimeField`]).
+    ///
+    /// `self` must not be the point at infinity or the point `(0, 0)`.
+    pub fn ladder(&self, by: impl AsRef<[u64]>) -> (Self, Self) {
+        let a24 = a24::<P>();
+        let mut r0 = Self::zero();
+        let mut r1 = *self;
+        let mut swap = false;
+        for limb in by.as_ref().iter().rev() {
+            for i in (0..64).rev() {
+                let bit = (limb >> i) & 1 == 1;
+                // Swap the registers if the current bit differs from the previous one.
+                Self::ct_swap(&mut r0, &mut r1, swap ^ bit);
+                swap = bit;
+                r1 = r0.differential_add(&r1, self);
+                r0 = r0.double_with(&a24);
+            }
+        }
+        Self::ct_swap(&mut r0, &mut r1, swap);
+        (r0, r1)
+    }
+
+    /// Returns `[k] * self`, where `k` is the integer whose little-endian limbs
+    /// are `by`, computed with [`XZProjective::ladder`].
+    ///
+    /// `self` must not be the point at infinity or the point `(0, 0)`.
+    pub fn mul_bigint(&self, by: impl AsRef<[u64]>) -> Self {
+        self.ladder(by).0
+    }
+
+    /// Doubles `self` with the precomputed constant `a24 = (a + 2) / 4`.
+    ///
+    /// [\[RFC7748\]](https://www.rfc-editor.org/rfc/rfc7748#section-5) writes
+    /// the formula with `(a - 2) / 4`, which is equivalent.
+    fn double_with(&self, a24: &P::BaseField) -> Self {
+        let aa = (self.x + self.z).square();
+        let bb = (self.x - self.z).square();
+        let e = aa - bb;
+        Self::new(aa * bb, e * (bb + *a24 * e))
+    }
+
+    /// Swaps `a` and `b` if `choice` is `true`.
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let (x0, x1) = (
+            P::BaseField::ct_select(&b.x, &a.x, choice),
+            P::BaseField::ct_select(&a.x, &b.x, choice),
+        );
+        let (z0, z1) = (
+            P::BaseField::ct_select(&b.z, &a.z, choice),
+            P::BaseField::ct_select(&a.z, &b.z, choice),
+        );
+        *a = Self::new(x0, z0);
+        *b = Self::new(x1, z1);
+    }
+}
+
+/// Returns `(a + 2) / 4`, where `a` is the coefficient of the curve.
+fn a24<P: MontCurveConfig>() -> P::BaseField {
+    let two = P::BaseField::one().double();
+    (P::COEFF_A + two) * two.double().inverse().unwrap()
+}
+
+impl<P: MontCurveConfig> From<MontgomeryAffine<P>> for XZProjective<P> {
+    #[inline]
+    fn from(p: MontgomeryAffine<P>) -> Self {
+        Self::from_x(p.x)
+    }
+}
+
+// The twisted Edwards point `(x, y)` corresponds to the Montgomery
+// `x`-coordinate `(1 + y) / (1 - y)`, which maps the identity `(0, 1)` to the
+// point at infinity `(2 : 0)`.
+impl<P: MontCurveConfig> From<TEAffine<P::TECurveConfig>> for XZProjective<P> {
+    #[inline]
+    fn from(p: TEAffine<P::TECurveConfig>) -> Self {
+        let one = P::BaseField::one();
+        Self::new(one + p.y, one - p.y)
+    }
+}
@@ -1,8 +1,11 @@
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    envelope::{ParametersHasher, ParametersId},
+    CanonicalDeserialize, CanonicalSerialize, Compress, RawLayout, SerializationError, Valid,
+    Validate,
 };
 use ark_std::{
     borrow::Borrow,
+    cfg_into_iter, cfg_iter,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     io::{Read, Write},
     ops::{Add, Mul, Neg, Sub},
@@ -17,6 +20,8 @@ use ark_std::{
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 
 use educe::Educe;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use zeroize::Zeroize;
 
 use super::{bucket::Bucket, Projective, SWCurveConfig, SWFlags, ZeroFlag};
@@ -127,18 +132,59 @@ impl<P: SWCurveConfig> Affine<P> {
     /// The results are sorted by lexicographical order.
     /// This means that, if `P::BaseField: PrimeField`, the results are sorted as integers.
     pub fn get_ys_from_x_unchecked(x: P::BaseField) -> Option<(P::BaseField, P::BaseField)> {
-        // Compute the curve equation x^3 + Ax + B.
+        Self::x3_plus_ax_plus_b(x).sqrt().map(Self::sort_ys)
+    }
+
+    /// Returns the two possible y-coordinates corresponding to each of the
+    /// given x-coordinates, as [`Self::get_ys_from_x_unchecked`] does.
+    ///
+    /// The square roots are computed at once with [`Field::batch_sqrt`].
+    pub fn batch_get_ys_from_x_unchecked(
+        xs: &[P::BaseField],
+    ) -> Vec<Option<(P::BaseField, P::BaseField)>> {
+        let x3_plus_ax_plus_b: Vec<_> =
+            cfg_iter!(xs).map(|x| Self::x3_plus_ax_plus_b(*x)).collect();
+        cfg_into_iter!(P::BaseField::batch_sqrt(&x3_plus_ax_plus_b))
+            .map(|y| y.map(Self::sort_ys))
+            .collect()
+    }
+
+    /// Attempts to construct an affine point from each of the given
+    /// x-coordinates and `greatest` flags, as [`Self::get_point_from_x_unchecked`]
+    /// does.
+    ///
+    /// The square roots are computed at once with [`Field::batch_sqrt`], which
+    /// makes this the method of choice to decompress many points.
+    pub fn batch_get_points_from_x_unchecked(xs: &[(P::BaseField, bool)]) -> Vec<Option<Self>> {
+        let x_coords: Vec<_> = xs.iter().map(|(x, _)| *x).collect();
+        let ys = Self::batch_get_ys_from_x_unchecked(&x_coords);
+        cfg_iter!(xs)
+            .zip(ys)
+            .map(|((x, greatest), ys)| {
+                ys.map(|(smaller, larger)| {
+                    Self::new_unchecked(*x, if *greatest { larger } else { smaller })
+                })
+            })
+            .collect()
+    }
+
+    /// Computes the right-hand side of the curve equation, `x^3 + Ax + B`.
+    fn x3_plus_ax_plus_b(x: P::BaseField) -> P::BaseField {
         // Since Rust does not optimise away additions with zero, we explicitly check
         // for that case here, and avoid multiplication by `a` if possible.
         let mut x3_plus_ax_plus_b = P::add_b(x.square() * x);
         if !P::COEFF_A.is_zero() {
             x3_plus_ax_plus_b += P::mul_by_a(x)
         };
-        let y = x3_plus_ax_plus_b.sqrt()?;
+        x3_plus_ax_plus_b
+    }
+
+    /// Sorts `y` and `-y` in lexicographical order.
+    fn sort_ys(y: P::BaseField) -> (P::BaseField, P::BaseField) {
         let neg_y = -y;
         match y < neg_y {
-            true => Some((y, neg_y)),
-            false => Some((neg_y, y)),
+            true => (y, neg_y),
+            false => (neg_y, y),
         }
     }
 
@@ -369,7 +415,8 @@ impl<P: SWCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Affine<P> {
 }
 
 // The projective point X, Y, Z is represented in the affine
-// coordinates as X/Z^2, Y/Z^3.
+// coordinates as X/Z^2, Y/Z^3, or as X/Z, Y/Z if `P::COMPLETE_FORMULAS`
+// is set.
 impl<P: SWCurveConfig> From<Projective<P>> for Affine<P> {
     #[inline]
     fn from(p: Projective<P>) -> Self {
@@ -378,6 +425,9 @@ impl<P: SWCurveConfig> From<Projective<P>> for Affine<P> {
         } else if p.z.is_one() {
             // If Z is one, the point is already normalized.
             Self::new_unchecked(p.x, p.y)
+        } else if P::COMPLETE_FORMULAS {
+            let zinv = p.z.inverse().unwrap();
+            Self::new_unchecked(p.x * &zinv, p.y * &zinv)
         } else {
             // Z is nonzero, so it must have an inverse in a field.
             let zinv = p.z.inverse().unwrap();
@@ -408,6 +458,28 @@ impl<P: SWCurveConfig> CanonicalSerialize for Affine<P> {
     fn serialized_size(&self, compress: Compress) -> usize {
         P::serialized_size(compress)
     }
+
+    /// The raw limbs of a point are those of `x` and `y`, followed by a limb
+    /// set to one for the point at infinity.
+    fn raw_layout() -> Option<RawLayout> {
+        let base = P::BaseField::raw_layout()?;
+        let mut hasher = ParametersHasher::new(b"SWRaw");
+        hasher.update(&base.id.to_le_bytes());
+        hasher.update_serialized(&P::COEFF_A);
+        hasher.update_serialized(&P::COEFF_B);
+        Some(RawLayout {
+            limbs: 2 * base.limbs + 1,
+            id: hasher.finish(),
+        })
+    }
+
+    fn write_raw_limbs(&self, limbs: &mut [u64]) {
+        let (infinity, coords) = limbs.split_last_mut().unwrap();
+        let (x, y) = coords.split_at_mut(coords.len() / 2);
+        self.x.write_raw_limbs(x);
+        self.y.write_raw_limbs(y);
+        *infinity = self.is_zero().into();
+    }
 }
 
 impl<P: SWCurveConfig> Valid for Affine<P> {
@@ -418,6 +490,25 @@ impl<P: SWCurveConfig> Valid for Affine<P> {
             Err(SerializationError::InvalidData)
         }
     }
+
+    /// Checks that the points are on the curve, and then that they are in the
+    /// prime-order subgroup with
+    /// [`SWCurveConfig::batch_is_in_correct_subgroup_assuming_on_curve`].
+    fn batch_check<'a>(
+        batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), SerializationError>
+    where
+        Self: 'a,
+    {
+        let points: Vec<Self> = batch.copied().collect();
+        if cfg_iter!(points).all(Self::is_on_curve)
+            && P::batch_is_in_correct_subgroup_assuming_on_curve(&points)
+        {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
 }
 
 impl<P: SWCurveConfig> CanonicalDeserialize for Affine<P> {
@@ -428,6 +519,53 @@ impl<P: SWCurveConfig> CanonicalDeserialize for Affine<P> {
     ) -> Result<Self, SerializationError> {
         P::deserialize_with_mode(reader, compress, validate)
     }
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        Some(P::serialized_size(compress))
+    }
+
+    #[inline]
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<Self>, SerializationError> {
+        P::deserialize_chunk_unchecked(bytes, compress)
+    }
+
+    fn expected_raw_layout() -> Option<RawLayout> {
+        <Self as CanonicalSerialize>::raw_layout()
+    }
+
+    fn read_raw_limbs(limbs: &[u64]) -> Option<Self> {
+        let (infinity, coords) = limbs.split_last()?;
+        let (x, y) = coords.split_at(coords.len() / 2);
+        let x = P::BaseField::read_raw_limbs(x)?;
+        let y = P::BaseField::read_raw_limbs(y)?;
+        match infinity {
+            0 => Some(Self::new_unchecked(x, y)),
+            1 => Some(Self::identity()),
+            _ => None,
+        }
+    }
+}
+
+/// Points are identified by the fields and coefficients of the curve, and by
+/// the serialization of its generator, which also distinguishes curves that
+/// customize their serialization format.
+impl<P: SWCurveConfig> ParametersId for Affine<P>
+where
+    P::BaseField: ParametersId,
+    P::ScalarField: ParametersId,
+{
+    fn parameters_id() -> u64 {
+        let mut hasher = ParametersHasher::new(b"SW");
+        hasher.update(&P::BaseField::parameters_id().to_le_bytes());
+        hasher.update(&P::ScalarField::parameters_id().to_le_bytes());
+        hasher.update_serialized(&P::COEFF_A);
+        hasher.update_serialized(&P::COEFF_B);
+        hasher.update_serialized(&P::GENERATOR);
+        hasher.finish()
+    }
 }
 
 impl<M: SWCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
@@ -0,0 +1,184 @@
+//! Batch subgroup checks, with random linear combinations of the points.
+//!
+//! A point of the curve is the sum of a point of the prime-order subgroup and
+//! of a point of the cofactor subgroup, whose order is a product of prime
+//! factors of the cofactor. A linear combination of points with random
+//! coefficients smaller than `2^64` is in the prime-order subgroup if all the
+//! points are, and otherwise is not, except with probability at most
+//! `1/p + 2^-64`, where `p` is the smallest prime factor of the cofactor.
+
+use ark_ff::{PrimeField, Zero};
+use ark_std::vec::*;
+
+use super::SWCurveConfig;
+#[cfg(any(feature = "sha2", test))]
+use {
+    super::{Affine, Projective},
+    crate::scalar_mul::variable_base::VariableBaseMSM,
+    ark_serialize::CanonicalSerializeHashExt,
+    ark_std::cfg_chunks,
+    sha2::{Digest, Sha256},
+};
+
+#[cfg(all(feature = "parallel", any(feature = "sha2", test)))]
+use rayon::prelude::*;
+
+/// The bound below which the prime factors of the cofactor are searched for.
+const SMALL_FACTORS_BOUND: u64 = 1 << 16;
+
+/// The smallest number of bits of the smallest prime factor of the cofactor
+/// for which random linear combinations are checked: below it, too many
+/// linear combinations would be needed for them to be faster than checking
+/// the points one by one.
+const MIN_FACTOR_BITS: u32 = 8;
+
+/// The number of points that are hashed at once when deriving the
+/// coefficients of the linear combinations.
+#[cfg(any(feature = "sha2", test))]
+const HASH_CHUNK: usize = 1 << 10;
+
+/// Returns the number of random linear combinations of points of `P` that
+/// must be checked so that points outside the prime-order subgroup pass all
+/// the checks with probability at most about `2^-128`.
+///
+/// Returns `None` if the cofactor is not coprime to the order of the
+/// prime-order subgroup, in which case random linear combinations do not
+/// check the subgroup, or if its smallest prime factor has fewer than
+/// [`MIN_FACTOR_BITS`] bits.
+pub(super) fn rounds<P: SWCurveConfig>() -> Option<usize> {
+    let cofactor = P::COFACTOR;
+    let bytes: Vec<u8> = cofactor
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect();
+    if P::ScalarField::from_le_bytes_mod_order(&bytes).is_zero() {
+        return None;
+    }
+    let divides_cofactor = |d: &u64| {
+        cofactor.iter().rev().fold(0u128, |rem, limb| {
+            ((rem << 64) | *limb as u128) % *d as u128
+        }) == 0
+    };
+    // The smallest divisor greater than one is the smallest prime factor.
+    let factor_bits = (2..SMALL_FACTORS_BOUND)
+        .find(divides_cofactor)
+        .unwrap_or(SMALL_FACTORS_BOUND)
+        .ilog2();
+    (factor_bits >= MIN_FACTOR_BITS).then(|| 128usize.div_ceil(factor_bits as usize))
+}
+
+/// Checks that all the `points` are in the prime-order subgroup with
+/// `rounds` random linear combinations of the points.
+///
+/// The coefficients are derived by hashing the points, so that they cannot be
+/// chosen after the points: the coefficients of each round are the
+/// little-endian `u64`s of SHA-256 in counter mode, keyed with a hash of the
+/// points.
+#[cfg(any(feature = "sha2", test))]
+pub(super) fn is_in_correct_subgroup<P: SWCurveConfig>(
+    points: &[Affine<P>],
+    rounds: usize,
+) -> bool {
+    let digests: Vec<_> = cfg_chunks!(points, HASH_CHUNK)
+        .map(|chunk| chunk.hash_uncompressed::<Sha256>())
+        .collect();
+    let mut hasher = Sha256::new_with_prefix(b"ark-ec batch subgroup check");
+    for digest in digests {
+        hasher.update(digest);
+    }
+    let seed = hasher.finalize();
+
+    (0..rounds as u64).all(|round| {
+        let coeffs: Vec<u64> = (0..points.len().div_ceil(4) as u64)
+            .flat_map(|block| {
+                let digest = Sha256::new_with_prefix(seed)
+                    .chain_update(round.to_le_bytes())
+                    .chain_update(block.to_le_bytes())
+                    .finalize();
+                let mut coeffs = [0u64; 4];
+                for (coeff, bytes) in coeffs.iter_mut().zip(digest.chunks_exact(8)) {
+                    *coeff = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+                coeffs
+            })
+            .take(points.len())
+            .collect();
+        let combination = Projective::<P>::msm_u64(points, &coeffs);
+        P::is_in_correct_subgroup_assuming_on_curve(&combination.into())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        short_weierstrass::batch_subgroup_check_rounds, AffineRepr, CurveConfig, CurveGroup,
+    };
+    use ark_ff::{fields::Fp64, MontBackend, MontFp};
+    use ark_std::UniformRand;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "1000003"]
+    #[generator = "2"]
+    pub(crate) struct FqConfig;
+    pub(crate) type Fq = Fp64<MontBackend<FqConfig, 1>>;
+
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "1301"]
+    #[generator = "2"]
+    pub(crate) struct FrConfig;
+    pub(crate) type Fr = Fp64<MontBackend<FrConfig, 1>>;
+
+    /// E: Elliptic Curve defined by y^2 = x^3 + x + 173 over Finite Field of
+    /// size 1000003, of order 769 * 1301.
+    struct TestConfig;
+
+    impl CurveConfig for TestConfig {
+        const COFACTOR: &[u64] = &[769];
+
+        const COFACTOR_INV: Fr = MontFp!("785");
+
+        type BaseField = Fq;
+        type ScalarField = Fr;
+    }
+
+    impl SWCurveConfig for TestConfig {
+        const COEFF_A: Fq = MontFp!("1");
+
+        const COEFF_B: Fq = MontFp!("173");
+
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(MontFp!("990015"), MontFp!("20680"));
+
+        /// We use `()` because the point (0, 0) is not on the curve.
+        type ZeroFlag = ();
+    }
+
+    #[test]
+    fn random_linear_combinations() {
+        // The smallest prime factor of the cofactor, 769, has 9 bits.
+        assert_eq!(rounds::<TestConfig>(), Some(15));
+        assert_eq!(batch_subgroup_check_rounds::<TestConfig>(), Some(15));
+
+        let mut rng = ark_std::test_rng();
+        let mut points: Vec<Affine<TestConfig>> = (0..100)
+            .map(|_| (Affine::generator() * Fr::rand(&mut rng)).into_affine())
+            .collect();
+        points.push(Affine::zero());
+        assert!(is_in_correct_subgroup(&points, 15));
+        assert!(TestConfig::batch_is_in_correct_subgroup_assuming_on_curve(
+            &points
+        ));
+
+        let outside = Affine::<TestConfig>::new_unchecked(MontFp!("2"), MontFp!("166226"));
+        assert!(outside.is_on_curve());
+        assert!(!outside.is_in_correct_subgroup_assuming_on_curve());
+        for i in [0, 50, 100] {
+            let mut points = points.clone();
+            points[i] = outside;
+            assert!(!is_in_correct_subgroup(&points, 15));
+            assert!(!TestConfig::batch_is_in_correct_subgroup_assuming_on_curve(
+                &points
+            ));
+        }
+    }
+}
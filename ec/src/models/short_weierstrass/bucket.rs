@@ -391,6 +391,9 @@ impl<P: SWCurveConfig> From<Bucket<P>> for Projective<P> {
     fn from(p: Bucket<P>) -> Self {
         if p.is_zero() {
             Self::zero()
+        } else if P::COMPLETE_FORMULAS {
+            // In homogeneous projective coordinates, with Z = ZZ * ZZZ.
+            Self::new_unchecked(p.x * &p.zzz, p.y * &p.zz, p.zz * &p.zzz)
         } else {
             Self::new_unchecked(p.x * &p.zz, p.y * &p.zzz, p.zz)
         }
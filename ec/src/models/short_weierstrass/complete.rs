@@ -0,0 +1,213 @@
+use super::{Affine, Projective, SWCurveConfig};
+use crate::AffineRepr;
+use ark_ff::{fields::Field, AdditiveGroup, ConstantTimeField};
+use ark_std::Zero;
+
+/// The complete formulae of [\[RCB15\]](https://eprint.iacr.org/2015/1060),
+/// in homogeneous projective coordinates `(X : Y : Z)`, which [`Projective`]
+/// uses when `P::COMPLETE_FORMULAS` is set. The formulae for `a = 0` are used
+/// if `P::COEFF_A` is zero, and those for general `a` otherwise.
+impl<P: SWCurveConfig> Projective<P> {
+    /// Returns `a` if `choice` is `true`, and `b` otherwise, with
+    /// [`ConstantTimeField::ct_select`] on each coordinate.
+    fn ct_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new_unchecked(
+            P::BaseField::ct_select(&a.x, &b.x, choice),
+            P::BaseField::ct_select(&a.y, &b.y, choice),
+            P::BaseField::ct_select(&a.z, &b.z, choice),
+        )
+    }
+
+    /// Sets `self = 2 * self`, with Algorithm 9 of \[RCB15\] if `P::COEFF_A` is
+    /// zero, and Algorithm 3 otherwise.
+    pub(super) fn complete_double_in_place(&mut self) {
+        let b3 = P::COEFF_B.double() + P::COEFF_B;
+        let (x, y, z) = (self.x, self.y, self.z);
+
+        if P::COEFF_A.is_zero() {
+            let mut t0 = y.square();
+            let mut z3 = t0.double().double().double();
+            let mut t1 = y * z;
+            let mut t2 = b3 * z.square();
+            let mut x3 = t2 * z3;
+            let mut y3 = t0 + t2;
+            z3 *= t1;
+            t1 = t2.double();
+            t2 += t1;
+            t0 -= t2;
+            y3 *= t0;
+            y3 += x3;
+            t1 = x * y;
+            x3 = t0 * t1;
+            x3.double_in_place();
+            *self = Self::new_unchecked(x3, y3, z3);
+        } else {
+            let mut t0 = x.square();
+            let t1 = y.square();
+            let mut t2 = z.square();
+            let mut t3 = (x * y).double();
+            let mut z3 = (x * z).double();
+            let mut x3 = P::mul_by_a(z3);
+            let mut y3 = b3 * t2 + x3;
+            x3 = t1 - y3;
+            y3 += t1;
+            y3 *= x3;
+            x3 *= t3;
+            z3 *= b3;
+            t2 = P::mul_by_a(t2);
+            t3 = P::mul_by_a(t0 - t2) + z3;
+            z3 = t0.double();
+            t0 += z3;
+            t0 += t2;
+            t0 *= t3;
+            y3 += t0;
+            t2 = (y * z).double();
+            t0 = t2 * t3;
+            x3 -= t0;
+            z3 = (t2 * t1).double().double();
+            *self = Self::new_unchecked(x3, y3, z3);
+        }
+    }
+
+    /// Sets `self = self + other`, with Algorithm 7 of \[RCB15\] if
+    /// `P::COEFF_A` is zero, and Algorithm 1 otherwise.
+    pub(super) fn complete_add_assign(&mut self, other: &Self) {
+        let b3 = P::COEFF_B.double() + P::COEFF_B;
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let mut t0 = x1 * x2;
+        let mut t1 = y1 * y2;
+        let mut t2 = z1 * z2;
+        let mut t3 = (x1 + y1) * (x2 + y2);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        if P::COEFF_A.is_zero() {
+            t4 = (y1 + z1) * (y2 + z2) - (t1 + t2);
+            let mut x3 = (x1 + z1) * (x2 + z2);
+            let mut y3 = x3 - (t0 + t2);
+            x3 = t0.double();
+            t0 += x3;
+            t2 *= b3;
+            let mut z3 = t1 + t2;
+            t1 -= t2;
+            y3 *= b3;
+            x3 = t4 * y3;
+            t2 = t3 * t1;
+            x3 = t2 - x3;
+            y3 *= t0;
+            t1 *= z3;
+            y3 += t1;
+            t0 *= t3;
+            z3 *= t4;
+            z3 += t0;
+            *self = Self::new_unchecked(x3, y3, z3);
+        } else {
+            t4 = (x1 + z1) * (x2 + z2) - (t0 + t2);
+            let t5 = (y1 + z1) * (y2 + z2) - (t1 + t2);
+            let mut z3 = P::mul_by_a(t4);
+            let mut x3 = b3 * t2;
+            z3 += x3;
+            x3 = t1 - z3;
+            z3 += t1;
+            let mut y3 = x3 * z3;
+            t1 = t0.double() + t0;
+            t2 = P::mul_by_a(t2);
+            t4 *= b3;
+            t1 += t2;
+            t2 = P::mul_by_a(t0 - t2);
+            t4 += t2;
+            t0 = t1 * t4;
+            y3 += t0;
+            t0 = t5 * t4;
+            x3 *= t3;
+            x3 -= t0;
+            t0 = t3 * t1;
+            z3 *= t5;
+            z3 += t0;
+            *self = Self::new_unchecked(x3, y3, z3);
+        }
+    }
+
+    /// Sets `self = self + other`, with Algorithm 8 of \[RCB15\] if
+    /// `P::COEFF_A` is zero, and Algorithm 2 otherwise. The point at infinity
+    /// has no affine coordinates for these formulae to take, so `self` is
+    /// returned unchanged if `other` is the point at infinity.
+    pub(super) fn complete_add_assign_affine(&mut self, other: &Affine<P>) {
+        if other.is_zero() {
+            return;
+        }
+        let b3 = P::COEFF_B.double() + P::COEFF_B;
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2) = (other.x, other.y);
+
+        let mut t0 = x1 * x2;
+        let mut t1 = y1 * y2;
+        let mut t3 = (x2 + y2) * (x1 + y1);
+        let mut t4 = t0 + t1;
+        t3 -= t4;
+        *self = if P::COEFF_A.is_zero() {
+            t4 = y2 * z1 + y1;
+            let mut y3 = x2 * z1 + x1;
+            let mut x3 = t0.double();
+            t0 += x3;
+            let mut t2 = b3 * z1;
+            let mut z3 = t1 + t2;
+            t1 -= t2;
+            y3 *= b3;
+            x3 = t4 * y3;
+            t2 = t3 * t1;
+            x3 = t2 - x3;
+            y3 *= t0;
+            t1 *= z3;
+            y3 += t1;
+            t0 *= t3;
+            z3 *= t4;
+            z3 += t0;
+            Self::new_unchecked(x3, y3, z3)
+        } else {
+            t4 = x2 * z1 + x1;
+            let t5 = y2 * z1 + y1;
+            let mut z3 = P::mul_by_a(t4);
+            let mut x3 = b3 * z1;
+            z3 += x3;
+            x3 = t1 - z3;
+            z3 += t1;
+            let mut y3 = x3 * z3;
+            t1 = t0.double() + t0;
+            let mut t2 = P::mul_by_a(z1);
+            t4 *= b3;
+            t1 += t2;
+            t2 = P::mul_by_a(t0 - t2);
+            t4 += t2;
+            t0 = t1 * t4;
+            y3 += t0;
+            t0 = t5 * t4;
+            x3 *= t3;
+            x3 -= t0;
+            t0 = t3 * t1;
+            z3 *= t5;
+            z3 += t0;
+            Self::new_unchecked(x3, y3, z3)
+        };
+    }
+
+    /// Performs scalar multiplication with a double-and-add loop that adds
+    /// `self` at every bit of `other`, including its leading zeros, and keeps
+    /// the sum with [`ConstantTimeField::ct_select`], so that the sequence of
+    /// point operations only depends on the number of limbs of `other`. The
+    /// field arithmetic underneath is not constant time (see
+    /// [`ConstantTimeField`]).
+    pub(super) fn complete_mul_bigint(&self, other: &[u64]) -> Self {
+        let mut res = Self::zero();
+        for limb in other.iter().rev() {
+            for i in (0..64).rev() {
+                res.complete_double_in_place();
+                let mut sum = res;
+                sum.complete_add_assign(self);
+                res = Self::ct_select(&sum, &res, (limb >> i) & 1 == 1);
+            }
+        }
+        res
+    }
+}
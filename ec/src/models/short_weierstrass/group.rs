@@ -5,7 +5,8 @@ use crate::{
 };
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    envelope::ParametersId, CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError,
+    Valid, Validate,
 };
 use ark_std::{
     borrow::Borrow,
@@ -28,13 +29,21 @@ use zeroize::Zeroize;
 /// Jacobian coordinates for a point on an elliptic curve in short Weierstrass
 /// form, over the base field `P::BaseField`. This struct implements arithmetic
 /// via the Jacobian formulae.
+///
+/// If `P::COMPLETE_FORMULAS` is set, the coordinates are instead homogeneous
+/// projective coordinates, where `X / Z` and `Y / Z` are the affine `X` and
+/// `Y`, and the arithmetic uses the complete formulae of
+/// [\[RCB15\]](https://eprint.iacr.org/2015/1060) (see
+/// [`SWCurveConfig::COMPLETE_FORMULAS`]).
 #[derive(Educe)]
 #[educe(Copy, Clone)]
 #[must_use]
 pub struct Projective<P: SWCurveConfig> {
-    /// `X / Z^2` projection of the affine `X`
+    /// `X / Z^2` projection of the affine `X`, or `X / Z` if
+    /// `P::COMPLETE_FORMULAS` is set
     pub x: P::BaseField,
-    /// `Y / Z^3` projection of the affine `Y`
+    /// `Y / Z^3` projection of the affine `Y`, or `Y / Z` if
+    /// `P::COMPLETE_FORMULAS` is set
     pub y: P::BaseField,
     /// Projective multiplicative inverse. Will be `0` only at infinity.
     pub z: P::BaseField,
@@ -66,6 +75,14 @@ impl<P: SWCurveConfig> PartialEq for Projective<P> {
             return false;
         }
 
+        if P::COMPLETE_FORMULAS {
+            // The homogeneous points (X, Y, Z) and (X', Y', Z')
+            // are equal when (X * Z') = (X' * Z)
+            // and (Y * Z') = (Y' * Z).
+            return self.x * &other.z == other.x * &self.z
+                && self.y * &other.z == other.y * &self.z;
+        }
+
         // The points (X, Y, Z) and (X', Y', Z')
         // are equal when (X * Z^2) = (X' * Z'^2)
         // and (Y * Z^3) = (Y' * Z'^3).
@@ -116,7 +133,8 @@ impl<P: SWCurveConfig> Default for Projective<P> {
 
 impl<P: SWCurveConfig> Projective<P> {
     /// Constructs a new group element without checking whether the coordinates
-    /// specify a point in the subgroup.
+    /// specify a point in the subgroup. The coordinates are homogeneous
+    /// instead of Jacobian if `P::COMPLETE_FORMULAS` is set.
     pub const fn new_unchecked(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
         Self { x, y, z }
     }
@@ -140,14 +158,11 @@ impl<P: SWCurveConfig> Zeroize for Projective<P> {
 }
 
 impl<P: SWCurveConfig> Zero for Projective<P> {
-    /// Returns the point at infinity, which always has Z = 0.
+    /// Returns the point at infinity, which always has Z = 0. It is
+    /// `(0 : 1 : 0)` in homogeneous projective coordinates.
     #[inline]
     fn zero() -> Self {
-        Self::new_unchecked(
-            P::BaseField::one(),
-            P::BaseField::one(),
-            P::BaseField::zero(),
-        )
+        Self::ZERO
     }
 
     /// Checks whether `self.z.is_zero()`.
@@ -160,15 +175,31 @@ impl<P: SWCurveConfig> Zero for Projective<P> {
 impl<P: SWCurveConfig> AdditiveGroup for Projective<P> {
     type Scalar = P::ScalarField;
 
-    const ZERO: Self =
-        Self::new_unchecked(P::BaseField::ONE, P::BaseField::ONE, P::BaseField::ZERO);
+    const ZERO: Self = Self::new_unchecked(
+        if P::COMPLETE_FORMULAS {
+            P::BaseField::ZERO
+        } else {
+            P::BaseField::ONE
+        },
+        P::BaseField::ONE,
+        P::BaseField::ZERO,
+    );
 
     /// Sets `self = 2 * self`. Note that Jacobian formulae are incomplete, and
     /// so doubling cannot be computed as `self + self`. Instead, this
     /// implementation uses the following specialized doubling formulae:
     /// * [`P::A` is zero](http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l)
     /// * [`P::A` is not zero](https://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#doubling-dbl-2007-bl)
+    ///
+    /// If `P::COMPLETE_FORMULAS` is set, the complete doubling formulae are
+    /// used instead, with the same field operations for every point,
+    /// including the point at infinity.
     fn double_in_place(&mut self) -> &mut Self {
+        if P::COMPLETE_FORMULAS {
+            self.complete_double_in_place();
+            return self;
+        }
+
         if self.is_zero() {
             return self;
         }
@@ -298,6 +329,9 @@ impl<P: SWCurveConfig> CurveGroup for Projective<P> {
     ///
     /// (Where batch inversion comprises 3N field multiplications + 1 inversion
     /// of these operations)
+    ///
+    /// Homogeneous projective coordinates, used if `P::COMPLETE_FORMULAS` is
+    /// set, are converted into (x/z, y/z, 1) instead.
     #[inline]
     fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
         let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
@@ -308,6 +342,7 @@ impl<P: SWCurveConfig> CurveGroup for Projective<P> {
             .zip(z_s)
             .map(|(g, z)| match g.is_zero() {
                 true => Affine::identity(),
+                false if P::COMPLETE_FORMULAS => Affine::new_unchecked(g.x * z, g.y * z),
                 false => {
                     let z2 = z.square();
                     let x = g.x * z2;
@@ -330,9 +365,14 @@ impl<P: SWCurveConfig> Neg for Projective<P> {
 }
 
 impl<P: SWCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
-    /// Using <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl>
+    /// Using <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl>,
+    /// or the complete formulae if `P::COMPLETE_FORMULAS` is set.
     fn add_assign(&mut self, other: T) {
         let other = other.borrow();
+        if P::COMPLETE_FORMULAS {
+            self.complete_add_assign_affine(other);
+            return;
+        }
 
         // If the other point is not at infinity, set `self` to the other point.
         // If the other point *is* at infinity, `other.xy()` will be `None`, and we
@@ -449,6 +489,11 @@ impl<'a, P: SWCurveConfig> Add<&'a Self> for Projective<P> {
 
 impl<'a, P: SWCurveConfig> AddAssign<&'a Self> for Projective<P> {
     fn add_assign(&mut self, other: &'a Self) {
+        if P::COMPLETE_FORMULAS {
+            self.complete_add_assign(other);
+            return;
+        }
+
         if self.is_zero() {
             *self = *other;
             return;
@@ -625,6 +670,19 @@ impl<P: SWCurveConfig> CanonicalDeserialize for Projective<P> {
         let aff = P::deserialize_with_mode(reader, compress, validate)?;
         Ok(aff.into())
     }
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        Some(P::serialized_size(compress))
+    }
+}
+
+impl<P: SWCurveConfig> ParametersId for Projective<P>
+where
+    Affine<P>: ParametersId,
+{
+    fn parameters_id() -> u64 {
+        Affine::<P>::parameters_id()
+    }
 }
 
 impl<M: SWCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
@@ -3,8 +3,10 @@ use ark_serialize::{
     CanonicalSerializeWithFlags, Compress, SerializationError, Valid, Validate,
 };
 use ark_std::{
+    cfg_chunks, cfg_iter,
     hash::Hash,
     io::{Read, Write},
+    vec::*,
 };
 
 use ark_ff::{fields::Field, AdditiveGroup};
@@ -17,12 +19,19 @@ use crate::{
 };
 use num_traits::Zero;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 mod affine;
 pub use affine::*;
 
 mod group;
 pub use group::*;
 
+mod batch;
+
+mod complete;
+
 mod bucket;
 pub use bucket::Bucket;
 
@@ -41,6 +50,29 @@ pub trait SWCurveConfig: super::CurveConfig {
     /// Generator of the prime-order subgroup.
     const GENERATOR: Affine<Self>;
 
+    /// Whether [`Projective<Self>`] uses homogeneous projective coordinates
+    /// and the complete formulae of [\[RCB15\]](https://eprint.iacr.org/2015/1060),
+    /// instead of Jacobian coordinates.
+    ///
+    /// This changes the meaning of the public coordinates of [`Projective`]:
+    /// by default `(x, y, z)` is the affine point `(x / z^2, y / z^3)`, and
+    /// the point at infinity is `(1, 1, 0)`; if this is set, it is the affine
+    /// point `(x / z, y / z)`, and the point at infinity is `(0, 1, 0)`. Code
+    /// that builds or reads these coordinates directly must check this
+    /// constant; the conversions to and from [`Affine`] take it into account.
+    ///
+    /// The complete formulae compute additions and doublings with the same
+    /// sequence of field operations for all inputs, including the point at
+    /// infinity and equal or opposite points, and the default
+    /// [`Self::mul_projective`] and [`Self::mul_affine`] then add at every bit
+    /// of the scalar, so that the sequence of point operations does not
+    /// depend on the bits of the scalar. The field arithmetic is not constant
+    /// time, though. The formulae are complete for every pair of points on
+    /// curves of odd order, and for every pair of points in the prime-order
+    /// subgroup otherwise. They cost a few more field multiplications than
+    /// the Jacobian ones.
+    const COMPLETE_FORMULAS: bool = false;
+
     /// A type that is stored in `Affine<Self>` to indicate whether the point is at infinity.
     type ZeroFlag: ZeroFlag<Self>;
 
@@ -89,6 +121,38 @@ pub trait SWCurveConfig: super::CurveConfig {
         }
     }
 
+    /// Check if all the provided curve points are in the prime-order subgroup.
+    ///
+    /// With the `sha2` feature, the default implementation checks random
+    /// linear combinations of the points, with one multi-scalar multiplication and one call to
+    /// [`Self::is_in_correct_subgroup_assuming_on_curve`] each. A combination
+    /// with 64-bit coefficients of points not all in the subgroup is in the
+    /// subgroup with probability at most about `1/p`, where `p` is the smallest
+    /// prime factor of the cofactor, so that `128 / log2(p)` combinations are
+    /// checked, with coefficients derived by hashing the points with SHA-256.
+    /// Without it, the points are checked one by one.
+    ///
+    /// This requires the cofactor to be coprime to the order `r` of the
+    /// prime-order subgroup. Otherwise, if `p` is below `2^8`, or if there are
+    /// too few points for the linear combinations to be faster, the points
+    /// are checked one by one. In particular, the cofactors of BLS12-381 G1
+    /// and G2 are divisible by 3 and 13, and those of the G1 groups of
+    /// BLS12-377, BW6-761 and BW6-767 by 2, so that their points are always
+    /// checked one by one, while the points of BLS12-377 G2 and BN254 G2 are
+    /// checked with linear combinations.
+    fn batch_is_in_correct_subgroup_assuming_on_curve(items: &[Affine<Self>]) -> bool {
+        if Self::cofactor_is_one() {
+            return true;
+        }
+        #[cfg(any(feature = "sha2", test))]
+        if let Some(rounds) =
+            batch_subgroup_check_rounds::<Self>().filter(|rounds| items.len() > 2 * rounds)
+        {
+            return batch::is_in_correct_subgroup(items, rounds);
+        }
+        cfg_iter!(items).all(Self::is_in_correct_subgroup_assuming_on_curve)
+    }
+
     /// Performs cofactor clearing.
     /// The default method is simply to multiply by the cofactor.
     /// Some curves can implement a more efficient algorithm.
@@ -99,13 +163,21 @@ pub trait SWCurveConfig: super::CurveConfig {
     /// Default implementation of group multiplication for projective
     /// coordinates
     fn mul_projective(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self> {
-        sw_double_and_add_projective(base, scalar)
+        if Self::COMPLETE_FORMULAS {
+            base.complete_mul_bigint(scalar)
+        } else {
+            sw_double_and_add_projective(base, scalar)
+        }
     }
 
     /// Default implementation of group multiplication for affine
     /// coordinates.
     fn mul_affine(base: &Affine<Self>, scalar: &[u64]) -> Projective<Self> {
-        sw_double_and_add_affine(base, scalar)
+        if Self::COMPLETE_FORMULAS {
+            base.into_group().complete_mul_bigint(scalar)
+        } else {
+            sw_double_and_add_affine(base, scalar)
+        }
     }
 
     /// Default implementation for multi scalar multiplication
@@ -192,6 +264,27 @@ pub trait SWCurveConfig: super::CurveConfig {
         }
     }
 
+    /// Deserializes, without validating them, the consecutive points
+    /// serialized in `bytes`, as [`Self::deserialize_with_mode`] does.
+    ///
+    /// The default implementation deserializes the points independently of
+    /// each other with [`Self::deserialize_with_mode`], in parallel, so that
+    /// it follows the serialization format of the curve. Curves can override
+    /// it to decompress the points at once: those that keep the default
+    /// format can use [`batch_deserialize_chunk_unchecked`].
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<Affine<Self>>, SerializationError> {
+        let size = Self::serialized_size(compress);
+        if bytes.len() % size != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+        cfg_chunks!(bytes, size)
+            .map(|bytes| Self::deserialize_with_mode(bytes, compress, Validate::No))
+            .collect()
+    }
+
     #[inline]
     fn serialized_size(compress: Compress) -> usize {
         let zero = Self::BaseField::zero();
@@ -202,6 +295,62 @@ pub trait SWCurveConfig: super::CurveConfig {
     }
 }
 
+/// Returns the number of random linear combinations of points of `P` that
+/// [`SWCurveConfig::batch_is_in_correct_subgroup_assuming_on_curve`] checks
+/// by default with the `sha2` feature, or `None` if it checks the points one
+/// by one (or, if the cofactor is one, does not need to check them).
+pub fn batch_subgroup_check_rounds<P: SWCurveConfig>() -> Option<usize> {
+    if P::cofactor_is_one() {
+        return None;
+    }
+    batch::rounds::<P>()
+}
+
+/// Deserializes, without validating them, the consecutive points of `P`
+/// serialized in `bytes` in the default format of
+/// [`SWCurveConfig::serialize_with_mode`], decompressing them at once with
+/// [`Affine::batch_get_points_from_x_unchecked`].
+///
+/// Curves that keep the default format can opt into batched decompression
+/// by overriding [`SWCurveConfig::deserialize_chunk_unchecked`] with this
+/// function.
+pub fn batch_deserialize_chunk_unchecked<P: SWCurveConfig>(
+    bytes: &[u8],
+    compress: Compress,
+) -> Result<Vec<Affine<P>>, SerializationError> {
+    let size = P::serialized_size(compress);
+    if bytes.len() % size != 0 {
+        return Err(SerializationError::InvalidData);
+    }
+    match compress {
+        Compress::Yes => {
+            // The x-coordinates and `greatest` flags, or `None` for the
+            // points at infinity.
+            let xs = cfg_chunks!(bytes, size)
+                .map(|bytes| {
+                    let (x, flags): (_, SWFlags) =
+                        CanonicalDeserializeWithFlags::deserialize_with_flags(bytes)?;
+                    Ok(flags.is_positive().map(|is_positive| (x, !is_positive)))
+                })
+                .collect::<Result<Vec<_>, SerializationError>>()?;
+            let finite: Vec<_> = xs.iter().flatten().copied().collect();
+            let mut points = Affine::batch_get_points_from_x_unchecked(&finite).into_iter();
+            xs.iter()
+                .map(|x| match x {
+                    Some(_) => points
+                        .next()
+                        .flatten()
+                        .ok_or(SerializationError::InvalidData),
+                    None => Ok(Affine::identity()),
+                })
+                .collect()
+        },
+        Compress::No => cfg_chunks!(bytes, size)
+            .map(|bytes| P::deserialize_with_mode(bytes, compress, Validate::No))
+            .collect(),
+    }
+}
+
 pub trait ZeroFlag<C: SWCurveConfig>:
     Hash + Ord + Eq + Copy + Sync + Send + Sized + 'static
 {
@@ -228,3 +377,20 @@ impl<C: SWCurveConfig> ZeroFlag<C> for () {
         point.x.is_zero() & point.y.is_zero()
     }
 }
+
+/// Returns whether `q` is `φ(p)` or `φ^2(p)`, where `φ(x, y) = (ωx, y)` is the
+/// endomorphism of curves with `a = 0` given by a primitive cube root of unity
+/// `ω` of the base field.
+pub(crate) fn is_cube_root_endomorphism_image<P: SWCurveConfig>(
+    q: &Projective<P>,
+    p: &Projective<P>,
+    omega: P::BaseField,
+) -> bool {
+    let mut phi_p = *p;
+    phi_p.x *= omega;
+    if *q == phi_p {
+        return true;
+    }
+    phi_p.x *= omega;
+    *q == phi_p
+}
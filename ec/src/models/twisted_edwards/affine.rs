@@ -1,5 +1,7 @@
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    envelope::{ParametersHasher, ParametersId},
+    CanonicalDeserialize, CanonicalSerialize, Compress, RawLayout, SerializationError, Valid,
+    Validate,
 };
 use ark_std::{
     borrow::Borrow,
@@ -322,6 +324,25 @@ impl<P: TECurveConfig> CanonicalSerialize for Affine<P> {
     fn serialized_size(&self, compress: Compress) -> usize {
         P::serialized_size(compress)
     }
+
+    /// The raw limbs of a point are those of `x` and `y`.
+    fn raw_layout() -> Option<RawLayout> {
+        let base = P::BaseField::raw_layout()?;
+        let mut hasher = ParametersHasher::new(b"TERaw");
+        hasher.update(&base.id.to_le_bytes());
+        hasher.update_serialized(&P::COEFF_A);
+        hasher.update_serialized(&P::COEFF_D);
+        Some(RawLayout {
+            limbs: 2 * base.limbs,
+            id: hasher.finish(),
+        })
+    }
+
+    fn write_raw_limbs(&self, limbs: &mut [u64]) {
+        let (x, y) = limbs.split_at_mut(limbs.len() / 2);
+        self.x.write_raw_limbs(x);
+        self.y.write_raw_limbs(y);
+    }
 }
 
 impl<P: TECurveConfig> Valid for Affine<P> {
@@ -342,6 +363,40 @@ impl<P: TECurveConfig> CanonicalDeserialize for Affine<P> {
     ) -> Result<Self, SerializationError> {
         P::deserialize_with_mode(reader, compress, validate)
     }
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        Some(P::serialized_size(compress))
+    }
+
+    fn expected_raw_layout() -> Option<RawLayout> {
+        <Self as CanonicalSerialize>::raw_layout()
+    }
+
+    fn read_raw_limbs(limbs: &[u64]) -> Option<Self> {
+        let (x, y) = limbs.split_at(limbs.len() / 2);
+        let x = P::BaseField::read_raw_limbs(x)?;
+        let y = P::BaseField::read_raw_limbs(y)?;
+        Some(Self::new_unchecked(x, y))
+    }
+}
+
+/// Points are identified by the fields and coefficients of the curve, and by
+/// the serialization of its generator, which also distinguishes curves that
+/// customize their serialization format.
+impl<P: TECurveConfig> ParametersId for Affine<P>
+where
+    P::BaseField: ParametersId,
+    P::ScalarField: ParametersId,
+{
+    fn parameters_id() -> u64 {
+        let mut hasher = ParametersHasher::new(b"TE");
+        hasher.update(&P::BaseField::parameters_id().to_le_bytes());
+        hasher.update(&P::ScalarField::parameters_id().to_le_bytes());
+        hasher.update_serialized(&P::COEFF_A);
+        hasher.update_serialized(&P::COEFF_D);
+        hasher.update_serialized(&P::GENERATOR);
+        hasher.finish()
+    }
 }
 
 impl<M: TECurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
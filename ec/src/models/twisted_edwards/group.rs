@@ -1,5 +1,6 @@
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    envelope::ParametersId, CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError,
+    Valid, Validate,
 };
 use ark_std::{
     borrow::Borrow,
@@ -23,7 +24,7 @@ use zeroize::Zeroize;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::{Affine, MontCurveConfig, TECurveConfig};
+use super::{Affine, TECurveConfig};
 use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
@@ -403,25 +404,6 @@ impl<P: TECurveConfig> From<Affine<P>> for Projective<P> {
     }
 }
 
-#[derive(Educe)]
-#[educe(Copy, Clone, PartialEq, Eq, Debug, Hash)]
-pub struct MontgomeryAffine<P: MontCurveConfig> {
-    pub x: P::BaseField,
-    pub y: P::BaseField,
-}
-
-impl<P: MontCurveConfig> Display for MontgomeryAffine<P> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "MontgomeryAffine(x={}, y={})", self.x, self.y)
-    }
-}
-
-impl<P: MontCurveConfig> MontgomeryAffine<P> {
-    pub const fn new(x: P::BaseField, y: P::BaseField) -> Self {
-        Self { x, y }
-    }
-}
-
 impl<P: TECurveConfig> CanonicalSerialize for Projective<P> {
     #[inline]
     fn serialize_with_mode<W: Write>(
@@ -465,6 +447,19 @@ impl<P: TECurveConfig> CanonicalDeserialize for Projective<P> {
         let aff = P::deserialize_with_mode(reader, compress, validate)?;
         Ok(aff.into())
     }
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        Some(P::serialized_size(compress))
+    }
+}
+
+impl<P: TECurveConfig> ParametersId for Projective<P>
+where
+    Affine<P>: ParametersId,
+{
+    fn parameters_id() -> u64 {
+        Affine::<P>::parameters_id()
+    }
 }
 
 impl<M: TECurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
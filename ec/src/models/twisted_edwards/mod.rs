@@ -18,6 +18,8 @@ pub use group::*;
 mod serialization_flags;
 pub use serialization_flags::*;
 
+pub use super::montgomery::{MontCurveConfig, MontgomeryAffine};
+
 /// Constants and convenience functions that collectively define the [Twisted Edwards model](https://www.hyperelliptic.org/EFD/g1p/auto-twisted.html)
 /// of the curve.
 ///
@@ -159,19 +161,4 @@ pub trait TECurveConfig: super::CurveConfig {
     }
 }
 
-/// Constants and convenience functions that collectively define the [Montgomery model](https://www.hyperelliptic.org/EFD/g1p/auto-montgom.html)
-/// of the curve.
-///
-/// In this model, the curve equation is `b * y² = x³ + a * x² + x`, for constants `a` and `b`.
-pub trait MontCurveConfig: super::CurveConfig {
-    /// Coefficient `a` of the curve equation.
-    const COEFF_A: Self::BaseField;
-    /// Coefficient `b` of the curve equation.
-    const COEFF_B: Self::BaseField;
-
-    /// Model parameters for the Twisted Edwards curve that is birationally
-    /// equivalent to this curve.
-    type TECurveConfig: TECurveConfig<BaseField = Self::BaseField>;
-}
-
 //////////////////////////////////////////////////////////////////////////////
@@ -120,6 +120,13 @@ pub trait Pairing: Sized + 'static + Copy + Debug + Sync + Send + Eq {
     ) -> PairingOutput<Self> {
         Self::multi_pairing([p], [q])
     }
+
+    /// Checks that `f` is in the target group of the pairing, which is the
+    /// subgroup of order `Self::ScalarField::characteristic()` of the
+    /// multiplicative group of `Self::TargetField`.
+    fn is_in_target_group(f: &Self::TargetField) -> bool {
+        f.pow(Self::ScalarField::characteristic()).is_one()
+    }
 }
 
 /// Represents the target group of a pairing. This struct is a
@@ -154,7 +161,7 @@ impl<P: Pairing> CanonicalSerialize for PairingOutput<P> {
 
 impl<P: Pairing> Valid for PairingOutput<P> {
     fn check(&self) -> Result<(), SerializationError> {
-        if self.0.pow(P::ScalarField::characteristic()).is_one() {
+        if P::is_in_target_group(&self.0) {
             Ok(())
         } else {
             Err(SerializationError::InvalidData)
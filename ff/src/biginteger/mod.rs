@@ -83,6 +83,11 @@ impl<const N: usize> CanonicalDeserialize for BigInt<N> {
             reader, compress, validate,
         )?))
     }
+
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        <[u64; N]>::fixed_serialized_size(compress)
+    }
 }
 
 /// Construct a [`struct@BigInt<N>`] element from a literal string.
@@ -0,0 +1,193 @@
+use crate::{BigInteger, Field, PrimeField};
+use ark_std::vec::*;
+use num_bigint::BigUint;
+
+/// Field operations after the straight-line algorithms of RFC 9380, which
+/// replace the data-dependent branches of [`Field::sqrt`], [`Field::legendre`]
+/// and comparisons with arithmetic and selections, and only branch on the
+/// public parameters of the field. [`Field::sqrt`], for instance, runs
+/// Tonelli–Shanks for a number of iterations that depends on its input.
+///
+/// They are built on the arithmetic of the field, which is *not* constant
+/// time: the Montgomery backend conditionally subtracts the modulus in its
+/// reductions, and branches on its operands in subtraction and negation. So
+/// these operations do not protect secret inputs against timing attacks. They
+/// are implemented for every [`Field`], and are slower than their
+/// variable-time counterparts.
+///
+/// # Example
+/// ```
+/// # use ark_ff::{ConstantTimeField, Field};
+/// # use ark_std::{test_rng, UniformRand};
+/// # use ark_test_curves::bls12_381::Fq as F;
+/// let mut rng = test_rng();
+/// let (u, v) = (F::rand(&mut rng), F::rand(&mut rng));
+/// // `z` is a non-square.
+/// let z = -F::from(1u8);
+///
+/// let (is_square, y) = F::ct_sqrt_ratio(&u, &v, &z);
+/// assert_eq!(is_square, (u / v).legendre().is_qr());
+/// assert_eq!(y.square(), if is_square { u / v } else { z * u / v });
+/// ```
+pub trait ConstantTimeField: Field {
+    /// Returns `true` if `self` is zero, folding the limbs of its coefficients
+    /// instead of comparing them.
+    fn ct_is_zero(&self) -> bool {
+        let mut acc = 0u64;
+        for elem in self.to_base_prime_field_elements() {
+            acc |= fold_limbs(&elem.into_bigint());
+        }
+        is_zero_u64(acc) == 1
+    }
+
+    /// Returns `true` if `self == other`.
+    fn ct_eq(&self, other: &Self) -> bool {
+        (*self - other).ct_is_zero()
+    }
+
+    /// Returns `a` if `choice` is `true`, and `b` otherwise, as
+    /// `b + (a - b) * choice`.
+    fn ct_select(a: &Self, b: &Self, choice: bool) -> Self {
+        // `from(0)` would take a shortcut: `c` is `2 - 1` or `1 - 1`.
+        let c = Self::from(u64::from(choice) + 1) - Self::ONE;
+        *b + (*a - b) * c
+    }
+
+    /// Returns the `sgn0` of [\[HSSWW23, Section 4.1\]], the parity of the
+    /// first non-zero coefficient of `self` over the base prime field.
+    ///
+    /// [\[HSSWW23, Section 4.1\]]: <https://datatracker.ietf.org/doc/html/rfc9380#section-4.1>
+    fn ct_sgn0(&self) -> bool {
+        let (mut sign, mut zero) = (0u64, 1u64);
+        for elem in self.to_base_prime_field_elements() {
+            let elem = elem.into_bigint();
+            sign |= zero & (elem.as_ref()[0] & 1);
+            zero &= is_zero_u64(fold_limbs(&elem));
+        }
+        sign == 1
+    }
+
+    /// Returns the inverse of `self`, or zero if `self` is zero: the `inv0`
+    /// of [\[HSSWW23, Section 4\]], computed as `self^(q - 2)`.
+    ///
+    /// [\[HSSWW23, Section 4\]]: <https://datatracker.ietf.org/doc/html/rfc9380#section-4>
+    fn ct_inverse(&self) -> Self {
+        self.pow(Order::<Self>::new().q_minus_two)
+    }
+
+    /// Returns `(true, sqrt(u / v))` if `u / v` is a square, and
+    /// `(false, sqrt(z * u / v))` otherwise, where `z` is a non-square and `v`
+    /// is non-zero: the `sqrt_ratio` of [\[HSSWW23, Appendix F.2.1.1\]].
+    ///
+    /// The square roots are not normalized: callers choose their sign with
+    /// [`ConstantTimeField::ct_sgn0`].
+    ///
+    /// [\[HSSWW23, Appendix F.2.1.1\]]: <https://datatracker.ietf.org/doc/html/rfc9380#appendix-F.2.1.1>
+    fn ct_sqrt_ratio(u: &Self, v: &Self, z: &Self) -> (bool, Self) {
+        let Order { c1, c3, .. } = Order::<Self>::new();
+        // c6 = z^c2 and c7 = z^((c2 + 1) / 2), where c2 = 2 * c3 + 1.
+        let z_c3 = z.pow(&c3);
+        let c7 = z_c3 * z;
+        let c6 = c7 * z_c3;
+
+        // 1. tv1 = c6
+        // 2. tv2 = v^c4, where c4 = 2^c1 - 1
+        let mut tv1 = c6;
+        let mut tv2 = Self::ONE;
+        for _ in 0..c1 {
+            tv2 = tv2.square() * v;
+        }
+        // 3. tv3 = tv2^2
+        // 4. tv3 = tv3 * v
+        // 5. tv5 = u * tv3
+        // 6. tv5 = tv5^c3
+        // 7. tv5 = tv5 * tv2
+        let mut tv3 = tv2.square() * v;
+        let mut tv5 = (*u * tv3).pow(&c3) * tv2;
+        // 8. tv2 = tv5 * v
+        // 9. tv3 = tv5 * u
+        // 10. tv4 = tv3 * tv2
+        tv2 = tv5 * v;
+        tv3 = tv5 * u;
+        let mut tv4 = tv3 * tv2;
+        // 11. tv5 = tv4^c5, where c5 = 2^(c1 - 1)
+        // 12. isQR = tv5 == 1
+        tv5 = tv4;
+        for _ in 1..c1 {
+            tv5.square_in_place();
+        }
+        let is_qr = tv5.ct_eq(&Self::ONE);
+        // 13. tv2 = tv3 * c7
+        // 14. tv5 = tv4 * tv1
+        // 15. tv3 = CMOV(tv2, tv3, isQR)
+        // 16. tv4 = CMOV(tv5, tv4, isQR)
+        tv2 = tv3 * c7;
+        tv5 = tv4 * tv1;
+        tv3 = Self::ct_select(&tv3, &tv2, is_qr);
+        tv4 = Self::ct_select(&tv4, &tv5, is_qr);
+        // 17. for i in (c1, c1 - 1, ..., 2):
+        for i in (2..=c1).rev() {
+            // 18. tv5 = i - 2
+            // 19. tv5 = 2^tv5
+            // 20. tv5 = tv4^tv5
+            // 21. e1 = tv5 == 1
+            tv5 = tv4;
+            for _ in 2..i {
+                tv5.square_in_place();
+            }
+            let e1 = tv5.ct_eq(&Self::ONE);
+            // 22. tv2 = tv3 * tv1
+            // 23. tv1 = tv1 * tv1
+            // 24. tv5 = tv4 * tv1
+            // 25. tv3 = CMOV(tv2, tv3, e1)
+            // 26. tv4 = CMOV(tv5, tv4, e1)
+            tv2 = tv3 * tv1;
+            tv1.square_in_place();
+            tv5 = tv4 * tv1;
+            tv3 = Self::ct_select(&tv3, &tv2, e1);
+            tv4 = Self::ct_select(&tv4, &tv5, e1);
+        }
+        // 27. return (isQR, tv3)
+        (is_qr, tv3)
+    }
+}
+
+impl<F: Field> ConstantTimeField for F {}
+
+/// Returns the bitwise OR of the limbs of `n`.
+fn fold_limbs<B: BigInteger>(n: &B) -> u64 {
+    n.as_ref().iter().fold(0, |acc, limb| acc | limb)
+}
+
+/// Returns `1` if `x` is zero, and `0` otherwise.
+const fn is_zero_u64(x: u64) -> u64 {
+    ((x | x.wrapping_neg()) >> 63) ^ 1
+}
+
+/// The public constants of the order `q = p^m` of the field `F`.
+struct Order<F> {
+    /// The 2-adicity `c1` of `q - 1`, such that `q - 1 = 2^c1 * c2` with `c2`
+    /// odd.
+    c1: u32,
+    /// `c3 = (c2 - 1) / 2`.
+    c3: Vec<u64>,
+    /// `q - 2`.
+    q_minus_two: Vec<u64>,
+    _field: ark_std::marker::PhantomData<F>,
+}
+
+impl<F: Field> Order<F> {
+    fn new() -> Self {
+        let p: BigUint = F::BasePrimeField::MODULUS.into();
+        let q = p.pow(F::extension_degree() as u32);
+        let q_minus_one = &q - 1u32;
+        let c1 = q_minus_one.trailing_zeros().unwrap_or(0) as usize;
+        let c3: BigUint = q_minus_one >> (c1 + 1);
+        Self {
+            c1: c1 as u32,
+            c3: c3.to_u64_digits(),
+            q_minus_two: (q - 2u32).to_u64_digits(),
+            _field: ark_std::marker::PhantomData,
+        }
+    }
+}
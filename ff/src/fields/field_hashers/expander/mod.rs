@@ -34,7 +34,6 @@ impl DST {
         DST(array)
     }
 
-    #[allow(dead_code)]
     pub fn new_xof<H: ExtendableOutput + Default>(dst: &[u8], k: usize) -> DST {
         let array = if dst.len() > MAX_DST_LENGTH {
             let mut long = H::default();
@@ -58,7 +57,6 @@ impl DST {
     }
 }
 
-#[allow(dead_code)]
 pub(super) struct ExpanderXof<H: ExtendableOutput + Clone + Default> {
     pub(super) xofer: PhantomData<H>,
     pub(super) dst: Vec<u8>,
@@ -67,6 +65,7 @@ pub(super) struct ExpanderXof<H: ExtendableOutput + Clone + Default> {
 
 impl<H: ExtendableOutput + Clone + Default> Expander for ExpanderXof<H> {
     fn expand(&self, msg: &[u8], n: usize) -> Vec<u8> {
+        assert!(n < (1 << 16), "Length should be smaller than 2^16");
         let mut xofer = H::default();
         xofer.update(msg);
 
@@ -9,6 +9,14 @@ use std::{
 };
 
 use super::{Expander, ExpanderXmd, ExpanderXof};
+use ark_test_curves::{
+    ark_ff::{
+        field_hashers::{DefaultFieldHasher, HashToField, XofFieldHasher},
+        PrimeField,
+    },
+    bls12_381::{Fq, Fq2},
+    secp256k1,
+};
 
 #[derive(Debug, serde_derive::Serialize, serde_derive::Deserialize)]
 pub struct ExpanderVector {
@@ -130,3 +138,67 @@ fn get_expander(id: ExpID, _dst: &[u8], k: usize) -> Box<dyn Expander> {
         },
     }
 }
+
+/// The field hashers must read the field elements from the output of their
+/// expander. With a 128-bit security level, each element of the base field of
+/// BLS12-381 takes 64 bytes, so that the vectors of 128 bytes are two elements
+/// of `Fq`, or one of `Fq2`.
+#[test]
+fn field_hashers() {
+    for filename in read_dir("./src/fields/field_hashers/expander/testdata").unwrap() {
+        let file = File::open(filename.unwrap().path()).unwrap();
+        let data: ExpanderVector = serde_json::from_reader(BufReader::new(file)).unwrap();
+        // The security level `k` of the SHAKE256 vectors is 256, but it only
+        // matters for domain separation tags longer than 255 bytes.
+        match data.hash.as_str() {
+            "SHA256" => check_field_hasher::<DefaultFieldHasher<Sha256>>(&data),
+            "SHA512" => check_field_hasher::<DefaultFieldHasher<Sha512>>(&data),
+            "SHAKE128" => check_field_hasher::<XofFieldHasher<Shake128>>(&data),
+            "SHAKE256" => {
+                check_field_hasher::<XofFieldHasher<Shake256>>(&data);
+                check_sec_param_256::<XofFieldHasher<Shake256, 256>>(&data);
+            },
+            _ => unimplemented!(),
+        }
+    }
+}
+
+/// At the 256-bit security level of the SHAKE256 vectors, each element of the
+/// 256-bit base field of secp256k1 takes 64 bytes, so that the vectors of 128
+/// bytes are two of its elements.
+fn check_sec_param_256<H: HashToField<secp256k1::Fq>>(data: &ExpanderVector) {
+    assert_eq!(data.k, 256);
+    let dst = data.dst.as_bytes();
+    for v in data.vectors.iter().filter(|v| v.len_in_bytes == "0x80") {
+        let bytes = hex::decode(&v.uniform_bytes).unwrap();
+        let want: Vec<secp256k1::Fq> = bytes
+            .chunks(64)
+            .map(secp256k1::Fq::from_be_bytes_mod_order)
+            .collect();
+        let got = H::new(dst).hash_to_field_vec(v.msg.as_bytes(), 2);
+        assert_eq!(got, want);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Length should be smaller than 2^16")]
+fn xof_field_hasher_too_many_elements() {
+    // Each element of `Fq2` takes 128 bytes.
+    let hasher = <XofFieldHasher<Shake128> as HashToField<Fq2>>::new(b"dst");
+    let _: Vec<Fq2> = hasher.hash_to_field_vec(b"msg", 512);
+}
+
+fn check_field_hasher<H: HashToField<Fq> + HashToField<Fq2>>(data: &ExpanderVector) {
+    let dst = data.dst.as_bytes();
+    for v in data.vectors.iter().filter(|v| v.len_in_bytes == "0x80") {
+        let bytes = hex::decode(&v.uniform_bytes).unwrap();
+        let want: Vec<Fq> = bytes.chunks(64).map(Fq::from_be_bytes_mod_order).collect();
+
+        let got: [Fq; 2] = <H as HashToField<Fq>>::new(dst).hash_to_field(v.msg.as_bytes());
+        assert_eq!(got[..], want[..]);
+
+        let got: Vec<Fq2> =
+            <H as HashToField<Fq2>>::new(dst).hash_to_field_vec(v.msg.as_bytes(), 1);
+        assert_eq!(got, [Fq2::new(want[0], want[1])]);
+    }
+}
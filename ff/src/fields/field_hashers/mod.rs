@@ -4,10 +4,11 @@ use core::marker::PhantomData;
 
 use crate::{Field, PrimeField};
 
-use digest::{FixedOutputReset, XofReader};
+use ark_std::vec::*;
+use digest::{core_api::BlockSizeUser, ExtendableOutput, FixedOutputReset, XofReader};
 use expander::Expander;
 
-use self::expander::ExpanderXmd;
+use self::expander::{ExpanderXmd, ExpanderXof};
 
 /// Trait for hashing messages to field elements.
 pub trait HashToField<F: Field>: Sized {
@@ -19,7 +20,25 @@ pub trait HashToField<F: Field>: Sized {
     fn new(domain: &[u8]) -> Self;
 
     /// Hash an arbitrary `msg` to `N` elements of the field `F`.
-    fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N];
+    fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N] {
+        let mut elems = self.hash_to_field_vec(msg, N).into_iter();
+        ark_std::array::from_fn(|_| elems.next().unwrap())
+    }
+
+    /// Hash an arbitrary `msg` to `count` elements of the field `F`, the
+    /// `hash_to_field(msg, count)` of [\[HSSWW23, Section 5.2\]].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` elements take more bytes than the expanders of
+    /// \[HSSWW23\] can output: less than `2^16` bytes in total, and for
+    /// `expand_message_xmd`, at most 255 outputs of the hash function. Each
+    /// coefficient of an element takes `ceil((ceil(log2(p)) + SEC_PARAM) / 8)`
+    /// bytes, e.g. 64 bytes for BLS12-381 at the default security level, so
+    /// that at most 63 elements of its `Fq2` can be hashed at once with SHA-256.
+    ///
+    /// [\[HSSWW23, Section 5.2\]]: <https://datatracker.ietf.org/doc/html/rfc9380#section-5.2>
+    fn hash_to_field_vec(&self, msg: &[u8], count: usize) -> Vec<F>;
 }
 
 /// This field hasher constructs a Hash-To-Field based on a fixed-output hash function,
@@ -39,23 +58,33 @@ pub trait HashToField<F: Field>: Sized {
 ///
 /// assert_eq!(field_elements.len(), 2);
 /// ```
-pub struct DefaultFieldHasher<H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize = 128> {
+pub struct DefaultFieldHasher<
+    H: FixedOutputReset + BlockSizeUser + Default + Clone,
+    const SEC_PARAM: usize = 128,
+> {
     expander: ExpanderXmd<H>,
     len_per_base_elem: usize,
 }
 
-impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> HashToField<F>
-    for DefaultFieldHasher<H, SEC_PARAM>
+impl<F: Field, H: FixedOutputReset + BlockSizeUser + Default + Clone, const SEC_PARAM: usize>
+    HashToField<F> for DefaultFieldHasher<H, SEC_PARAM>
 {
+    /// Initialises a new hasher with the domain separation tag `dst`, which
+    /// is hashed with `H` if it is longer than 255 bytes, as in
+    /// [\[HSSWW23, Section 5.3.3\]].
+    ///
+    /// [\[HSSWW23, Section 5.3.3\]]: <https://datatracker.ietf.org/doc/html/rfc9380#section-5.3.3>
     fn new(dst: &[u8]) -> Self {
         // The final output of `hash_to_field` will be an array of field
         // elements from F::BaseField, each of size `len_per_elem`.
         let len_per_base_elem = get_len_per_elem::<F, SEC_PARAM>();
 
+        // `expand_message_xmd` pads the message with a block of zeros of the
+        // hash function.
         let expander = ExpanderXmd {
             hasher: PhantomData,
             dst: dst.to_vec(),
-            block_size: len_per_base_elem,
+            block_size: H::block_size(),
         };
 
         DefaultFieldHasher {
@@ -64,27 +93,96 @@ impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> Ha
         }
     }
 
-    fn hash_to_field<const N: usize>(&self, message: &[u8]) -> [F; N] {
+    fn hash_to_field_vec(&self, message: &[u8], count: usize) -> Vec<F> {
         let m = F::extension_degree() as usize;
 
-        // The user requests `N` of elements of F_p^m to output per input msg,
-        // each field element comprising `m` BasePrimeField elements.
-        let len_in_bytes = N * m * self.len_per_base_elem;
+        // The user requests `count` of elements of F_p^m to output per input
+        // msg, each field element comprising `m` BasePrimeField elements.
+        let len_in_bytes = count * m * self.len_per_base_elem;
         let uniform_bytes = self.expander.expand(message, len_in_bytes);
+        elems_from_uniform_bytes(&uniform_bytes, self.len_per_base_elem, count)
+    }
+}
 
-        let cb = |i| {
-            let base_prime_field_elem = |j| {
-                let elm_offset = self.len_per_base_elem * (j + i * m);
-                F::BasePrimeField::from_be_bytes_mod_order(
-                    &uniform_bytes[elm_offset..][..self.len_per_base_elem],
-                )
-            };
-            F::from_base_prime_field_elems((0..m).map(base_prime_field_elem)).unwrap()
+/// This field hasher constructs a Hash-To-Field based on an extendable-output
+/// function, like SHAKE128 or SHAKE256, with the `expand_message_xof` of
+/// [\[HSSWW23, Section 5.3.2\]].
+///
+/// `SEC_PARAM` is the target security level `k` in bits, which sets the
+/// length of the bytes hashed to each base prime field element and the length
+/// of the hash of domain separation tags longer than 255 bytes. The
+/// `SHAKE128` suites of \[HSSWW23\] use 128, and the `SHAKE256` ones 256.
+///
+/// [\[HSSWW23, Section 5.3.2\]]: <https://datatracker.ietf.org/doc/html/rfc9380#section-5.3.2>
+///
+/// # Examples
+///
+/// ```
+/// use ark_ff::fields::field_hashers::{HashToField, XofFieldHasher};
+/// use ark_test_curves::bls12_381::{Fq, Fq2};
+/// use sha3::Shake128;
+///
+/// let hasher = <XofFieldHasher<Shake128> as HashToField<Fq>>::new(&[1, 2, 3]);
+/// let field_elements: [Fq; 2] = hasher.hash_to_field(b"Hello, World!");
+///
+/// // The number of elements can be chosen at runtime.
+/// let hasher = <XofFieldHasher<Shake128> as HashToField<Fq2>>::new(&[1, 2, 3]);
+/// let field_elements: Vec<Fq2> = hasher.hash_to_field_vec(b"Hello, World!", 3);
+///
+/// assert_eq!(field_elements.len(), 3);
+/// ```
+pub struct XofFieldHasher<H: ExtendableOutput + Default + Clone, const SEC_PARAM: usize = 128> {
+    expander: ExpanderXof<H>,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, H: ExtendableOutput + Default + Clone, const SEC_PARAM: usize> HashToField<F>
+    for XofFieldHasher<H, SEC_PARAM>
+{
+    /// Initialises a new hasher with the domain separation tag `dst`, which
+    /// is hashed to `ceil(2 * SEC_PARAM / 8)` bytes if it is longer than 255
+    /// bytes, as in [\[HSSWW23, Section 5.3.3\]].
+    ///
+    /// [\[HSSWW23, Section 5.3.3\]]: <https://datatracker.ietf.org/doc/html/rfc9380#section-5.3.3>
+    fn new(dst: &[u8]) -> Self {
+        let len_per_base_elem = get_len_per_elem::<F, SEC_PARAM>();
+        let expander = ExpanderXof {
+            xofer: PhantomData,
+            dst: dst.to_vec(),
+            k: SEC_PARAM,
         };
-        ark_std::array::from_fn(cb)
+
+        XofFieldHasher {
+            expander,
+            len_per_base_elem,
+        }
+    }
+
+    fn hash_to_field_vec(&self, message: &[u8], count: usize) -> Vec<F> {
+        let m = F::extension_degree() as usize;
+        let len_in_bytes = count * m * self.len_per_base_elem;
+        let uniform_bytes = self.expander.expand(message, len_in_bytes);
+        elems_from_uniform_bytes(&uniform_bytes, self.len_per_base_elem, count)
     }
 }
 
+/// Reads `count` elements of `F` from the output of an expander, each of
+/// their coefficients from `len_per_base_elem` bytes interpreted in
+/// big-endian order.
+fn elems_from_uniform_bytes<F: Field>(
+    uniform_bytes: &[u8],
+    len_per_base_elem: usize,
+    count: usize,
+) -> Vec<F> {
+    let m = F::extension_degree() as usize;
+    let mut base_prime_field_elems = uniform_bytes
+        .chunks(len_per_base_elem)
+        .map(F::BasePrimeField::from_be_bytes_mod_order);
+    (0..count)
+        .map(|_| F::from_base_prime_field_elems(base_prime_field_elems.by_ref().take(m)).unwrap())
+        .collect()
+}
+
 pub fn hash_to_field<F: Field, H: XofReader, const SEC_PARAM: usize>(h: &mut H) -> F {
     // The final output of `hash_to_field` will be an array of field
     // elements from F::BaseField, each of size `len_per_elem`.
@@ -38,6 +38,9 @@ pub use cyclotomic::*;
 mod sqrt;
 pub use sqrt::*;
 
+mod constant_time;
+pub use constant_time::*;
+
 #[cfg(feature = "parallel")]
 use ark_std::cmp::max;
 #[cfg(feature = "parallel")]
@@ -278,6 +281,15 @@ pub trait Field:
         })
     }
 
+    /// Returns the square roots of `elems`, as [`Self::sqrt`] would.
+    ///
+    /// The square roots are computed in parallel if the `parallel` feature
+    /// is enabled. Fields whose square roots require an inversion override
+    /// this method to share that inversion between all the elements.
+    fn batch_sqrt(elems: &[Self]) -> Vec<Option<Self>> {
+        ark_std::cfg_iter!(elems).map(Self::sqrt).collect()
+    }
+
     /// Returns `self * self`.
     #[must_use]
     fn square(&self) -> Self;
@@ -4,8 +4,10 @@ use crate::{
     UniformRand, Zero,
 };
 use ark_serialize::{
+    envelope::{ParametersHasher, ParametersId},
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, RawLayout, SerializationError, Valid,
+    Validate,
 };
 use ark_std::{
     cmp::*,
@@ -75,7 +77,7 @@ pub trait CubicExtConfig: 'static + Send + Sync + Sized {
 
 /// An element of a cubic extension field F_p\[X\]/(X^3 - P::NONRESIDUE) is
 /// represented as c0 + c1 * X + c2 * X^2, for c0, c1, c2 in `P::BaseField`.
-#[derive(educe::Educe, CanonicalDeserialize)]
+#[derive(educe::Educe)]
 #[educe(Default, Hash, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CubicExtField<P: CubicExtConfig> {
     pub c0: P::BaseField,
@@ -624,6 +626,27 @@ impl<P: CubicExtConfig> CanonicalSerialize for CubicExtField<P> {
     fn serialized_size(&self, _compress: Compress) -> usize {
         self.serialized_size_with_flags::<EmptyFlags>()
     }
+
+    /// The raw limbs of an extension field element are those of its
+    /// coefficients, in order.
+    fn raw_layout() -> Option<RawLayout> {
+        let base = P::BaseField::raw_layout()?;
+        let mut hasher = ParametersHasher::new(b"CubicExtRaw");
+        hasher.update(&base.id.to_le_bytes());
+        hasher.update_serialized(&P::NONRESIDUE);
+        Some(RawLayout {
+            limbs: 3 * base.limbs,
+            id: hasher.finish(),
+        })
+    }
+
+    fn write_raw_limbs(&self, limbs: &mut [u64]) {
+        let (c0, rest) = limbs.split_at_mut(limbs.len() / 3);
+        let (c1, c2) = rest.split_at_mut(c0.len());
+        self.c0.write_raw_limbs(c0);
+        self.c1.write_raw_limbs(c1);
+        self.c2.write_raw_limbs(c2);
+    }
 }
 
 impl<P: CubicExtConfig> CanonicalDeserializeWithFlags for CubicExtField<P> {
@@ -638,6 +661,60 @@ impl<P: CubicExtConfig> CanonicalDeserializeWithFlags for CubicExtField<P> {
     }
 }
 
+impl<P: CubicExtConfig> Valid for CubicExtField<P> {
+    const TRIVIAL_CHECK: bool = P::BaseField::TRIVIAL_CHECK;
+
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        self.c0.check()?;
+        self.c1.check()?;
+        self.c2.check()
+    }
+}
+
+impl<P: CubicExtConfig> CanonicalDeserialize for CubicExtField<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c2 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        Some(3 * P::BaseField::fixed_serialized_size(compress)?)
+    }
+
+    fn expected_raw_layout() -> Option<RawLayout> {
+        <Self as CanonicalSerialize>::raw_layout()
+    }
+
+    fn read_raw_limbs(limbs: &[u64]) -> Option<Self> {
+        let mut coeffs = limbs.chunks_exact(limbs.len() / 3);
+        let c0 = P::BaseField::read_raw_limbs(coeffs.next()?)?;
+        let c1 = P::BaseField::read_raw_limbs(coeffs.next()?)?;
+        let c2 = P::BaseField::read_raw_limbs(coeffs.next()?)?;
+        Some(Self::new(c0, c1, c2))
+    }
+}
+
+impl<P: CubicExtConfig> ParametersId for CubicExtField<P>
+where
+    P::BaseField: ParametersId,
+{
+    fn parameters_id() -> u64 {
+        let mut hasher = ParametersHasher::new(b"CubicExt");
+        hasher.update(&P::BaseField::parameters_id().to_le_bytes());
+        hasher.update_serialized(&P::NONRESIDUE);
+        hasher.finish()
+    }
+}
+
 impl<P: CubicExtConfig> ToConstraintField<P::BasePrimeField> for CubicExtField<P>
 where
     P::BaseField: ToConstraintField<P::BasePrimeField>,
@@ -3,8 +3,11 @@ use crate::{
     SqrtPrecomputation, Zero,
 };
 use ark_serialize::{
-    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
+    buffer_byte_size,
+    envelope::{ParametersHasher, ParametersId},
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, RawLayout, SerializationError, Valid,
+    Validate,
 };
 use ark_std::{
     cmp::*,
@@ -596,6 +599,24 @@ impl<P: FpConfig<N>, const N: usize> CanonicalSerialize for Fp<P, N> {
     fn serialized_size(&self, _compress: Compress) -> usize {
         self.serialized_size_with_flags::<EmptyFlags>()
     }
+
+    /// The raw limbs of a prime field element are its internal
+    /// representation, e.g. its Montgomery form, which is identified by the
+    /// modulus and the representation of one.
+    fn raw_layout() -> Option<RawLayout> {
+        let mut hasher = ParametersHasher::new(b"FpRaw");
+        hasher.update_serialized(&P::MODULUS);
+        hasher.update_serialized(&P::ONE.0);
+        Some(RawLayout {
+            limbs: N,
+            id: hasher.finish(),
+        })
+    }
+
+    #[inline]
+    fn write_raw_limbs(&self, limbs: &mut [u64]) {
+        limbs.copy_from_slice(&self.0 .0);
+    }
 }
 
 impl<P: FpConfig<N>, const N: usize> CanonicalDeserializeWithFlags for Fp<P, N> {
@@ -647,6 +668,30 @@ impl<P: FpConfig<N>, const N: usize> CanonicalDeserialize for Fp<P, N> {
     ) -> Result<Self, SerializationError> {
         Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
     }
+
+    #[inline]
+    fn fixed_serialized_size(_compress: Compress) -> Option<usize> {
+        Some(buffer_byte_size(Self::MODULUS_BIT_SIZE as usize))
+    }
+
+    fn expected_raw_layout() -> Option<RawLayout> {
+        <Self as CanonicalSerialize>::raw_layout()
+    }
+
+    fn read_raw_limbs(limbs: &[u64]) -> Option<Self> {
+        let repr = BigInt(limbs.try_into().ok()?);
+        (repr < P::MODULUS).then_some(Self(repr, PhantomData))
+    }
+}
+
+/// Prime fields are identified by their modulus, which determines their
+/// serialization regardless of the `FpConfig` backend.
+impl<P: FpConfig<N>, const N: usize> ParametersId for Fp<P, N> {
+    fn parameters_id() -> u64 {
+        let mut hasher = ParametersHasher::new(b"Fp");
+        hasher.update_serialized(&P::MODULUS);
+        hasher.finish()
+    }
 }
 
 impl<P: FpConfig<N>, const N: usize> FromStr for Fp<P, N> {
@@ -1,13 +1,17 @@
 use crate::{
+    batch_inversion,
     biginteger::BigInteger,
     fields::{Field, LegendreSymbol, PrimeField},
     AdditiveGroup, FftField, One, SqrtPrecomputation, ToConstraintField, UniformRand, Zero,
 };
 use ark_serialize::{
+    envelope::{ParametersHasher, ParametersId},
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, RawLayout, SerializationError, Valid,
+    Validate,
 };
 use ark_std::{
+    cfg_into_iter, cfg_iter,
     cmp::*,
     fmt,
     io::{Read, Write},
@@ -21,6 +25,9 @@ use ark_std::{
 };
 use zeroize::Zeroize;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Defines a Quadratic extension field from a quadratic non-residue.
 pub trait QuadExtConfig: 'static + Send + Sync + Sized {
     /// The prime field that this quadratic extension is eventually an extension of.
@@ -89,7 +96,7 @@ pub trait QuadExtConfig: 'static + Send + Sync + Sized {
 
 /// An element of a quadratic extension field F_p\[X\]/(X^2 - P::NONRESIDUE) is
 /// represented as c0 + c1 * X, for c0, c1 in `P::BaseField`.
-#[derive(educe::Educe, CanonicalDeserialize)]
+#[derive(educe::Educe)]
 #[educe(Default, Hash, Clone, Copy, Debug, PartialEq, Eq)]
 pub struct QuadExtField<P: QuadExtConfig> {
     /// Coefficient `c0` in the representation of the field element `c = c0 + c1 * X`
@@ -159,6 +166,84 @@ impl<P: QuadExtConfig> QuadExtField<P> {
         self.c0 *= element;
         self.c1 *= element;
     }
+
+    /// Square root based on the complex method. See
+    /// https://eprint.iacr.org/2012/685.pdf (page 15, algorithm 8)
+    ///
+    /// Unless the square root is found without an inversion, returns the
+    /// coordinate `c0` of the square root and `c1 / 2`: the square root is
+    /// `c0 + c1 / (2 * c0) * x`, see [`Self::sqrt_second_phase`].
+    fn sqrt_first_phase(&self) -> SqrtPhase<P> {
+        if self.c1.is_zero() {
+            // for c = c0 + c1 * x, we have c1 = 0
+            // sqrt(c) == sqrt(c0) is an element of Fp2, i.e. sqrt(c0) = a = a0 + a1 * x for some a0, a1 in Fp
+            // squaring both sides: c0 = a0^2 + a1^2 * x^2 + (2 * a0 * a1 * x) = a0^2 + (a1^2 * P::NONRESIDUE)
+            // since there are no `x` terms on LHS, a0 * a1 = 0
+            // so either a0 = sqrt(c0) or a1 = sqrt(c0/P::NONRESIDUE)
+            let sqrt = if self.c0.legendre().is_qr() {
+                // either c0 is a valid sqrt in the base field
+                self.c0.sqrt().map(|c0| Self::new(c0, P::BaseField::ZERO))
+            } else {
+                // or we need to compute sqrt(c0/P::NONRESIDUE)
+                (self.c0.div(P::NONRESIDUE))
+                    .sqrt()
+                    .map(|res| Self::new(P::BaseField::ZERO, res))
+            };
+            return SqrtPhase::Done(sqrt);
+        }
+        // Try computing the square root
+        // Check at the end of the algorithm if it was a square root
+        let alpha = self.norm();
+
+        // Compute `(p+1)/2` as `1/2`.
+        // This is cheaper than `P::BaseField::one().double().inverse()`
+        let mut two_inv = P::BasePrimeField::MODULUS;
+
+        two_inv.add_with_carry(&1u64.into());
+        two_inv.div2();
+
+        let two_inv = P::BasePrimeField::from(two_inv);
+        let two_inv = P::BaseField::from_base_prime_field(two_inv);
+
+        match alpha.sqrt() {
+            Some(alpha) => {
+                let mut delta = (alpha + &self.c0) * &two_inv;
+                if delta.legendre().is_qnr() {
+                    delta -= &alpha;
+                }
+                let c0 = delta.sqrt().expect("Delta must have a square root");
+                SqrtPhase::Pending(c0, self.c1 * &two_inv)
+            },
+            None => SqrtPhase::Done(None),
+        }
+    }
+
+    /// Returns the square root candidate `c0 + c1 * x` if it is a square
+    /// root of `self`, and `None` otherwise, in which case there exists no
+    /// square root.
+    fn sqrt_second_phase(&self, c0: P::BaseField, c1: P::BaseField) -> Option<Self> {
+        let sqrt_cand = Self::new(c0, c1);
+        // Check if sqrt_cand is actually the square root
+        // if not, there exists no square root.
+        if sqrt_cand.square() == *self {
+            Some(sqrt_cand)
+        } else {
+            #[cfg(debug_assertions)]
+            {
+                use crate::fields::LegendreSymbol::*;
+                if self.legendre() != QuadraticNonResidue {
+                    panic!("Input has a square root per its legendre symbol, but it was not found")
+                }
+            }
+            None
+        }
+    }
+}
+
+/// The result of [`QuadExtField::sqrt_first_phase`].
+enum SqrtPhase<P: QuadExtConfig> {
+    Done(Option<QuadExtField<P>>),
+    Pending(P::BaseField, P::BaseField),
 }
 
 impl<P: QuadExtConfig> Zero for QuadExtField<P> {
@@ -366,63 +451,35 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
     }
 
     fn sqrt(&self) -> Option<Self> {
-        // Square root based on the complex method. See
-        // https://eprint.iacr.org/2012/685.pdf (page 15, algorithm 8)
-        if self.c1.is_zero() {
-            // for c = c0 + c1 * x, we have c1 = 0
-            // sqrt(c) == sqrt(c0) is an element of Fp2, i.e. sqrt(c0) = a = a0 + a1 * x for some a0, a1 in Fp
-            // squaring both sides: c0 = a0^2 + a1^2 * x^2 + (2 * a0 * a1 * x) = a0^2 + (a1^2 * P::NONRESIDUE)
-            // since there are no `x` terms on LHS, a0 * a1 = 0
-            // so either a0 = sqrt(c0) or a1 = sqrt(c0/P::NONRESIDUE)
-            if self.c0.legendre().is_qr() {
-                // either c0 is a valid sqrt in the base field
-                return self.c0.sqrt().map(|c0| Self::new(c0, P::BaseField::ZERO));
-            } else {
-                // or we need to compute sqrt(c0/P::NONRESIDUE)
-                return (self.c0.div(P::NONRESIDUE))
-                    .sqrt()
-                    .map(|res| Self::new(P::BaseField::ZERO, res));
-            }
+        match self.sqrt_first_phase() {
+            SqrtPhase::Done(sqrt) => sqrt,
+            SqrtPhase::Pending(c0, half_c1) => {
+                let c0_inv = c0.inverse().expect("c0 must have an inverse");
+                self.sqrt_second_phase(c0, half_c1 * c0_inv)
+            },
         }
-        // Try computing the square root
-        // Check at the end of the algorithm if it was a square root
-        let alpha = self.norm();
-
-        // Compute `(p+1)/2` as `1/2`.
-        // This is cheaper than `P::BaseField::one().double().inverse()`
-        let mut two_inv = P::BasePrimeField::MODULUS;
-
-        two_inv.add_with_carry(&1u64.into());
-        two_inv.div2();
-
-        let two_inv = P::BasePrimeField::from(two_inv);
-        let two_inv = P::BaseField::from_base_prime_field(two_inv);
+    }
 
-        alpha.sqrt().and_then(|alpha| {
-            let mut delta = (alpha + &self.c0) * &two_inv;
-            if delta.legendre().is_qnr() {
-                delta -= &alpha;
-            }
-            let c0 = delta.sqrt().expect("Delta must have a square root");
-            let c0_inv = c0.inverse().expect("c0 must have an inverse");
-            let sqrt_cand = Self::new(c0, self.c1 * &two_inv * &c0_inv);
-            // Check if sqrt_cand is actually the square root
-            // if not, there exists no square root.
-            if sqrt_cand.square() == *self {
-                Some(sqrt_cand)
-            } else {
-                #[cfg(debug_assertions)]
-                {
-                    use crate::fields::LegendreSymbol::*;
-                    if self.legendre() != QuadraticNonResidue {
-                        panic!(
-                            "Input has a square root per its legendre symbol, but it was not found"
-                        )
-                    }
-                }
-                None
-            }
-        })
+    /// Computes the square roots as [`Self::sqrt`], inverting the
+    /// coordinates `c0` of all the square roots at once.
+    fn batch_sqrt(elems: &[Self]) -> Vec<Option<Self>> {
+        let phases: Vec<_> = cfg_iter!(elems).map(Self::sqrt_first_phase).collect();
+        let mut c0_invs: Vec<_> = phases
+            .iter()
+            .map(|phase| match phase {
+                SqrtPhase::Pending(c0, _) => *c0,
+                SqrtPhase::Done(_) => P::BaseField::ZERO,
+            })
+            .collect();
+        batch_inversion(&mut c0_invs);
+        cfg_into_iter!(phases)
+            .zip(c0_invs)
+            .zip(elems)
+            .map(|((phase, c0_inv), elem)| match phase {
+                SqrtPhase::Done(sqrt) => sqrt,
+                SqrtPhase::Pending(c0, half_c1) => elem.sqrt_second_phase(c0, half_c1 * c0_inv),
+            })
+            .collect()
     }
 
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
@@ -714,6 +771,25 @@ impl<P: QuadExtConfig> CanonicalSerialize for QuadExtField<P> {
     fn serialized_size(&self, _compress: Compress) -> usize {
         self.serialized_size_with_flags::<EmptyFlags>()
     }
+
+    /// The raw limbs of an extension field element are those of its
+    /// coefficients, in order.
+    fn raw_layout() -> Option<RawLayout> {
+        let base = P::BaseField::raw_layout()?;
+        let mut hasher = ParametersHasher::new(b"QuadExtRaw");
+        hasher.update(&base.id.to_le_bytes());
+        hasher.update_serialized(&P::NONRESIDUE);
+        Some(RawLayout {
+            limbs: 2 * base.limbs,
+            id: hasher.finish(),
+        })
+    }
+
+    fn write_raw_limbs(&self, limbs: &mut [u64]) {
+        let (c0, c1) = limbs.split_at_mut(limbs.len() / 2);
+        self.c0.write_raw_limbs(c0);
+        self.c1.write_raw_limbs(c1);
+    }
 }
 
 impl<P: QuadExtConfig> CanonicalDeserializeWithFlags for QuadExtField<P> {
@@ -727,6 +803,57 @@ impl<P: QuadExtConfig> CanonicalDeserializeWithFlags for QuadExtField<P> {
     }
 }
 
+impl<P: QuadExtConfig> Valid for QuadExtField<P> {
+    const TRIVIAL_CHECK: bool = P::BaseField::TRIVIAL_CHECK;
+
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        self.c0.check()?;
+        self.c1.check()
+    }
+}
+
+impl<P: QuadExtConfig> CanonicalDeserialize for QuadExtField<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        Some(2 * P::BaseField::fixed_serialized_size(compress)?)
+    }
+
+    fn expected_raw_layout() -> Option<RawLayout> {
+        <Self as CanonicalSerialize>::raw_layout()
+    }
+
+    fn read_raw_limbs(limbs: &[u64]) -> Option<Self> {
+        let mut coeffs = limbs.chunks_exact(limbs.len() / 2);
+        let c0 = P::BaseField::read_raw_limbs(coeffs.next()?)?;
+        let c1 = P::BaseField::read_raw_limbs(coeffs.next()?)?;
+        Some(Self::new(c0, c1))
+    }
+}
+
+impl<P: QuadExtConfig> ParametersId for QuadExtField<P>
+where
+    P::BaseField: ParametersId,
+{
+    fn parameters_id() -> u64 {
+        let mut hasher = ParametersHasher::new(b"QuadExt");
+        hasher.update(&P::BaseField::parameters_id().to_le_bytes());
+        hasher.update_serialized(&P::NONRESIDUE);
+        hasher.finish()
+    }
+}
+
 impl<P: QuadExtConfig> ToConstraintField<P::BasePrimeField> for QuadExtField<P>
 where
     P::BaseField: ToConstraintField<P::BasePrimeField>,
@@ -14,10 +14,15 @@ use criterion::{criterion_group, criterion_main, Bencher, BenchmarkId, Criterion
 const BENCHMARK_MIN_DEGREE: usize = 1 << 4;
 const BENCHMARK_MAX_DEGREE_BLS12_381: usize = 1 << 22;
 const BENCHMARK_MAX_DEGREE_MNT6_753: usize = 1 << 17;
+// degree bounds for the large FFTs, which straddle the size at which
+// `Radix2EvaluationDomain` switches to the four-step FFT.
+const BENCHMARK_MIN_DEGREE_LARGE: usize = 1 << 18;
+const BENCHMARK_MAX_DEGREE_LARGE: usize = 1 << 24;
 const BENCHMARK_LOG_INTERVAL_DEGREE: usize = 1;
 
 const ENABLE_RADIX2_BENCHES: bool = true;
 const ENABLE_MIXED_RADIX_BENCHES: bool = true;
+const ENABLE_LARGE_RADIX2_BENCHES: bool = true;
 
 // returns vec![2^{min}, 2^{min + interval}, ..., 2^{max}], where:
 // interval = BENCHMARK_LOG_INTERVAL_DEGREE
@@ -39,6 +44,14 @@ fn default_size_range_mnt6_753() -> Vec<usize> {
     )
 }
 
+fn large_size_range() -> Vec<usize> {
+    size_range(
+        BENCHMARK_LOG_INTERVAL_DEGREE,
+        BENCHMARK_MIN_DEGREE_LARGE,
+        BENCHMARK_MAX_DEGREE_LARGE,
+    )
+}
+
 fn setup_bench(
     c: &mut Criterion,
     name: &str,
@@ -162,6 +175,25 @@ fn bench_bls12_381(c: &mut Criterion) {
     }
 }
 
+fn bench_bls12_381_large(c: &mut Criterion) {
+    if ENABLE_LARGE_RADIX2_BENCHES {
+        let name = "BLS12_381 - Radix2 - Large Subgroup FFT";
+        setup_bench(
+            c,
+            name,
+            bench_fft_in_place::<bls12_381_fr, Radix2EvaluationDomain<bls12_381_fr>>,
+            &large_size_range(),
+        );
+        let name = "BLS12_381 - Radix2 - Large Subgroup IFFT";
+        setup_bench(
+            c,
+            name,
+            bench_ifft_in_place::<bls12_381_fr, Radix2EvaluationDomain<bls12_381_fr>>,
+            &large_size_range(),
+        );
+    }
+}
+
 fn bench_mnt6_753(c: &mut Criterion) {
     let name = "MNT6_753 - Mixed Radix";
     if ENABLE_MIXED_RADIX_BENCHES {
@@ -173,5 +205,10 @@ fn bench_mnt6_753(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_bls12_381, bench_mnt6_753);
+criterion_group!(
+    benches,
+    bench_bls12_381,
+    bench_bls12_381_large,
+    bench_mnt6_753
+);
 criterion_main!(benches);
@@ -12,7 +12,8 @@ use crate::domain::{
 };
 use ark_ff::{FftField, Field};
 use ark_serialize::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    CanonicalDeserialize, CanonicalSerialize, Compress, DeserializeLimits, SerializationError,
+    Valid, Validate,
 };
 use ark_std::{
     io::{Read, Write},
@@ -103,15 +104,33 @@ impl<F: FftField> Valid for GeneralEvaluationDomain<F> {
 
 impl<F: FftField> CanonicalDeserialize for GeneralEvaluationDomain<F> {
     fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_limits(
+            reader,
+            compress,
+            validate,
+            &mut DeserializeLimits::unlimited(),
+        )
+    }
+
+    fn deserialize_with_limits<R: Read>(
         mut reader: R,
         compress: Compress,
         validate: Validate,
+        limits: &mut DeserializeLimits,
     ) -> Result<Self, SerializationError> {
-        match u8::deserialize_with_mode(&mut reader, compress, validate)? {
-            0 => Radix2EvaluationDomain::deserialize_with_mode(&mut reader, compress, validate)
-                .map(Self::Radix2),
-            1 => MixedRadixEvaluationDomain::deserialize_with_mode(&mut reader, compress, validate)
-                .map(Self::MixedRadix),
+        match u8::deserialize_with_limits(&mut reader, compress, validate, limits)? {
+            0 => {
+                Radix2EvaluationDomain::deserialize_with_limits(reader, compress, validate, limits)
+                    .map(Self::Radix2)
+            },
+            1 => MixedRadixEvaluationDomain::deserialize_with_limits(
+                reader, compress, validate, limits,
+            )
+            .map(Self::MixedRadix),
             _ => Err(SerializationError::InvalidData),
         }
     }
@@ -268,6 +268,34 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
     omega: F,
     two_adicity: u32,
 ) {
+    mixed_radix_fft_with_powers(a, two_adicity, |exp| {
+        let w = omega.pow([exp as u64]);
+        ark_std::iter::successors(Some(F::one()), move |w_j| Some(*w_j * w))
+    });
+}
+
+/// Computes a mixed-radix FFT of `a` using a table of precomputed roots of
+/// unity, where `roots[i * stride]` must equal `omega^i` for `i < a.len()`.
+pub(crate) fn serial_mixed_radix_fft_with_roots<T: DomainCoeff<F>, F: FftField>(
+    a: &mut [T],
+    roots: &[F],
+    stride: usize,
+    two_adicity: u32,
+) {
+    assert!(roots.len() > (a.len() - 1) * stride);
+    mixed_radix_fft_with_powers(a, two_adicity, |exp| {
+        roots.iter().step_by(exp * stride).copied()
+    });
+}
+
+/// Computes a mixed-radix FFT of `a` with respect to `omega`, where
+/// `powers(e)` yields `omega^(j * e)` for `j = 0, 1, ...`.
+fn mixed_radix_fft_with_powers<T, F, I>(a: &mut [T], two_adicity: u32, powers: impl Fn(usize) -> I)
+where
+    T: DomainCoeff<F>,
+    F: FftField,
+    I: Iterator<Item = F>,
+{
     // Conceptually, this FFT first splits into 2 sub-arrays two_adicity many times,
     // and then splits into q sub-arrays q_adicity many times.
 
@@ -313,22 +341,17 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
             }
         }
 
-        let omega_q = omega.pow([(n / q) as u64]);
-        let mut qth_roots = Vec::with_capacity(q);
-        qth_roots.push(F::one());
-        for i in 1..q {
-            qth_roots.push(qth_roots[i - 1] * omega_q);
-        }
+        // qth_roots[i] = omega^{i * n / q}
+        let qth_roots: Vec<F> = powers(n / q).take(q).collect();
 
         let mut terms = vec![T::zero(); q - 1];
 
         // Doing the q_adicity passes.
         for _ in 0..q_adicity {
-            let w_m = omega.pow([(n / (q * m)) as u64]);
+            // w_m = omega^{n / (q * m)}, and w_j = w_m^j
             let mut k = 0;
             while k < n {
-                let mut w_j = F::one(); // w_j is omega_m ^ j
-                for j in 0..m {
+                for (j, w_j) in (0..m).zip(powers(n / (q * m))) {
                     let base_term = a[k + j];
                     let mut w_j_i = w_j;
                     for i in 1..q {
@@ -345,8 +368,6 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
                             a[k + j + i * m] += tmp;
                         }
                     }
-
-                    w_j *= w_m;
                 }
 
                 k += q * m;
@@ -358,19 +379,15 @@ pub(crate) fn serial_mixed_radix_fft<T: DomainCoeff<F>, F: FftField>(
     }
 
     for _ in 0..two_adicity {
-        // w_m is 2^s-th root of unity now
-        let w_m = omega.pow([(n / (2 * m)) as u64]);
-
+        // w_m = omega^{n / (2 * m)} is a 2^s-th root of unity now, and w = w_m^j
         let mut k = 0;
         while k < n {
-            let mut w = F::one();
-            for j in 0..m {
+            for (j, w) in (0..m).zip(powers(n / (2 * m))) {
                 let mut t = a[(k + m) + j];
                 t *= w;
                 a[(k + m) + j] = a[k + j];
                 a[(k + m) + j] -= t;
                 a[k + j] += t;
-                w *= w_m;
             }
             k += 2 * m;
         }
@@ -16,11 +16,13 @@ use rayon::prelude::*;
 
 pub mod general;
 pub mod mixed_radix;
+pub mod plan;
 pub mod radix2;
 pub(crate) mod utils;
 
 pub use general::GeneralEvaluationDomain;
 pub use mixed_radix::MixedRadixEvaluationDomain;
+pub use plan::FftPlan;
 pub use radix2::Radix2EvaluationDomain;
 
 /// Defines a domain over which finite field (I)FFTs can be performed.
@@ -111,6 +113,64 @@ pub trait EvaluationDomain<F: FftField>:
     /// Compute a IFFT, modifying the vector in place.
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>);
 
+    /// Compute a FFT using the precomputed twiddle factors in `plan`.
+    #[inline]
+    fn fft_with_plan<T: DomainCoeff<F>>(&self, plan: &FftPlan<F>, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place_with_plan(plan, &mut coeffs);
+        coeffs
+    }
+
+    /// Compute a FFT using the precomputed twiddle factors in `plan`,
+    /// modifying the vector in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `plan` was not computed for the subgroup underlying `self`.
+    fn fft_in_place_with_plan<T: DomainCoeff<F>>(&self, plan: &FftPlan<F>, coeffs: &mut Vec<T>) {
+        assert!(
+            plan.is_compatible_with(self),
+            "FFT plan does not match the domain"
+        );
+        if !self.coset_offset().is_one() {
+            Self::distribute_powers(coeffs, self.coset_offset());
+        }
+        coeffs.resize(self.size(), T::zero());
+        plan.fft_in_place(coeffs);
+    }
+
+    /// Compute a IFFT using the precomputed twiddle factors in `plan`.
+    #[inline]
+    fn ifft_with_plan<T: DomainCoeff<F>>(&self, plan: &FftPlan<F>, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place_with_plan(plan, &mut evals);
+        evals
+    }
+
+    /// Compute a IFFT using the precomputed twiddle factors in `plan`,
+    /// modifying the vector in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `plan` was not computed for the subgroup underlying `self`.
+    fn ifft_in_place_with_plan<T: DomainCoeff<F>>(&self, plan: &FftPlan<F>, evals: &mut Vec<T>) {
+        assert!(
+            plan.is_compatible_with(self),
+            "FFT plan does not match the domain"
+        );
+        evals.resize(self.size(), T::zero());
+        plan.ifft_in_place(evals);
+        if self.coset_offset().is_one() {
+            ark_std::cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv());
+        } else {
+            Self::distribute_powers_and_mul_by_const(
+                evals,
+                self.coset_offset_inv(),
+                self.size_inv(),
+            );
+        }
+    }
+
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
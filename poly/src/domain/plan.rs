@@ -0,0 +1,389 @@
+//! This module defines `FftPlan`, which holds the roots of unity needed to
+//! perform (I)FFTs over a fixed multiplicative subgroup.
+//!
+//! Computing an FFT requires the powers of a generator of the subgroup, which
+//! `EvaluationDomain::fft_in_place` recomputes on every call. When many FFTs of
+//! the same size are performed, these can instead be computed once with
+//! `FftPlan::new` and passed to `EvaluationDomain::fft_in_place_with_plan`.
+//! A plan is immutable once created, so it can be shared across threads by
+//! wrapping it in an `Arc`.
+
+use crate::domain::{
+    mixed_radix::serial_mixed_radix_fft_with_roots,
+    utils::{best_fft, bitreverse_permutation_in_place, compute_powers_serial},
+    DomainCoeff, EvaluationDomain,
+};
+use ark_ff::{FftField, Field};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, DeserializeLimits, Read,
+    SerializationError, Valid, Validate,
+};
+use ark_std::{cfg_chunks_mut, cfg_iter, cfg_iter_mut, fmt, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Precomputed forward and inverse twiddle factors for (I)FFTs over the
+/// multiplicative subgroup generated by `group_gen`.
+///
+/// If the size of the subgroup is a power of two, the twiddles are stored in
+/// bit-reversed order, i.e. `twiddles[i] = group_gen^{bitrev(i)}` for
+/// `i < size / 2`, so that every layer of the FFT reads a contiguous prefix of
+/// the table. Otherwise, `twiddles[i] = group_gen^i` for `i < size`, as
+/// required by the mixed-radix FFT.
+#[derive(Clone, Hash, PartialEq, Eq, CanonicalSerialize)]
+pub struct FftPlan<F: Field> {
+    /// The size of the subgroup.
+    size: u64,
+    /// A generator of the subgroup.
+    group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    group_gen_inv: F,
+    /// Twiddle factors for the forward FFT.
+    twiddles: Vec<F>,
+    /// Twiddle factors for the inverse FFT.
+    inv_twiddles: Vec<F>,
+}
+
+impl<F: Field> fmt::Debug for FftPlan<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FFT plan for a subgroup of size {}", self.size)
+    }
+}
+
+impl<F: FftField> FftPlan<F> {
+    /// Precompute the twiddle factors for (I)FFTs over `domain`.
+    ///
+    /// The plan only depends on the subgroup underlying `domain`, and can be
+    /// used for any coset of it.
+    pub fn new<D: EvaluationDomain<F>>(domain: &D) -> Self {
+        Self::from_generator(domain.size() as u64, domain.group_gen())
+    }
+
+    /// Precompute the twiddle factors for (I)FFTs over the subgroup of size
+    /// `size` generated by `group_gen`.
+    fn from_generator(size: u64, group_gen: F) -> Self {
+        let group_gen_inv = group_gen.inverse().unwrap();
+        let n = size as usize;
+        let (twiddles, inv_twiddles) = if n.is_power_of_two() {
+            let log_half = ark_std::log2(n / 2);
+            let bitreverse_powers = |root: F| {
+                let mut powers = compute_powers_serial(n / 2, root);
+                bitreverse_permutation_in_place(&mut powers, log_half);
+                powers
+            };
+            (
+                bitreverse_powers(group_gen),
+                bitreverse_powers(group_gen_inv),
+            )
+        } else {
+            (
+                compute_powers_serial(n, group_gen),
+                compute_powers_serial(n, group_gen_inv),
+            )
+        };
+        Self {
+            size,
+            group_gen,
+            group_gen_inv,
+            twiddles,
+            inv_twiddles,
+        }
+    }
+
+    /// Return the size of the subgroup this plan was computed for.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Return the generator of the subgroup this plan was computed for.
+    #[inline]
+    pub const fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    /// Return the inverse of `self.group_gen()`.
+    #[inline]
+    pub const fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    /// Return whether this plan can be used for (I)FFTs over `domain`.
+    pub fn is_compatible_with<D: EvaluationDomain<F>>(&self, domain: &D) -> bool {
+        self.size() == domain.size() && self.group_gen == domain.group_gen()
+    }
+
+    /// Computes the FFT of `a` over the subgroup, leaving the coset offset to
+    /// the caller.
+    pub(crate) fn fft_in_place<T: DomainCoeff<F>>(&self, a: &mut [T]) {
+        self.fft_helper_in_place(a, &self.twiddles, self.group_gen, false);
+    }
+
+    /// Computes the IFFT of `a` over the subgroup, without dividing by the size
+    /// of the subgroup. The normalization and the coset offset are left to the
+    /// caller.
+    pub(crate) fn ifft_in_place<T: DomainCoeff<F>>(&self, a: &mut [T]) {
+        self.fft_helper_in_place(a, &self.inv_twiddles, self.group_gen_inv, true);
+    }
+
+    fn fft_helper_in_place<T: DomainCoeff<F>>(
+        &self,
+        a: &mut [T],
+        twiddles: &[F],
+        root: F,
+        inverse: bool,
+    ) {
+        assert_eq!(a.len(), self.size());
+        let n = a.len();
+        if n.is_power_of_two() {
+            let log_n = ark_std::log2(n);
+            if inverse {
+                bitreverse_permutation_in_place(a, log_n);
+                gs_butterflies(a, twiddles);
+            } else {
+                ct_butterflies(a, twiddles);
+                bitreverse_permutation_in_place(a, log_n);
+            }
+        } else {
+            let two_adicity = ark_ff::utils::k_adicity(2, self.size);
+            // The sub-FFTs performed by `best_fft` are over subgroups of the
+            // subgroup of size `n`, so their roots of unity are a strided
+            // subset of `twiddles`.
+            best_fft(
+                a,
+                root,
+                two_adicity,
+                |sub_a: &mut [T], _, sub_two_adicity| {
+                    let stride = n / sub_a.len();
+                    serial_mixed_radix_fft_with_roots(sub_a, twiddles, stride, sub_two_adicity)
+                },
+            );
+        }
+    }
+}
+
+/// Cooley-Tukey butterflies, which map an in-order input to the bit-reversed
+/// DFT when `twiddles` is in bit-reversed order.
+fn ct_butterflies<F: FftField, T: DomainCoeff<F>>(a: &mut [T], twiddles: &[F]) {
+    let n = a.len();
+    let mut num_chunks = 1;
+    while num_chunks < n {
+        let gap = n / (2 * num_chunks);
+        apply_butterflies(a, &twiddles[..num_chunks], gap, |lo, hi, w| {
+            *hi *= *w;
+            let mut neg = *lo;
+            neg -= *hi;
+            *lo += *hi;
+            *hi = neg;
+        });
+        num_chunks *= 2;
+    }
+}
+
+/// Gentleman-Sande butterflies, which map a bit-reversed input to the
+/// (unnormalized) in-order inverse DFT when `twiddles` is in bit-reversed order.
+fn gs_butterflies<F: FftField, T: DomainCoeff<F>>(a: &mut [T], twiddles: &[F]) {
+    let n = a.len();
+    let mut num_chunks = n / 2;
+    while num_chunks > 0 {
+        let gap = n / (2 * num_chunks);
+        apply_butterflies(a, &twiddles[..num_chunks], gap, |lo, hi, w| {
+            let mut neg = *lo;
+            neg -= *hi;
+            *lo += *hi;
+            *hi = neg;
+            *hi *= *w;
+        });
+        num_chunks /= 2;
+    }
+}
+
+/// Applies `butterfly` to all pairs `(a[i], a[i + gap])` in the `k`-th chunk
+/// of size `2 * gap`, using the twiddle factor `twiddles[k]`.
+fn apply_butterflies<F: FftField, T: DomainCoeff<F>>(
+    a: &mut [T],
+    twiddles: &[F],
+    gap: usize,
+    butterfly: impl Fn(&mut T, &mut T, &F) + Sync + Send,
+) {
+    #[cfg(feature = "parallel")]
+    let max_threads = rayon::current_num_threads();
+    #[cfg(not(feature = "parallel"))]
+    let max_threads = 1;
+
+    if twiddles.len() < max_threads && gap > MIN_GAP_SIZE_FOR_PARALLELIZATION {
+        // There are too few chunks to keep all threads busy, so parallelize
+        // within each chunk instead.
+        a.chunks_mut(2 * gap).zip(twiddles).for_each(|(chunk, w)| {
+            let (lo, hi) = chunk.split_at_mut(gap);
+            cfg_iter_mut!(lo)
+                .zip(hi)
+                .for_each(|(lo, hi)| butterfly(lo, hi, w));
+        });
+    } else {
+        cfg_chunks_mut!(a, 2 * gap)
+            .zip(cfg_iter!(twiddles))
+            .for_each(|(chunk, w)| {
+                let (lo, hi) = chunk.split_at_mut(gap);
+                lo.iter_mut()
+                    .zip(hi)
+                    .for_each(|(lo, hi)| butterfly(lo, hi, w));
+            });
+    }
+}
+
+/// The minimum size of a chunk at which parallelization of butterflies within
+/// the chunk is beneficial.
+const MIN_GAP_SIZE_FOR_PARALLELIZATION: usize = 1 << 10;
+
+impl<F: FftField> Valid for FftPlan<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let num_twiddles = if self.size.is_power_of_two() {
+            self.size / 2
+        } else {
+            self.size
+        };
+        if self.size == 0
+            || self.twiddles.len() as u64 != num_twiddles
+            || !self.group_gen.pow([self.size]).is_one()
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        // Recomputing the tables is as expensive as checking them entry by entry.
+        if *self != Self::from_generator(self.size, self.group_gen) {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<F: FftField> CanonicalDeserialize for FftPlan<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_limits(
+            reader,
+            compress,
+            validate,
+            &mut DeserializeLimits::unlimited(),
+        )
+    }
+
+    fn deserialize_with_limits<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        let plan = limits.nested(|limits| {
+            Ok(Self {
+                size: u64::deserialize_with_limits(&mut reader, compress, validate, limits)?,
+                group_gen: F::deserialize_with_limits(&mut reader, compress, validate, limits)?,
+                group_gen_inv: F::deserialize_with_limits(&mut reader, compress, validate, limits)?,
+                twiddles: Vec::deserialize_with_limits(&mut reader, compress, validate, limits)?,
+                inv_twiddles: Vec::deserialize_with_limits(
+                    &mut reader,
+                    compress,
+                    validate,
+                    limits,
+                )?,
+            })
+        })?;
+        if validate == Validate::Yes {
+            plan.check()?;
+        }
+        Ok(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FftPlan;
+    use crate::{
+        EvaluationDomain, GeneralEvaluationDomain, MixedRadixEvaluationDomain,
+        Radix2EvaluationDomain,
+    };
+    use ark_ff::FftField;
+    use ark_serialize::{
+        CanonicalDeserialize, CanonicalSerialize, DeserializeLimits, SerializationError,
+    };
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::{bls12_381::Fr, bn384_small_two_adicity::Fq};
+
+    fn plan_matches_domain<F: FftField, D: EvaluationDomain<F>>(domain: D) {
+        let rng = &mut test_rng();
+        let plan = FftPlan::new(&domain);
+        assert!(plan.is_compatible_with(&domain));
+        let coset = domain.get_coset(F::GENERATOR).unwrap();
+        for domain in [domain, coset] {
+            let coeffs: Vec<F> = (0..domain.size()).map(|_| F::rand(rng)).collect();
+            let evals = domain.fft_with_plan(&plan, &coeffs);
+            assert_eq!(evals, domain.fft(&coeffs));
+            assert_eq!(domain.ifft_with_plan(&plan, &evals), coeffs);
+        }
+    }
+
+    #[test]
+    fn radix2_plan() {
+        for log_n in 0..12 {
+            plan_matches_domain(Radix2EvaluationDomain::<Fr>::new(1 << log_n).unwrap());
+        }
+    }
+
+    #[test]
+    fn mixed_radix_plan() {
+        for n in [1, 2, 3, 6, 9, 12, 27, 48, 100, 300, 1000] {
+            plan_matches_domain(MixedRadixEvaluationDomain::<Fq>::new(n).unwrap());
+        }
+    }
+
+    #[test]
+    fn general_domain_plan() {
+        plan_matches_domain(GeneralEvaluationDomain::<Fr>::new(100).unwrap());
+        plan_matches_domain(GeneralEvaluationDomain::<Fq>::new(100).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn incompatible_plan() {
+        let domain = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+        let plan = FftPlan::new(&Radix2EvaluationDomain::<Fr>::new(8).unwrap());
+        domain.fft_with_plan(&plan, &[Fr::from(1u64)]);
+    }
+
+    #[test]
+    fn serialization() {
+        fn round_trip<F: FftField, D: EvaluationDomain<F>>(domain: D) {
+            let plan = FftPlan::new(&domain);
+            let mut bytes = Vec::new();
+            plan.serialize_compressed(&mut bytes).unwrap();
+            let deserialized = FftPlan::deserialize_compressed(&bytes[..]).unwrap();
+            assert_eq!(plan, deserialized);
+
+            // The twiddle tables count towards the element limit.
+            let elements = plan.twiddles.len() + plan.inv_twiddles.len();
+            let limits = DeserializeLimits::new(elements, bytes.len(), 2);
+            let deserialized =
+                FftPlan::deserialize_compressed_with_limits(&bytes[..], limits).unwrap();
+            assert_eq!(plan, deserialized);
+            let limits = DeserializeLimits::new(elements - 1, bytes.len(), 2);
+            assert!(matches!(
+                FftPlan::<F>::deserialize_compressed_with_limits(&bytes[..], limits),
+                Err(SerializationError::LimitExceeded)
+            ));
+
+            // A plan with corrupted twiddles is rejected.
+            let mut corrupted = plan;
+            corrupted.twiddles[1] = F::GENERATOR;
+            let mut bytes = Vec::new();
+            corrupted.serialize_compressed(&mut bytes).unwrap();
+            assert!(FftPlan::<F>::deserialize_compressed(&bytes[..]).is_err());
+            assert!(FftPlan::<F>::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+        }
+
+        round_trip(Radix2EvaluationDomain::<Fr>::new(64).unwrap());
+        round_trip(MixedRadixEvaluationDomain::<Fq>::new(24).unwrap());
+    }
+}
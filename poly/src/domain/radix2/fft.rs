@@ -2,7 +2,9 @@
 // to the arkworks APIs
 
 use crate::domain::{
-    radix2::{fft, EvaluationDomain, Radix2EvaluationDomain},
+    radix2::{
+        fft, four_step::LOG_FOUR_STEP_FFT_THRESHOLD, EvaluationDomain, Radix2EvaluationDomain,
+    },
     utils::compute_powers_serial,
     DomainCoeff,
 };
@@ -90,6 +92,13 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
     fn fft_helper_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], ord: FFTOrder) {
         let log_len = ark_std::log2(x_s.len());
 
+        // For large in-order transforms, the strided butterflies below are
+        // dominated by cache misses, so use the cache-friendly four-step FFT.
+        if ord == FFTOrder::II && log_len >= LOG_FOUR_STEP_FFT_THRESHOLD {
+            Self::four_step_fft_in_place(x_s, self.group_gen);
+            return;
+        }
+
         if ord == FFTOrder::OI {
             self.oi_helper(x_s, self.group_gen, 1);
         } else {
@@ -107,6 +116,11 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
     fn ifft_helper_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], ord: FFTOrder) {
         let log_len = ark_std::log2(x_s.len());
 
+        if ord == FFTOrder::II && log_len >= LOG_FOUR_STEP_FFT_THRESHOLD {
+            Self::four_step_fft_in_place(x_s, self.group_gen_inv);
+            return;
+        }
+
         if ord == FFTOrder::II {
             derange(x_s, log_len);
         }
@@ -0,0 +1,236 @@
+//! Bailey's four-step FFT for large radix-2 domains.
+//!
+//! An FFT of size `n = n1 * n2` is computed by viewing the input as an
+//! `n2 x n1` matrix and performing
+//! 1. a transpose,
+//! 2. `n1` FFTs of size `n2` on the rows, followed by a multiplication by twiddle factors,
+//! 3. a transpose,
+//! 4. `n2` FFTs of size `n1` on the rows,
+//!
+//! and a final transpose to bring the output in order (this variant is
+//! sometimes called the six-step FFT). Every row FFT operates on a contiguous
+//! block of `O(sqrt(n))` elements that fits in cache, whereas the butterflies
+//! of the standard in-place FFT have strides of up to `n / 2`.
+//!
+//! The matrices are square or twice as wide as they are tall, and are
+//! transposed in place, so that no buffer of size `n` is allocated.
+
+use crate::domain::{
+    radix2::Radix2EvaluationDomain,
+    utils::{bitreverse_permutation_in_place, compute_powers_serial},
+    DomainCoeff,
+};
+use ark_ff::FftField;
+use ark_std::cfg_chunks_mut;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The minimum `log_2` of the domain size at which the four-step FFT is used
+/// instead of the in-place radix-2 FFT. Below this size the whole input fits
+/// comfortably in cache and the extra transposes do not pay off. It is
+/// lowered in tests, so that the four-step FFT is tested through the public
+/// API on small domains.
+pub(crate) const LOG_FOUR_STEP_FFT_THRESHOLD: u32 = if cfg!(test) { 4 } else { 20 };
+
+/// Side length of the square tiles used when transposing.
+const TRANSPOSE_BLOCK_SIZE: usize = 1 << 4;
+
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    /// Computes the in-order FFT of `x_s` with respect to `root`, which must be
+    /// a primitive `x_s.len()`-th root of unity, using the four-step algorithm.
+    /// The coset offset and the size normalization are left to the caller.
+    pub(crate) fn four_step_fft_in_place<T: DomainCoeff<F>>(x_s: &mut [T], root: F) {
+        let n = x_s.len();
+        assert!(n.is_power_of_two());
+        let log_n = ark_std::log2(n);
+        if log_n < 2 {
+            // Both sub-transforms would be trivial, so a single row FFT suffices.
+            let roots = compute_powers_serial(n / 2, root);
+            row_fft(x_s, &roots);
+            return;
+        }
+
+        // `n1 >= n2`, and both are powers of two.
+        let log_n2 = log_n / 2;
+        let log_n1 = log_n - log_n2;
+        let n1 = 1 << log_n1;
+        let n2 = 1 << log_n2;
+
+        let roots_n1 = compute_powers_serial(n1 / 2, root.pow([n2 as u64]));
+        let roots_n2 = compute_powers_serial(n2 / 2, root.pow([n1 as u64]));
+
+        // Step 1: the input is an `n2 x n1` matrix; transpose it so that the
+        // `j1`-th row holds the elements `x_s[j1], x_s[j1 + n1], ...`.
+        transpose_in_place(x_s, n2, n1);
+
+        // Step 2: size-`n2` FFTs of the rows, and multiplication of the
+        // `(j1, k2)`-th entry by `root^(j1 * k2)`.
+        cfg_chunks_mut!(x_s, n2).enumerate().for_each(|(j1, row)| {
+            row_fft(row, &roots_n2);
+            let twiddle = root.pow([j1 as u64]);
+            let mut pow = twiddle;
+            row.iter_mut().skip(1).for_each(|x| {
+                *x *= pow;
+                pow *= twiddle;
+            });
+        });
+
+        // Step 3: transpose back to an `n2 x n1` matrix.
+        transpose_in_place(x_s, n1, n2);
+
+        // Step 4: size-`n1` FFTs of the rows. The `(k2, k1)`-th entry now
+        // holds the `(k2 + n2 * k1)`-th evaluation.
+        cfg_chunks_mut!(x_s, n1).for_each(|row| row_fft(row, &roots_n1));
+
+        // Step 5: transpose to put the evaluations in order.
+        transpose_in_place(x_s, n2, n1);
+    }
+}
+
+/// Computes an in-order FFT of a (small) row, where `roots` holds the first
+/// `row.len() / 2` powers of a primitive `row.len()`-th root of unity.
+fn row_fft<F: FftField, T: DomainCoeff<F>>(row: &mut [T], roots: &[F]) {
+    let n = row.len();
+    let mut gap = n / 2;
+    let mut step = 1;
+    while gap > 0 {
+        row.chunks_mut(2 * gap).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(gap);
+            lo.iter_mut()
+                .zip(hi)
+                .zip(roots.iter().step_by(step))
+                .for_each(|((lo, hi), root)| {
+                    let mut neg = *lo;
+                    neg -= *hi;
+                    *lo += *hi;
+                    *hi = neg;
+                    *hi *= *root;
+                });
+        });
+        gap /= 2;
+        step *= 2;
+    }
+    bitreverse_permutation_in_place(row, ark_std::log2(n));
+}
+
+/// Transposes the `rows x cols` row-major matrix `x_s` in place, where
+/// `rows` and `cols` are equal, or one is twice the other.
+///
+/// A `r x 2r` matrix `[A | B]`, whose rows are the rows of `A` followed by
+/// those of `B`, is transposed into the `2r x r` matrix `[A^T; B^T]` by first
+/// moving its row halves to get `[A; B]`, and then transposing `A` and `B`;
+/// a `2r x r` matrix is transposed by undoing these steps.
+fn transpose_in_place<T: Copy>(x_s: &mut [T], rows: usize, cols: usize) {
+    debug_assert_eq!(x_s.len(), rows * cols);
+    if rows == cols {
+        transpose_square(x_s, rows);
+    } else if cols == 2 * rows {
+        // The `i`-th half row, `s + 2 * k` for the `k`-th row of `A` or `B`,
+        // moves to the `k + rows * s`-th position.
+        permute_blocks(x_s, rows, |i| i / 2 + rows * (i % 2));
+        x_s.chunks_exact_mut(rows * rows)
+            .for_each(|m| transpose_square(m, rows));
+    } else {
+        debug_assert_eq!(rows, 2 * cols);
+        x_s.chunks_exact_mut(cols * cols)
+            .for_each(|m| transpose_square(m, cols));
+        permute_blocks(x_s, cols, |i| 2 * (i % cols) + i / cols);
+    }
+}
+
+/// Transposes the `n x n` row-major matrix `x_s` in place, swapping square
+/// tiles of [`TRANSPOSE_BLOCK_SIZE`] elements across the diagonal.
+fn transpose_square<T>(x_s: &mut [T], n: usize) {
+    let block = ark_std::cmp::min(TRANSPOSE_BLOCK_SIZE, n);
+    for row_start in (0..n).step_by(block) {
+        for col_start in (row_start..n).step_by(block) {
+            for r in row_start..row_start + block {
+                let first_col = if row_start == col_start {
+                    r + 1
+                } else {
+                    col_start
+                };
+                for c in first_col..col_start + block {
+                    x_s.swap(r * n + c, c * n + r);
+                }
+            }
+        }
+    }
+}
+
+/// Moves the `i`-th block of `len` elements of `x_s` to position `target(i)`,
+/// where `target` is a permutation of the blocks, following its cycles with
+/// block swaps.
+fn permute_blocks<T>(x_s: &mut [T], len: usize, target: impl Fn(usize) -> usize) {
+    let num_blocks = x_s.len() / len;
+    for start in 0..num_blocks {
+        // Each cycle is followed from its smallest block.
+        let mut i = target(start);
+        while i > start {
+            i = target(i);
+        }
+        if i < start {
+            continue;
+        }
+        let mut i = target(start);
+        while i != start {
+            let (lo, hi) = x_s.split_at_mut(i.max(start) * len);
+            lo[i.min(start) * len..][..len].swap_with_slice(&mut hi[..len]);
+            i = target(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LOG_FOUR_STEP_FFT_THRESHOLD;
+    use crate::{EvaluationDomain, Radix2EvaluationDomain};
+    use ark_ff::{FftField, UniformRand};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Evaluates `coeffs` over `domain` with the plain radix-2 FFT, which
+    /// never takes the four-step path.
+    fn radix2_fft(domain: &Radix2EvaluationDomain<Fr>, coeffs: &[Fr]) -> Vec<Fr> {
+        let mut evals = coeffs.to_vec();
+        domain.degree_aware_fft_in_place(&mut evals);
+        evals
+    }
+
+    #[test]
+    fn four_step_fft_matches_radix2_fft() {
+        let rng = &mut test_rng();
+        for log_n in 0..12 {
+            let n = 1 << log_n;
+            let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+            let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+
+            let expected = radix2_fft(&domain, &coeffs);
+            let mut actual = coeffs.clone();
+            Radix2EvaluationDomain::four_step_fft_in_place(&mut actual, domain.group_gen);
+            assert_eq!(expected, actual, "log_n = {log_n}");
+
+            // Running the transform with the inverse root and scaling recovers the input.
+            Radix2EvaluationDomain::four_step_fft_in_place(&mut actual, domain.group_gen_inv);
+            for x in &mut actual {
+                *x *= domain.size_inv;
+            }
+            assert_eq!(coeffs, actual, "log_n = {log_n}");
+        }
+    }
+
+    #[test]
+    fn fft_above_threshold_matches_radix2_fft() {
+        let rng = &mut test_rng();
+        for log_n in LOG_FOUR_STEP_FFT_THRESHOLD..12 {
+            let n = 1 << log_n;
+            let domain = Radix2EvaluationDomain::<Fr>::new(n).unwrap();
+            for domain in [domain, domain.get_coset(Fr::GENERATOR).unwrap()] {
+                let coeffs: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+                let evals = radix2_fft(&domain, &coeffs);
+                assert_eq!(domain.fft(&coeffs), evals, "log_n = {log_n}");
+                assert_eq!(domain.ifft(&evals), coeffs, "log_n = {log_n}");
+            }
+        }
+    }
+}
@@ -90,7 +90,7 @@ pub(crate) fn best_fft<T: DomainCoeff<F>, F: FftField>(
     a: &mut [T],
     omega: F,
     log_n: u32,
-    serial_fft: fn(&mut [T], F, u32),
+    serial_fft: impl Fn(&mut [T], F, u32) + Sync,
 ) {
     let num_cpus = rayon::current_num_threads();
     let log_cpus = log2_floor(num_cpus);
@@ -107,7 +107,7 @@ pub(crate) fn best_fft<T: DomainCoeff<F>, F: FftField>(
     a: &mut [T],
     omega: F,
     log_n: u32,
-    serial_fft: fn(&mut [T], F, u32),
+    serial_fft: impl Fn(&mut [T], F, u32) + Sync,
 ) {
     serial_fft(a, omega, log_n)
 }
@@ -118,7 +118,7 @@ pub(crate) fn parallel_fft<T: DomainCoeff<F>, F: FftField>(
     omega: F,
     log_n: u32,
     log_cpus: u32,
-    serial_fft: fn(&mut [T], F, u32),
+    serial_fft: impl Fn(&mut [T], F, u32) + Sync,
 ) {
     assert!(log_n >= log_cpus);
     // For documentation purposes, comments explain things
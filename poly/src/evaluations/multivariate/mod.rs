@@ -1,4 +1,5 @@
 pub mod multilinear;
 pub use multilinear::{
-    DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension,
+    DenseMultilinearExtension, LazyMultilinearExtension, MultilinearExtension,
+    SparseMultilinearExtension,
 };
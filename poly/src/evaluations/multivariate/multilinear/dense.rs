@@ -1,13 +1,17 @@
 //! Multilinear polynomial represented in dense evaluation form.
 
 use crate::{
-    evaluations::multivariate::multilinear::{swap_bits, MultilinearExtension},
-    Polynomial,
+    evaluations::multivariate::multilinear::{
+        eq_evaluations, mobius_transform_in_place, swap_bits, zeta_transform_in_place,
+        DefaultHasher, MultilinearExtension,
+    },
+    univariate::DensePolynomial,
+    DenseUVPolynomial, EvaluationDomain, Evaluations, Polynomial,
 };
-use ark_ff::{Field, Zero};
+use ark_ff::{FftField, Field, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{
-    cfg_iter,
+    cfg_chunks, cfg_iter, cfg_iter_mut,
     fmt::{self, Formatter},
     iter::IntoIterator,
     log2,
@@ -17,6 +21,7 @@ use ark_std::{
     vec,
     vec::*,
 };
+use hashbrown::HashMap;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
@@ -152,6 +157,243 @@ impl<F: Field> DenseMultilinearExtension<F> {
 
         Self::from_evaluations_slice(num_vars as usize, &evaluations)
     }
+
+    /// Returns the multilinear extension of `eq(x, point)`, which is `1` at
+    /// `x = point` and `0` elsewhere on the hypercube when `point` is Boolean.
+    /// See [`eq_evaluations`].
+    pub fn eq(point: &[F]) -> Self {
+        Self::from_evaluations_vec(point.len(), eq_evaluations(point))
+    }
+
+    /// Fixes the first `partial_point.len()` variables of `self` to the
+    /// values in `partial_point` (from left to right) in place. This is the
+    /// in-place version of [`MultilinearExtension::fix_variables`].
+    pub fn fix_variables_in_place(&mut self, partial_point: &[F]) {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        for r in partial_point {
+            // The variable being fixed is the lowest bit of the index.
+            #[cfg(feature = "parallel")]
+            {
+                self.evaluations = fix_first_variable(&self.evaluations, *r);
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                let half = self.evaluations.len() / 2;
+                for b in 0..half {
+                    let left = self.evaluations[b << 1];
+                    let right = self.evaluations[(b << 1) + 1];
+                    self.evaluations[b] = left + *r * (right - left);
+                }
+                self.evaluations.truncate(half);
+            }
+        }
+        self.num_vars -= partial_point.len();
+    }
+
+    /// Returns the MLE resulting from fixing the last `partial_point.len()`
+    /// variables of `self` to the values in `partial_point`, i.e. for a point
+    /// `(x, y)` with `y.len() == partial_point.len()`,
+    /// `self.fix_high_variables(y).evaluate(x) == self.evaluate((x, y))`.
+    pub fn fix_high_variables(&self, partial_point: &[F]) -> Self {
+        let mut poly = self.clone();
+        poly.fix_high_variables_in_place(partial_point);
+        poly
+    }
+
+    /// Fixes the last `partial_point.len()` variables of `self` to the values
+    /// in `partial_point` in place. See [`Self::fix_high_variables`].
+    pub fn fix_high_variables_in_place(&mut self, partial_point: &[F]) {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        for r in partial_point.iter().rev() {
+            // The variable being fixed is the highest bit of the index, so
+            // both halves of the table are contiguous.
+            let half = self.evaluations.len() / 2;
+            let (lo, hi) = self.evaluations.split_at_mut(half);
+            cfg_iter_mut!(lo)
+                .zip(hi)
+                .for_each(|(lo, hi)| *lo += *r * (*hi - *lo));
+            self.evaluations.truncate(half);
+        }
+        self.num_vars -= partial_point.len();
+    }
+
+    /// Evaluates `self` at each of `points`. Points that share a prefix share
+    /// the work of fixing the variables of that prefix, so that e.g. the
+    /// evaluations at `m` points which only differ in their last coordinate
+    /// cost about as much as a single evaluation for small `m`.
+    pub fn evaluate_many(&self, points: &[Vec<F>]) -> Vec<F> {
+        for point in points {
+            assert_eq!(point.len(), self.num_vars, "invalid size of point");
+        }
+        let mut result = vec![F::zero(); points.len()];
+        let indices: Vec<usize> = (0..points.len()).collect();
+        evaluate_by_prefix(&self.evaluations, 0, points, &indices, &mut result);
+        result
+    }
+
+    /// Returns the inner product `sum_x self(x) * other(x)` over the
+    /// hypercube. In particular, if `other` is `Self::eq(point)`, this is the
+    /// evaluation of `self` at `point`.
+    pub fn inner_product(&self, other: &Self) -> F {
+        assert_eq!(self.num_vars, other.num_vars);
+        cfg_iter!(self.evaluations)
+            .zip(&other.evaluations)
+            .map(|(a, b)| *a * b)
+            .sum()
+    }
+
+    /// Evaluates each of `polys` at `point`, computing the table of
+    /// `eq(x, point)` only once.
+    pub fn batch_evaluate(polys: &[impl AsRef<Self> + Sync], point: &[F]) -> Vec<F> {
+        let eq = Self::eq(point);
+        cfg_iter!(polys)
+            .map(|poly| poly.as_ref().inner_product(&eq))
+            .collect()
+    }
+}
+
+/// Returns the table of evaluations with the first variable fixed to `r`.
+fn fix_first_variable<F: Field>(evals: &[F], r: F) -> Vec<F> {
+    cfg_chunks!(evals, 2)
+        .map(|pair| pair[0] + r * (pair[1] - pair[0]))
+        .collect()
+}
+
+/// Writes into `result` the evaluations at the points with the given
+/// `indices`, which all share their first `depth` coordinates, given the
+/// evaluations `evals` of the polynomial with those coordinates fixed.
+fn evaluate_by_prefix<F: Field>(
+    evals: &[F],
+    depth: usize,
+    points: &[Vec<F>],
+    indices: &[usize],
+    result: &mut [F],
+) {
+    if evals.len() == 1 {
+        for &i in indices {
+            result[i] = evals[0];
+        }
+        return;
+    }
+    let mut groups: HashMap<F, Vec<usize>, core::hash::BuildHasherDefault<DefaultHasher>> =
+        HashMap::with_hasher(core::hash::BuildHasherDefault::<DefaultHasher>::default());
+    for &i in indices {
+        groups.entry(points[i][depth]).or_default().push(i);
+    }
+    for (r, indices) in groups {
+        let evals = fix_first_variable(evals, r);
+        evaluate_by_prefix(&evals, depth + 1, points, &indices, result);
+    }
+}
+
+impl<F: Field> DenseMultilinearExtension<F> {
+    /// Returns the coefficients of `self` in the multilinear monomial basis,
+    /// where the `S`-th coefficient, for `S` the set of bits of the index, is
+    /// the coefficient of `prod_{i in S} x_i`. See [`mobius_transform_in_place`].
+    pub fn to_monomial_coefficients(&self) -> Vec<F> {
+        let mut coeffs = self.evaluations.clone();
+        mobius_transform_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Constructs the multilinear polynomial `sum_S coeffs[S] prod_{i in S} x_i`,
+    /// where `S` is the set of bits of the index. See [`zeta_transform_in_place`].
+    pub fn from_monomial_coefficients(num_vars: usize, mut coeffs: Vec<F>) -> Self {
+        assert_eq!(
+            coeffs.len(),
+            1 << num_vars,
+            "The size of coefficients should be 2^num_vars."
+        );
+        zeta_transform_in_place(&mut coeffs);
+        Self::from_evaluations_vec(num_vars, coeffs)
+    }
+
+    /// Interprets the evaluations of `self` as the coefficients of the
+    /// univariate polynomial `sum_i self.evaluations[i] * X^i`, without copying.
+    /// This is the reinterpretation used by multilinear-to-univariate
+    /// compilers such as Gemini.
+    pub fn into_univariate(self) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec(self.evaluations)
+    }
+
+    /// Constructs the multilinear polynomial whose evaluation table consists
+    /// of the coefficients of `poly`, padded with zeros to the next power of
+    /// two. The inverse of [`Self::into_univariate`].
+    pub fn from_univariate(poly: DensePolynomial<F>) -> Self {
+        let mut evaluations = poly.coeffs;
+        let len = evaluations.len().max(1).next_power_of_two();
+        evaluations.resize(len, F::zero());
+        Self::from_evaluations_vec(log2(len) as usize, evaluations)
+    }
+
+    /// Evaluates the univariate polynomial `sum_i self.evaluations[i] * X^i`
+    /// at `point`. See [`Self::into_univariate`].
+    pub fn evaluate_as_univariate(&self, point: F) -> F {
+        self.evaluations
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, e| acc * point + e)
+    }
+
+    /// Computes the quotients `q_i` such that
+    /// `f(X) - f(point) = sum_i (X_i - point_i) * q_i(X_{i + 1}, ..., X_{n - 1})`,
+    /// and returns them together with `f(point)`. The `i`-th quotient has
+    /// `n - i - 1` variables.
+    pub fn quotients(&self, point: &[F]) -> (Vec<Self>, F) {
+        assert_eq!(point.len(), self.num_vars, "invalid size of point");
+        let mut remainder = self.evaluations.clone();
+        let mut quotients = Vec::with_capacity(self.num_vars);
+        for (i, r) in point.iter().enumerate() {
+            // With `f_i(X_i, ...)` the remainder after fixing the first `i`
+            // variables, `q_i = f_i(1, ...) - f_i(0, ...)` and
+            // `f_{i + 1} = f_i(0, ...) + r * q_i`.
+            let quotient: Vec<F> = cfg_chunks!(remainder, 2)
+                .map(|pair| pair[1] - pair[0])
+                .collect();
+            remainder = cfg_chunks!(remainder, 2)
+                .zip(&quotient)
+                .map(|(pair, q)| pair[0] + *r * q)
+                .collect();
+            quotients.push(Self::from_evaluations_vec(self.num_vars - i - 1, quotient));
+        }
+        (quotients, remainder[0])
+    }
+}
+
+impl<F: FftField> DenseMultilinearExtension<F> {
+    /// Interprets the evaluations of `self` as the evaluations of a univariate
+    /// polynomial over `domain`, without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain` does not have size `2^num_vars`.
+    pub fn into_evaluations<D: EvaluationDomain<F>>(self, domain: D) -> Evaluations<F, D> {
+        assert_eq!(domain.size(), self.evaluations.len(), "invalid domain size");
+        Evaluations::from_vec_and_domain(self.evaluations, domain)
+    }
+
+    /// Constructs the multilinear polynomial whose evaluation table consists
+    /// of `evals`. The inverse of [`Self::into_evaluations`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the domain of `evals` is not a power of two.
+    pub fn from_univariate_evaluations<D: EvaluationDomain<F>>(evals: Evaluations<F, D>) -> Self {
+        let num_vars = log2(evals.evals.len()) as usize;
+        Self::from_evaluations_vec(num_vars, evals.evals)
+    }
+}
+
+impl<F: Field> From<DenseMultilinearExtension<F>> for DensePolynomial<F> {
+    fn from(mle: DenseMultilinearExtension<F>) -> Self {
+        mle.into_univariate()
+    }
 }
 
 impl<'a, F: Field> IntoIterator for &'a DenseMultilinearExtension<F> {
@@ -220,23 +462,9 @@ impl<F: Field> MultilinearExtension<F> for DenseMultilinearExtension<F> {
     /// ```
     /// }
     fn fix_variables(&self, partial_point: &[F]) -> Self {
-        assert!(
-            partial_point.len() <= self.num_vars,
-            "invalid size of partial point"
-        );
-        let mut poly = self.evaluations.clone();
-        let nv = self.num_vars;
-        let dim = partial_point.len();
-        // evaluate single variable of partial point from left to right
-        for i in 1..dim + 1 {
-            let r = partial_point[i - 1];
-            for b in 0..(1 << (nv - i)) {
-                let left = poly[b << 1];
-                let right = poly[(b << 1) + 1];
-                poly[b] = left + r * (right - left);
-            }
-        }
-        Self::from_evaluations_slice(nv - dim, &poly[..(1 << (nv - dim))])
+        let mut poly = self.clone();
+        poly.fix_variables_in_place(partial_point);
+        poly
     }
 
     fn to_evaluations(&self) -> Vec<F> {
@@ -448,7 +676,12 @@ impl<F: Field> Polynomial<F> for DenseMultilinearExtension<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DenseMultilinearExtension, MultilinearExtension, Polynomial};
+    use crate::{
+        evaluations::multivariate::multilinear::{eq_eval, eq_evaluations},
+        univariate::DensePolynomial,
+        DenseMultilinearExtension, DenseUVPolynomial, EvaluationDomain, MultilinearExtension,
+        Polynomial, Radix2EvaluationDomain,
+    };
     use ark_ff::{Field, One, Zero};
     use ark_std::{ops::Neg, test_rng, vec::*, UniformRand};
     use ark_test_curves::bls12_381::Fr;
@@ -654,4 +887,168 @@ mod tests {
             assert_eq!(expected, merged.evaluate(&point));
         }
     }
+
+    #[test]
+    fn eq_table() {
+        let mut rng = test_rng();
+        for nv in 0..8 {
+            let r: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let x: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let eq = DenseMultilinearExtension::eq(&r);
+            assert_eq!(eq.evaluate(&x), eq_eval(&x, &r));
+            assert_eq!(eq.evaluations, eq_evaluations(&r));
+            for (i, e) in eq.iter().enumerate() {
+                let bits: Vec<_> = (0..nv).map(|j| Fr::from(((i >> j) & 1) as u64)).collect();
+                assert_eq!(*e, eq_eval(&bits, &r));
+            }
+
+            // On the hypercube, `eq` is the indicator function of `r`.
+            let bits: Vec<_> = (0..nv).map(|j| Fr::from((j % 2) as u64)).collect();
+            let eq = DenseMultilinearExtension::eq(&bits);
+            let index = (0..nv)
+                .filter(|j| j % 2 == 1)
+                .map(|j| 1 << j)
+                .sum::<usize>();
+            for (i, e) in eq.iter().enumerate() {
+                assert_eq!(e.is_one(), i == index);
+                assert_eq!(e.is_zero(), i != index);
+            }
+        }
+    }
+
+    #[test]
+    fn fix_variables_in_place() {
+        let mut rng = test_rng();
+        let poly = DenseMultilinearExtension::rand(10, &mut rng);
+        let point: Vec<_> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+        let expected = poly.evaluate(&point);
+        for k in 0..=10 {
+            let mut low = poly.clone();
+            low.fix_variables_in_place(&point[..k]);
+            assert_eq!(low, poly.fix_variables(&point[..k]));
+            assert_eq!(low.num_vars(), 10 - k);
+            assert_eq!(low.evaluate(&point[k..].to_vec()), expected);
+
+            let high = poly.fix_high_variables(&point[k..]);
+            assert_eq!(high.num_vars(), k);
+            assert_eq!(high.evaluate(&point[..k].to_vec()), expected);
+        }
+    }
+
+    #[test]
+    fn evaluate_many() {
+        let mut rng = test_rng();
+        let poly = DenseMultilinearExtension::rand(8, &mut rng);
+        let prefix: Vec<_> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+        let mut points: Vec<Vec<Fr>> = (0..10)
+            .map(|i| {
+                // Half of the points share a prefix of length 5.
+                let shared = if i % 2 == 0 { 5 } else { 0 };
+                let mut point = prefix[..shared].to_vec();
+                point.extend((shared..8).map(|_| Fr::rand(&mut rng)));
+                point
+            })
+            .collect();
+        points.push(points[0].clone());
+        let expected: Vec<_> = points.iter().map(|p| poly.evaluate(p)).collect();
+        assert_eq!(poly.evaluate_many(&points), expected);
+        assert!(poly.evaluate_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn inner_product() {
+        let mut rng = test_rng();
+        let polys: Vec<_> = (0..4)
+            .map(|_| DenseMultilinearExtension::<Fr>::rand(7, &mut rng))
+            .collect();
+        let point: Vec<_> = (0..7).map(|_| Fr::rand(&mut rng)).collect();
+        let eq = DenseMultilinearExtension::eq(&point);
+        for poly in &polys {
+            assert_eq!(poly.inner_product(&eq), poly.evaluate(&point));
+        }
+        let expected: Fr = polys[0]
+            .iter()
+            .zip(polys[1].iter())
+            .map(|(a, b)| *a * b)
+            .sum();
+        assert_eq!(polys[0].inner_product(&polys[1]), expected);
+        let evals: Vec<_> = polys.iter().map(|p| p.evaluate(&point)).collect();
+        assert_eq!(
+            DenseMultilinearExtension::batch_evaluate(&polys, &point),
+            evals
+        );
+    }
+
+    #[test]
+    fn monomial_coefficients() {
+        let mut rng = test_rng();
+        for nv in 0..8 {
+            let poly = DenseMultilinearExtension::<Fr>::rand(nv, &mut rng);
+            let coeffs = poly.to_monomial_coefficients();
+            let point: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let expected: Fr = coeffs
+                .iter()
+                .enumerate()
+                .map(|(s, c)| {
+                    (0..nv)
+                        .filter(|i| (s >> i) & 1 == 1)
+                        .fold(*c, |acc, i| acc * point[i])
+                })
+                .sum();
+            assert_eq!(poly.evaluate(&point), expected);
+            assert_eq!(
+                DenseMultilinearExtension::from_monomial_coefficients(nv, coeffs),
+                poly
+            );
+        }
+    }
+
+    #[test]
+    fn univariate_views() {
+        let mut rng = test_rng();
+        let poly = DenseMultilinearExtension::<Fr>::rand(6, &mut rng);
+        let x = Fr::rand(&mut rng);
+        let univariate: DensePolynomial<Fr> = poly.clone().into();
+        assert_eq!(univariate.coeffs, poly.evaluations);
+        assert_eq!(univariate.evaluate(&x), poly.evaluate_as_univariate(x));
+        assert_eq!(DenseMultilinearExtension::from_univariate(univariate), poly);
+
+        // Coefficient vectors are padded to a power of two.
+        let univariate = DensePolynomial::<Fr>::rand(4, &mut rng);
+        let padded = DenseMultilinearExtension::from_univariate(univariate.clone());
+        assert_eq!(padded.num_vars, 3);
+        assert_eq!(padded.evaluate_as_univariate(x), univariate.evaluate(&x));
+
+        let domain = Radix2EvaluationDomain::new(1 << 6).unwrap();
+        let evals = poly.clone().into_evaluations(domain);
+        assert_eq!(evals.evals, poly.evaluations);
+        assert_eq!(
+            DenseMultilinearExtension::from_univariate_evaluations(evals),
+            poly
+        );
+    }
+
+    #[test]
+    fn quotients() {
+        let mut rng = test_rng();
+        for nv in 0..8 {
+            let poly = DenseMultilinearExtension::<Fr>::rand(nv, &mut rng);
+            let point: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let (quotients, eval) = poly.quotients(&point);
+            assert_eq!(eval, poly.evaluate(&point));
+            assert_eq!(quotients.len(), nv);
+
+            // f(x) - f(r) = sum_i (x_i - r_i) * q_i(x_{i + 1}, ...)
+            let x: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let sum: Fr = quotients
+                .iter()
+                .enumerate()
+                .map(|(i, q)| {
+                    assert_eq!(q.num_vars, nv - i - 1);
+                    (x[i] - point[i]) * q.evaluate(&x[i + 1..].to_vec())
+                })
+                .sum();
+            assert_eq!(poly.evaluate(&x) - eval, sum);
+        }
+    }
 }
@@ -0,0 +1,751 @@
+//! Multilinear extensions whose evaluations are computed on demand.
+use crate::{
+    evaluations::multivariate::multilinear::{eq_evaluations, swap_bits},
+    DenseMultilinearExtension, MultilinearExtension, Polynomial,
+};
+use ark_ff::{Field, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, DeserializeLimits, Read,
+    SerializationError, Valid, Validate, Write,
+};
+use ark_std::{
+    cfg_into_iter, cfg_iter,
+    fmt::{self, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Index, Neg, Sub, SubAssign},
+    rand::Rng,
+    sync::Arc,
+    vec,
+    vec::*,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Indexing a lazy polynomial computes and caches its evaluations in chunks of
+/// `2^INDEX_CHUNK_LOG_SIZE`.
+#[cfg(feature = "std")]
+const INDEX_CHUNK_LOG_SIZE: usize = 10;
+
+/// The chunks of evaluations computed by [`Index`], each initialized on first
+/// use. Caching requires thread-safe lazy initialization, which is only
+/// available with the `std` feature.
+#[cfg(feature = "std")]
+type IndexCache<F> = std::sync::OnceLock<Vec<std::sync::OnceLock<Vec<F>>>>;
+#[cfg(not(feature = "std"))]
+type IndexCache<F> = ark_std::marker::PhantomData<F>;
+
+/// The source of the evaluations of a [`LazyMultilinearExtension`].
+#[derive(Clone)]
+enum Evaluations<F> {
+    /// The evaluations are stored in memory.
+    Table(Vec<F>),
+    /// The evaluations are computed by a callback from their index.
+    Oracle(Arc<dyn Fn(usize) -> F + Send + Sync>),
+}
+
+impl<F: Field> Evaluations<F> {
+    fn get(&self, index: usize) -> F {
+        match self {
+            Self::Table(evaluations) => evaluations[index],
+            Self::Oracle(f) => f(index),
+        }
+    }
+}
+
+/// Stores a multilinear polynomial whose evaluations over {0,1}^`num_vars` are
+/// computed on demand, either by a callback or by deserializing them from a
+/// byte buffer such as a memory-mapped file.
+///
+/// Operations that reduce the size of the polynomial, such as
+/// [`fix_variables`](MultilinearExtension::fix_variables), only materialize
+/// their result, so that a polynomial too large to fit in memory can be
+/// folded into one that does. Arithmetic between lazy polynomials stays lazy.
+///
+/// Since [`Index`] has to return a reference, indexing a lazy polynomial
+/// computes and caches the chunk of `2^10` evaluations containing the index,
+/// and keeps one (initially empty) cache slot per chunk: use
+/// [`Self::evaluation`] instead to compute a single evaluation without
+/// caching it. Without the `std` feature, indexing a polynomial whose
+/// evaluations are not stored in memory panics.
+#[derive(Clone)]
+pub struct LazyMultilinearExtension<F: Field> {
+    num_vars: usize,
+    evaluations: Evaluations<F>,
+    /// The chunks of evaluations of an `Oracle` computed by `index`.
+    cache: IndexCache<F>,
+}
+
+impl<F: Field> LazyMultilinearExtension<F> {
+    /// Constructs a polynomial whose evaluation at the point represented by
+    /// `index` is `f(index)`, for `index < 2^num_vars`.
+    ///
+    /// ```
+    /// use ark_test_curves::bls12_381::Fr;
+    /// # use ark_poly::{LazyMultilinearExtension, Polynomial};
+    ///
+    /// // The polynomial x_0 + 2 * x_1 evaluates to `index` on the hypercube.
+    /// let mle = LazyMultilinearExtension::from_fn(2, |i| Fr::from(i as u64));
+    /// assert_eq!(mle.evaluate(&vec![Fr::from(3), Fr::from(5)]), Fr::from(13));
+    /// ```
+    pub fn from_fn(num_vars: usize, f: impl Fn(usize) -> F + Send + Sync + 'static) -> Self {
+        Self {
+            num_vars,
+            evaluations: Evaluations::Oracle(Arc::new(f)),
+            cache: IndexCache::default(),
+        }
+    }
+
+    /// Constructs a polynomial from `bytes`, which hold its `2^num_vars`
+    /// evaluations serialized one after the other in little-endian order with
+    /// [`CanonicalSerialize::serialize_uncompressed`].
+    ///
+    /// `bytes` can be any buffer, e.g. a memory-mapped file: evaluations are
+    /// only deserialized when they are needed. Note that a serialized
+    /// `Vec<F>` starts with an 8-byte length prefix, which must be skipped.
+    ///
+    /// Returns an error if `bytes` does not have the expected length, or if
+    /// it does not hold valid serialized field elements. Checking this reads
+    /// all of `bytes` once, without storing the evaluations.
+    ///
+    /// ```
+    /// use ark_test_curves::bls12_381::Fr;
+    /// # use ark_poly::{LazyMultilinearExtension, MultilinearExtension};
+    /// # use ark_serialize::CanonicalSerialize;
+    ///
+    /// let mut bytes = Vec::new();
+    /// for x in [1u64, 2, 3, 4] {
+    ///     Fr::from(x).serialize_uncompressed(&mut bytes).unwrap();
+    /// }
+    /// let mle = LazyMultilinearExtension::<Fr>::from_bytes(2, bytes).unwrap();
+    /// assert_eq!(mle.evaluation(2), Fr::from(3));
+    /// ```
+    pub fn from_bytes<B>(num_vars: usize, bytes: B) -> Result<Self, SerializationError>
+    where
+        B: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        let size = F::zero().uncompressed_size();
+        if bytes.as_ref().len() != size << num_vars {
+            return Err(SerializationError::InvalidData);
+        }
+        cfg_into_iter!(0..1usize << num_vars).try_for_each(|i| {
+            F::deserialize_uncompressed_unchecked(&bytes.as_ref()[i * size..(i + 1) * size])
+                .map(|_| ())
+        })?;
+        Ok(Self::from_fn(num_vars, move |i| {
+            F::deserialize_uncompressed_unchecked(&bytes.as_ref()[i * size..(i + 1) * size])
+                .expect("the evaluations were checked on construction")
+        }))
+    }
+
+    /// Constructs a polynomial from the list of its evaluations, where the
+    /// index represents a point in {0,1}^`num_vars` in little endian form.
+    pub fn from_evaluations_vec(num_vars: usize, evaluations: Vec<F>) -> Self {
+        // assert that the number of variables matches the size of evaluations
+        assert_eq!(
+            evaluations.len(),
+            1 << num_vars,
+            "The size of evaluations should be 2^num_vars."
+        );
+        Self {
+            num_vars,
+            evaluations: Evaluations::Table(evaluations),
+            cache: IndexCache::default(),
+        }
+    }
+
+    /// Returns the evaluation of the polynomial at the point represented by
+    /// `index`, in little endian form.
+    pub fn evaluation(&self, index: usize) -> F {
+        assert!(index < 1 << self.num_vars, "index out of range");
+        self.evaluations.get(index)
+    }
+
+    /// Returns whether the evaluations of `self` are stored in memory.
+    pub const fn is_materialized(&self) -> bool {
+        matches!(self.evaluations, Evaluations::Table(_))
+    }
+
+    /// Returns the polynomial whose evaluations are those of `self` mapped
+    /// through `f`.
+    fn map(&self, f: impl Fn(F) -> F + Send + Sync + 'static) -> Self {
+        match &self.evaluations {
+            Evaluations::Table(evaluations) => Self::from_evaluations_vec(
+                self.num_vars,
+                cfg_iter!(evaluations).map(|x| f(*x)).collect(),
+            ),
+            Evaluations::Oracle(g) => {
+                let g = g.clone();
+                Self::from_fn(self.num_vars, move |i| f(g(i)))
+            },
+        }
+    }
+}
+
+impl<F: Field> From<DenseMultilinearExtension<F>> for LazyMultilinearExtension<F> {
+    fn from(other: DenseMultilinearExtension<F>) -> Self {
+        Self::from_evaluations_vec(other.num_vars, other.evaluations)
+    }
+}
+
+impl<F: Field> From<LazyMultilinearExtension<F>> for DenseMultilinearExtension<F> {
+    /// Materializes the evaluations of `other`.
+    fn from(other: LazyMultilinearExtension<F>) -> Self {
+        let evaluations = match other.evaluations {
+            Evaluations::Table(evaluations) => evaluations,
+            Evaluations::Oracle(_) => other.to_evaluations(),
+        };
+        Self::from_evaluations_vec(other.num_vars, evaluations)
+    }
+}
+
+impl<F: Field> MultilinearExtension<F> for LazyMultilinearExtension<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self {
+        Self::from_evaluations_vec(num_vars, (0..1 << num_vars).map(|_| F::rand(rng)).collect())
+    }
+
+    /// Relabels the variables of `self`. Lazy polynomials stay lazy.
+    fn relabel(&self, mut a: usize, mut b: usize, k: usize) -> Self {
+        // enforce order of a and b
+        if a > b {
+            ark_std::mem::swap(&mut a, &mut b);
+        }
+        if a == b || k == 0 {
+            return self.clone();
+        }
+        assert!(b + k <= self.num_vars, "invalid relabel argument");
+        assert!(a + k <= b, "overlapped swap window is not allowed");
+        match &self.evaluations {
+            Evaluations::Table(evaluations) => Self::from_evaluations_vec(
+                self.num_vars,
+                cfg_into_iter!(0..evaluations.len())
+                    .map(|i| evaluations[swap_bits(i, a, b, k)])
+                    .collect(),
+            ),
+            Evaluations::Oracle(f) => {
+                let f = f.clone();
+                Self::from_fn(self.num_vars, move |i| f(swap_bits(i, a, b, k)))
+            },
+        }
+    }
+
+    /// Binds the first `partial_point.len()` variables of `self` to
+    /// `partial_point`, computing each evaluation of the result from the
+    /// evaluations of `self` it depends on.
+    ///
+    /// Only the result and two tables of size about `2^{partial_point.len() / 2}`
+    /// are held in memory.
+    fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "invalid size of partial point"
+        );
+        if partial_point.is_empty() {
+            return self.clone();
+        }
+        let k = partial_point.len();
+        // eq(i, r) = eq(i_lo, r_lo) * eq(i_hi, r_hi), where `lo` and `hi` are
+        // the first and last halves of the bound variables.
+        let (lo_point, hi_point) = partial_point.split_at(k / 2);
+        let (lo, hi) = (eq_evaluations(lo_point), eq_evaluations(hi_point));
+        let evaluations = &self.evaluations;
+        let result = cfg_into_iter!(0..1usize << (self.num_vars - k))
+            .map(|j| {
+                cfg_iter!(hi)
+                    .enumerate()
+                    .map(|(h, eq_hi)| {
+                        let offset = (j << k) | (h << lo_point.len());
+                        let sum: F = lo
+                            .iter()
+                            .enumerate()
+                            .map(|(l, eq_lo)| *eq_lo * evaluations.get(offset | l))
+                            .sum();
+                        sum * eq_hi
+                    })
+                    .sum()
+            })
+            .collect();
+        Self::from_evaluations_vec(self.num_vars - k, result)
+    }
+
+    fn to_evaluations(&self) -> Vec<F> {
+        match &self.evaluations {
+            Evaluations::Table(evaluations) => evaluations.clone(),
+            Evaluations::Oracle(f) => cfg_into_iter!(0..1usize << self.num_vars)
+                .map(|i| f(i))
+                .collect(),
+        }
+    }
+}
+
+impl<F: Field> Index<usize> for LazyMultilinearExtension<F> {
+    type Output = F;
+
+    /// Returns the evaluation of the polynomial at a point represented by index.
+    ///
+    /// Index represents a vector in {0,1}^`num_vars` in little endian form. For
+    /// example, `0b1011` represents `P(1,1,0,1)`
+    ///
+    /// For a lazy polynomial, the first call to `index` for an index in a
+    /// chunk of `2^10` evaluations computes and caches that chunk.
+    ///
+    /// # Panics
+    ///
+    /// Without the `std` feature, panics if the evaluations of `self` are not
+    /// stored in memory.
+    fn index(&self, index: usize) -> &Self::Output {
+        match &self.evaluations {
+            Evaluations::Table(evaluations) => &evaluations[index],
+            #[cfg(not(feature = "std"))]
+            Evaluations::Oracle(_) => {
+                panic!("indexing a lazy polynomial requires the `std` feature, use `evaluation`")
+            },
+            #[cfg(feature = "std")]
+            Evaluations::Oracle(f) => {
+                assert!(index < 1 << self.num_vars, "index out of range");
+                let chunk_log_size = INDEX_CHUNK_LOG_SIZE.min(self.num_vars);
+                let chunks = self.cache.get_or_init(|| {
+                    (0..1usize << (self.num_vars - chunk_log_size))
+                        .map(|_| std::sync::OnceLock::new())
+                        .collect()
+                });
+                let chunk = index >> chunk_log_size;
+                let start = chunk << chunk_log_size;
+                let evaluations = chunks[chunk].get_or_init(|| {
+                    cfg_into_iter!(start..start + (1 << chunk_log_size))
+                        .map(|i| f(i))
+                        .collect()
+                });
+                &evaluations[index - start]
+            },
+        }
+    }
+}
+
+impl<F: Field> Polynomial<F> for LazyMultilinearExtension<F> {
+    type Point = Vec<F>;
+
+    fn degree(&self) -> usize {
+        self.num_vars
+    }
+
+    fn evaluate(&self, point: &Self::Point) -> F {
+        assert!(point.len() == self.num_vars);
+        self.fix_variables(point).evaluation(0)
+    }
+}
+
+impl<F: Field> PartialEq for LazyMultilinearExtension<F> {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (&self.evaluations, &other.evaluations);
+        self.num_vars == other.num_vars
+            && cfg_into_iter!(0..1usize << self.num_vars).all(|i| a.get(i) == b.get(i))
+    }
+}
+
+impl<F: Field> Eq for LazyMultilinearExtension<F> {}
+
+impl<F: Field> Hash for LazyMultilinearExtension<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num_vars.hash(state);
+        for i in 0..1 << self.num_vars {
+            self.evaluations.get(i).hash(state);
+        }
+    }
+}
+
+impl<F: Field> fmt::Debug for LazyMultilinearExtension<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "LazyML(nv = {}, evaluations = [", self.num_vars)?;
+        for i in 0..ark_std::cmp::min(4, 1 << self.num_vars) {
+            write!(f, "{:?} ", self.evaluations.get(i))?;
+        }
+        if self.num_vars < 2 {
+            write!(f, "])")?;
+        } else {
+            write!(f, "...])")?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> CanonicalSerialize for LazyMultilinearExtension<F> {
+    /// Serializes `self` in the same format as a [`DenseMultilinearExtension`],
+    /// computing one evaluation at a time.
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        (1u64 << self.num_vars).serialize_with_mode(&mut writer, compress)?;
+        for i in 0..1 << self.num_vars {
+            self.evaluations
+                .get(i)
+                .serialize_with_mode(&mut writer, compress)?;
+        }
+        self.num_vars.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        0u64.serialized_size(compress)
+            + (F::zero().serialized_size(compress) << self.num_vars)
+            + self.num_vars.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for LazyMultilinearExtension<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        match &self.evaluations {
+            Evaluations::Table(evaluations) => evaluations.check(),
+            Evaluations::Oracle(_) => Ok(()),
+        }
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for LazyMultilinearExtension<F> {
+    /// Deserializes a [`DenseMultilinearExtension`], whose evaluations are
+    /// stored in memory.
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        DenseMultilinearExtension::deserialize_with_mode(reader, compress, validate).map(Self::from)
+    }
+
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        DenseMultilinearExtension::deserialize_with_limits(reader, compress, validate, limits)
+            .map(Self::from)
+    }
+}
+
+impl<F: Field> Zero for LazyMultilinearExtension<F> {
+    fn zero() -> Self {
+        Self::from_evaluations_vec(0, vec![F::zero()])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num_vars == 0 && self.evaluations.get(0).is_zero()
+    }
+}
+
+impl<F: Field> Add for LazyMultilinearExtension<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<'a, F: Field> Add<&'a LazyMultilinearExtension<F>> for &LazyMultilinearExtension<F> {
+    type Output = LazyMultilinearExtension<F>;
+
+    /// Adds `rhs` to `self`. The sum is lazy unless both polynomials are
+    /// stored in memory.
+    fn add(self, rhs: &'a LazyMultilinearExtension<F>) -> Self::Output {
+        // handle constant zero case
+        if rhs.is_zero() {
+            return self.clone();
+        }
+        if self.is_zero() {
+            return rhs.clone();
+        }
+        assert_eq!(self.num_vars, rhs.num_vars);
+        match (&self.evaluations, &rhs.evaluations) {
+            (Evaluations::Table(a), Evaluations::Table(b)) => Self::Output::from_evaluations_vec(
+                self.num_vars,
+                cfg_iter!(a).zip(b).map(|(a, b)| *a + b).collect(),
+            ),
+            (a, b) => {
+                let (a, b) = (a.clone(), b.clone());
+                Self::Output::from_fn(self.num_vars, move |i| a.get(i) + b.get(i))
+            },
+        }
+    }
+}
+
+impl<F: Field> AddAssign for LazyMultilinearExtension<F> {
+    fn add_assign(&mut self, other: Self) {
+        *self = &*self + &other;
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a Self> for LazyMultilinearExtension<F> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self = &*self + other;
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a Self)> for LazyMultilinearExtension<F> {
+    fn add_assign(&mut self, (f, other): (F, &'a Self)) {
+        *self = &*self + &other.map(move |x| f * x);
+    }
+}
+
+impl<F: Field> Neg for LazyMultilinearExtension<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<F: Field> Sub for LazyMultilinearExtension<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl<'a, F: Field> Sub<&'a LazyMultilinearExtension<F>> for &LazyMultilinearExtension<F> {
+    type Output = LazyMultilinearExtension<F>;
+
+    fn sub(self, rhs: &'a LazyMultilinearExtension<F>) -> Self::Output {
+        self + &rhs.clone().neg()
+    }
+}
+
+impl<F: Field> SubAssign for LazyMultilinearExtension<F> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = &*self - &other;
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a Self> for LazyMultilinearExtension<F> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self = &*self - other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DenseMultilinearExtension, LazyMultilinearExtension, MultilinearExtension, Polynomial,
+    };
+    use ark_ff::Zero;
+    use ark_serialize::{
+        CanonicalDeserialize, CanonicalSerialize, DeserializeLimits, SerializationError,
+    };
+    use ark_std::{ops::Neg, test_rng, vec::*, UniformRand};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Returns a random dense polynomial together with a lazy polynomial
+    /// computing the same evaluations from a callback.
+    fn rand_lazy(
+        num_vars: usize,
+        rng: &mut impl ark_std::rand::Rng,
+    ) -> (DenseMultilinearExtension<Fr>, LazyMultilinearExtension<Fr>) {
+        let dense = DenseMultilinearExtension::rand(num_vars, rng);
+        let evaluations = dense.evaluations.clone();
+        let lazy = LazyMultilinearExtension::from_fn(num_vars, move |i| evaluations[i]);
+        (dense, lazy)
+    }
+
+    #[test]
+    fn fix_variables() {
+        let mut rng = test_rng();
+        let (dense, lazy) = rand_lazy(10, &mut rng);
+        for k in 0..=10 {
+            let point: Vec<_> = (0..k).map(|_| Fr::rand(&mut rng)).collect();
+            let fixed = lazy.fix_variables(&point);
+            assert_eq!(fixed.num_vars(), 10 - k);
+            assert_eq!(fixed.is_materialized(), k > 0);
+            assert_eq!(
+                fixed.to_evaluations(),
+                dense.fix_variables(&point).evaluations
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_at_a_point() {
+        let mut rng = test_rng();
+        let (dense, lazy) = rand_lazy(10, &mut rng);
+        let table = LazyMultilinearExtension::from_evaluations_vec(10, dense.evaluations.clone());
+        for _ in 0..10 {
+            let point: Vec<_> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+            let expected = dense.evaluate(&point);
+            assert_eq!(lazy.evaluate(&point), expected);
+            assert_eq!(table.evaluate(&point), expected);
+        }
+    }
+
+    #[test]
+    fn relabel_polynomial() {
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let (_, mut poly) = rand_lazy(10, &mut rng);
+            let mut point: Vec<_> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+
+            let expected = poly.evaluate(&point);
+
+            poly = poly.relabel(2, 2, 1); // should have no effect
+            assert_eq!(expected, poly.evaluate(&point));
+
+            poly = poly.relabel(3, 4, 1); // should switch 3 and 4
+            point.swap(3, 4);
+            assert_eq!(expected, poly.evaluate(&point));
+
+            poly = poly.relabel(2, 5, 3);
+            point.swap(2, 5);
+            point.swap(3, 6);
+            point.swap(4, 7);
+            assert_eq!(expected, poly.evaluate(&point));
+
+            poly = poly.relabel(7, 0, 2);
+            point.swap(0, 7);
+            point.swap(1, 8);
+            assert_eq!(expected, poly.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        const NV: usize = 10;
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let point: Vec<_> = (0..NV).map(|_| Fr::rand(&mut rng)).collect();
+            let (_, poly1) = rand_lazy(NV, &mut rng);
+            let (dense2, _) = rand_lazy(NV, &mut rng);
+            let poly2 = LazyMultilinearExtension::from(dense2);
+            let v1 = poly1.evaluate(&point);
+            let v2 = poly2.evaluate(&point);
+            assert_eq!((&poly1 + &poly2).evaluate(&point), v1 + v2);
+            assert_eq!((&poly1 - &poly2).evaluate(&point), v1 - v2);
+            assert_eq!(poly1.clone().neg().evaluate(&point), -v1);
+            {
+                let mut poly1 = poly1.clone();
+                poly1 += &poly2;
+                assert_eq!(poly1.evaluate(&point), v1 + v2);
+            }
+            {
+                let mut poly1 = poly1.clone();
+                poly1 -= &poly2;
+                assert_eq!(poly1.evaluate(&point), v1 - v2);
+            }
+            {
+                let mut poly1 = poly1.clone();
+                let scalar = Fr::rand(&mut rng);
+                poly1 += (scalar, &poly2);
+                assert_eq!(poly1.evaluate(&point), v1 + scalar * v2);
+            }
+            assert_eq!(&poly1 + &LazyMultilinearExtension::zero(), poly1);
+            assert_eq!(&LazyMultilinearExtension::zero() + &poly1, poly1);
+            let mut zero = LazyMultilinearExtension::zero();
+            let scalar = Fr::rand(&mut rng);
+            zero += (scalar, &poly1);
+            assert_eq!(zero.evaluate(&point), scalar * v1);
+        }
+    }
+
+    #[test]
+    fn operations_stay_lazy() {
+        let mut rng = test_rng();
+        let (dense, lazy) = rand_lazy(10, &mut rng);
+        let materialized = LazyMultilinearExtension::from(dense.clone());
+        assert!(!lazy.relabel(2, 5, 3).is_materialized());
+        assert!(!(&lazy + &materialized).is_materialized());
+        assert!(!(&lazy - &materialized).is_materialized());
+        assert!(!lazy.clone().neg().is_materialized());
+        assert!(materialized.relabel(2, 5, 3).is_materialized());
+        assert!((&materialized + &materialized).is_materialized());
+        assert_eq!(
+            lazy.relabel(1, 6, 3).to_evaluations(),
+            dense.relabel(1, 6, 3).evaluations
+        );
+    }
+
+    #[test]
+    fn index_and_conversions() {
+        let mut rng = test_rng();
+        let (dense, lazy) = rand_lazy(8, &mut rng);
+        let materialized = LazyMultilinearExtension::from(dense.clone());
+        for i in 0..1 << 8 {
+            #[cfg(feature = "std")]
+            assert_eq!(lazy[i], dense[i]);
+            assert_eq!(materialized[i], dense[i]);
+            assert_eq!(lazy.evaluation(i), dense[i]);
+        }
+        assert_eq!(lazy.to_evaluations(), dense.evaluations);
+        assert_eq!(DenseMultilinearExtension::from(lazy.clone()), dense);
+        assert_eq!(LazyMultilinearExtension::from(dense), lazy);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn index_caches_chunks() {
+        use ark_std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let lazy = LazyMultilinearExtension::from_fn(12, move |i| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Fr::from(i as u64)
+        });
+        assert_eq!(lazy[3], Fr::from(3u64));
+        assert_eq!(lazy[1000], Fr::from(1000u64));
+        assert_eq!(calls.load(Ordering::Relaxed), 1 << 10);
+        assert_eq!(lazy[3000], Fr::from(3000u64));
+        assert_eq!(calls.load(Ordering::Relaxed), 2 << 10);
+    }
+
+    #[test]
+    fn serialize() {
+        let mut rng = test_rng();
+        let (dense, lazy) = rand_lazy(6, &mut rng);
+        let mut bytes = Vec::new();
+        lazy.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), lazy.compressed_size());
+
+        let mut dense_bytes = Vec::new();
+        dense.serialize_compressed(&mut dense_bytes).unwrap();
+        assert_eq!(bytes, dense_bytes);
+
+        let deserialized = LazyMultilinearExtension::deserialize_compressed(&bytes[..]).unwrap();
+        assert!(deserialized.is_materialized());
+        assert_eq!(deserialized, lazy);
+
+        // The evaluations count towards the element limit.
+        let limits = DeserializeLimits::new((1 << 6) - 1, bytes.len(), 2);
+        assert!(matches!(
+            LazyMultilinearExtension::<Fr>::deserialize_compressed_with_limits(&bytes[..], limits),
+            Err(SerializationError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn from_bytes() {
+        let mut rng = test_rng();
+        let dense = DenseMultilinearExtension::<Fr>::rand(6, &mut rng);
+        let mut bytes = Vec::new();
+        dense
+            .evaluations
+            .serialize_uncompressed(&mut bytes)
+            .unwrap();
+        // Skip the length prefix of the serialized vector.
+        let bytes = bytes[8..].to_vec();
+        let lazy = LazyMultilinearExtension::<Fr>::from_bytes(6, bytes.clone()).unwrap();
+        assert_eq!(lazy.to_evaluations(), dense.evaluations);
+        let point: Vec<_> = (0..6).map(|_| Fr::rand(&mut rng)).collect();
+        assert_eq!(lazy.evaluate(&point), dense.evaluate(&point));
+
+        assert!(LazyMultilinearExtension::<Fr>::from_bytes(5, bytes.clone()).is_err());
+
+        // A non-canonical field element is rejected on construction.
+        let mut invalid = bytes;
+        invalid[..32].fill(0xff);
+        assert!(LazyMultilinearExtension::<Fr>::from_bytes(6, invalid).is_err());
+    }
+}
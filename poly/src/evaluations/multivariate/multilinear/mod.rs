@@ -1,15 +1,21 @@
 mod dense;
+mod lazy;
 mod sparse;
 
 pub use dense::DenseMultilinearExtension;
+pub use lazy::LazyMultilinearExtension;
 pub use sparse::SparseMultilinearExtension;
 
 use ark_std::{
+    cfg_chunks_mut, cfg_iter, cfg_iter_mut,
     fmt::Debug,
     hash::Hash,
     ops::{Add, AddAssign, Index, Neg, SubAssign},
+    vec,
     vec::*,
 };
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use ark_ff::{Field, Zero};
 
@@ -94,3 +100,79 @@ pub(crate) const fn swap_bits(x: usize, a: usize, b: usize, n: usize) -> usize {
     let global_xor_mask = (local_xor_mask << a) | (local_xor_mask << b);
     x ^ global_xor_mask
 }
+
+/// Returns the evaluations of `eq(x, point) = prod_i (x_i * point_i + (1 - x_i) * (1 - point_i))`
+/// over {0,1}^`point.len()`, in little-endian order.
+///
+/// The table is computed with `2^n` multiplications by doubling it once per
+/// variable, starting from the first one.
+pub fn eq_evaluations<F: Field>(point: &[F]) -> Vec<F> {
+    let mut evals = vec![F::zero(); 1 << point.len()];
+    evals[0] = F::one();
+    for (i, r) in point.iter().enumerate() {
+        // The first `2^i` entries hold the table for the first `i` variables.
+        let (lo, hi) = evals[..2 << i].split_at_mut(1 << i);
+        cfg_iter_mut!(lo).zip(hi).for_each(|(lo, hi)| {
+            *hi = *lo * r;
+            *lo -= *hi;
+        });
+    }
+    evals
+}
+
+/// Evaluates `eq(x, y) = prod_i (x_i * y_i + (1 - x_i) * (1 - y_i))`.
+pub fn eq_eval<F: Field>(x: &[F], y: &[F]) -> F {
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "points have different numbers of variables"
+    );
+    cfg_iter!(x)
+        .zip(y)
+        .map(|(x, y)| {
+            let xy = *x * y;
+            xy.double() - x - y + F::one()
+        })
+        .product()
+}
+
+/// Transforms the coefficients `c_S` of a multilinear polynomial in the
+/// monomial basis, where `S` is the set of bits of the index, into its
+/// evaluations `f(x) = sum_{S subset of x} c_S` over the hypercube, in
+/// little-endian order. This is the inverse of [`mobius_transform_in_place`].
+///
+/// # Panics
+///
+/// Panics if the length of `values` is not a power of two.
+pub fn zeta_transform_in_place<F: Field>(values: &mut [F]) {
+    subset_transform_in_place(values, |hi, lo| *hi += lo);
+}
+
+/// Transforms the evaluations of a multilinear polynomial over the hypercube,
+/// in little-endian order, into its coefficients `c_S` in the monomial basis,
+/// i.e. `f(x) = sum_S c_S prod_{i in S} x_i`. This is the inverse of
+/// [`zeta_transform_in_place`].
+///
+/// # Panics
+///
+/// Panics if the length of `values` is not a power of two.
+pub fn mobius_transform_in_place<F: Field>(values: &mut [F]) {
+    subset_transform_in_place(values, |hi, lo| *hi -= lo);
+}
+
+/// Applies `op(values[x], values[x - 2^i])` for every bit `i` and every `x`
+/// with the `i`-th bit set, one bit after the other.
+fn subset_transform_in_place<F: Field>(values: &mut [F], op: impl Fn(&mut F, F) + Send + Sync) {
+    assert!(
+        values.len().is_power_of_two(),
+        "the length should be a power of two"
+    );
+    let mut half = 1;
+    while half < values.len() {
+        cfg_chunks_mut!(values, 2 * half).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(half);
+            hi.iter_mut().zip(lo).for_each(|(hi, lo)| op(hi, *lo));
+        });
+        half *= 2;
+    }
+}
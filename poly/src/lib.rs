@@ -8,17 +8,22 @@ pub mod domain;
 
 pub mod evaluations;
 pub mod polynomial;
+pub mod reed_solomon;
+pub mod sumcheck;
 
 pub use domain::{
-    EvaluationDomain, GeneralEvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain,
+    EvaluationDomain, FftPlan, GeneralEvaluationDomain, MixedRadixEvaluationDomain,
+    Radix2EvaluationDomain,
 };
 pub use evaluations::{
     multivariate::multilinear::{
-        DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension,
+        DenseMultilinearExtension, LazyMultilinearExtension, MultilinearExtension,
+        SparseMultilinearExtension,
     },
     univariate::Evaluations,
 };
 pub use polynomial::{multivariate, univariate, DenseMVPolynomial, DenseUVPolynomial, Polynomial};
+pub use reed_solomon::ReedSolomonCode;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,460 @@
+//! A dense multivariate polynomial represented in coefficient form.
+use crate::{
+    multivariate::{SparsePolynomial, SparseTerm, Term},
+    DenseMVPolynomial, Polynomial,
+};
+use ark_ff::{Field, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    cfg_chunks, cfg_iter_mut, fmt,
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    vec,
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Stores a dense multivariate polynomial in coefficient form, with one
+/// coefficient for every monomial `x_0^{e_0} * ... * x_{n-1}^{e_{n-1}}`
+/// with all `e_i <= max_degree`.
+///
+/// The coefficient of that monomial is stored at index
+/// `sum_i e_i * (max_degree + 1)^i`, so that this representation is suited to
+/// polynomials with few variables and a low individual degree, e.g. AIR
+/// constraints.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DensePolynomial<F: Field> {
+    /// The number of variables of the polynomial.
+    pub num_vars: usize,
+    /// The maximum power of each variable.
+    pub max_degree: usize,
+    /// The `(max_degree + 1)^num_vars` coefficients of the polynomial.
+    pub coeffs: Vec<F>,
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Constructs a new polynomial in `num_vars` variables of individual
+    /// degree at most `max_degree` from its coefficients, where the
+    /// coefficient of `x_0^{e_0} * ... * x_{n-1}^{e_{n-1}}` is at index
+    /// `sum_i e_i * (max_degree + 1)^i`.
+    pub fn from_coefficients_vec(num_vars: usize, max_degree: usize, coeffs: Vec<F>) -> Self {
+        assert_eq!(
+            coeffs.len(),
+            num_coeffs(num_vars, max_degree),
+            "The number of coefficients should be (max_degree + 1)^num_vars."
+        );
+        Self {
+            num_vars,
+            max_degree,
+            coeffs,
+        }
+    }
+
+    /// Returns the coefficient of `x_0^{exponents[0]} * ... * x_{n-1}^{exponents[n-1]}`.
+    pub fn coeff(&self, exponents: &[usize]) -> F {
+        assert_eq!(
+            exponents.len(),
+            self.num_vars,
+            "Invalid number of exponents"
+        );
+        if exponents.iter().any(|e| *e > self.max_degree) {
+            return F::zero();
+        }
+        self.coeffs[self.index(exponents)]
+    }
+
+    fn index(&self, exponents: &[usize]) -> usize {
+        exponents
+            .iter()
+            .rev()
+            .fold(0, |index, e| index * (self.max_degree + 1) + e)
+    }
+
+    /// Returns the exponents of the monomial at `index`.
+    fn exponents(&self, mut index: usize) -> Vec<usize> {
+        (0..self.num_vars)
+            .map(|_| {
+                let e = index % (self.max_degree + 1);
+                index /= self.max_degree + 1;
+                e
+            })
+            .collect()
+    }
+
+    /// Returns the non-zero coefficients together with the exponents of their
+    /// monomials.
+    fn nonzero_terms(&self) -> impl Iterator<Item = (F, Vec<usize>)> + '_ {
+        self.coeffs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| (*c, self.exponents(i)))
+    }
+
+    /// Returns the same polynomial with at least `num_vars` variables and an
+    /// individual degree bound of at least `max_degree`.
+    fn resize(&self, num_vars: usize, max_degree: usize) -> Self {
+        let num_vars = num_vars.max(self.num_vars);
+        let max_degree = max_degree.max(self.max_degree);
+        if num_vars == self.num_vars && max_degree == self.max_degree {
+            return self.clone();
+        }
+        let mut result = Self::from_coefficients_vec(
+            num_vars,
+            max_degree,
+            vec![F::zero(); num_coeffs(num_vars, max_degree)],
+        );
+        for (c, mut exponents) in self.nonzero_terms() {
+            exponents.resize(num_vars, 0);
+            let index = result.index(&exponents);
+            result.coeffs[index] = c;
+        }
+        result
+    }
+
+    /// Divides `self` by a polynomial `form` of total degree one, and returns
+    /// `(q, r)` such that `self = q * form + r`, where `r` does not depend on
+    /// the variable of largest index in `form`. See
+    /// [`SparsePolynomial::divide_by_linear_form`].
+    ///
+    /// Returns `None` if `form` does not have total degree one.
+    pub fn divide_by_linear_form(&self, form: &Self) -> Option<(Self, Self)> {
+        let (quotient, remainder) = self.to_sparse().divide_by_linear_form(&form.to_sparse())?;
+        Some(((&quotient).into(), (&remainder).into()))
+    }
+
+    /// Converts `self` into a sparse polynomial.
+    pub fn to_sparse(&self) -> SparsePolynomial<F, SparseTerm> {
+        let terms = self
+            .nonzero_terms()
+            .map(|(c, exponents)| {
+                (
+                    c,
+                    SparseTerm::new(exponents.into_iter().enumerate().collect()),
+                )
+            })
+            .collect();
+        SparsePolynomial::from_coefficients_vec(self.num_vars, terms)
+    }
+}
+
+/// Returns `(max_degree + 1)^num_vars`.
+fn num_coeffs(num_vars: usize, max_degree: usize) -> usize {
+    (max_degree + 1)
+        .checked_pow(num_vars as u32)
+        .expect("too many coefficients")
+}
+
+impl<F: Field> From<&SparsePolynomial<F, SparseTerm>> for DensePolynomial<F> {
+    fn from(other: &SparsePolynomial<F, SparseTerm>) -> Self {
+        let max_degree = other
+            .terms
+            .iter()
+            .flat_map(|(_, term)| term.powers())
+            .max()
+            .unwrap_or(0);
+        let mut result = Self::from_coefficients_vec(
+            other.num_vars,
+            max_degree,
+            vec![F::zero(); num_coeffs(other.num_vars, max_degree)],
+        );
+        for (c, term) in &other.terms {
+            let mut exponents = vec![0; other.num_vars];
+            for (var, power) in term.iter() {
+                exponents[*var] = *power;
+            }
+            let index = result.index(&exponents);
+            result.coeffs[index] += c;
+        }
+        result
+    }
+}
+
+impl<F: Field> From<&DensePolynomial<F>> for SparsePolynomial<F, SparseTerm> {
+    fn from(other: &DensePolynomial<F>) -> Self {
+        other.to_sparse()
+    }
+}
+
+impl<F: Field> Polynomial<F> for DensePolynomial<F> {
+    type Point = Vec<F>;
+
+    /// Returns the total degree of the polynomial.
+    fn degree(&self) -> usize {
+        self.nonzero_terms()
+            .map(|(_, exponents)| exponents.iter().sum())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Evaluates `self` at `point`, one variable after the other.
+    fn evaluate(&self, point: &Vec<F>) -> F {
+        assert!(point.len() >= self.num_vars, "Invalid evaluation domain");
+        let mut evals = self.coeffs.clone();
+        for x in &point[..self.num_vars] {
+            // Consecutive chunks of `max_degree + 1` coefficients only differ
+            // in the power of the current variable.
+            evals = cfg_chunks!(evals, self.max_degree + 1)
+                .map(|chunk| chunk.iter().rev().fold(F::zero(), |acc, c| acc * x + c))
+                .collect();
+        }
+        evals[0]
+    }
+}
+
+impl<F: Field> PartialEq for DensePolynomial<F> {
+    /// Two polynomials are equal if they have the same non-zero coefficients,
+    /// regardless of their degree bounds and numbers of variables.
+    fn eq(&self, other: &Self) -> bool {
+        self.to_sparse() == other.to_sparse()
+    }
+}
+
+impl<F: Field> Eq for DensePolynomial<F> {}
+
+impl<F: Field> Hash for DensePolynomial<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_sparse().terms.hash(state);
+    }
+}
+
+impl<F: Field> Default for DensePolynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Field> Zero for DensePolynomial<F> {
+    /// Returns the zero polynomial.
+    fn zero() -> Self {
+        Self::from_coefficients_vec(0, 0, vec![F::zero()])
+    }
+
+    /// Checks if the given polynomial is zero.
+    fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+}
+
+impl<F: Field> Add for DensePolynomial<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<'a, F: Field> Add<&'a DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut result = self.resize(other.num_vars, other.max_degree);
+        result += other;
+        result
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a Self> for DensePolynomial<F> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self += (F::one(), other);
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a Self)> for DensePolynomial<F> {
+    fn add_assign(&mut self, (f, other): (F, &'a Self)) {
+        *self = self.resize(other.num_vars, other.max_degree);
+        let other = other.resize(self.num_vars, self.max_degree);
+        cfg_iter_mut!(self.coeffs)
+            .zip(&other.coeffs)
+            .for_each(|(a, b)| *a += f * b);
+    }
+}
+
+impl<F: Field> Neg for DensePolynomial<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        cfg_iter_mut!(self.coeffs).for_each(|c| *c = -*c);
+        self
+    }
+}
+
+impl<'a, F: Field> Sub<&'a DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    #[inline]
+    fn sub(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let mut result = self.clone();
+        result -= other;
+        result
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a Self> for DensePolynomial<F> {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += (-F::one(), other);
+    }
+}
+
+impl<'a, F: Field> Mul<&'a DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    /// Multiplies `self` by `other`. The individual degree bound of the result
+    /// is the sum of those of `self` and `other`.
+    fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        let num_vars = self.num_vars.max(other.num_vars);
+        let max_degree = self.max_degree + other.max_degree;
+        let mut result = DensePolynomial::from_coefficients_vec(
+            num_vars,
+            max_degree,
+            vec![F::zero(); num_coeffs(num_vars, max_degree)],
+        );
+        let other_terms: Vec<_> = other.nonzero_terms().collect();
+        for (a, a_exponents) in self.nonzero_terms() {
+            for (b, b_exponents) in &other_terms {
+                let mut exponents = vec![0; num_vars];
+                for (i, e) in a_exponents.iter().enumerate() {
+                    exponents[i] += e;
+                }
+                for (i, e) in b_exponents.iter().enumerate() {
+                    exponents[i] += e;
+                }
+                let index = result.index(&exponents);
+                result.coeffs[index] += a * b;
+            }
+        }
+        result
+    }
+}
+
+impl<F: Field> Mul for DensePolynomial<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+impl<F: Field> fmt::Debug for DensePolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "{:?}", self.to_sparse())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::UniformRand;
+    use ark_std::{rand::Rng, test_rng};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Generates a random `l`-variate polynomial of individual degree at most `d`.
+    fn rand_poly<R: Rng>(l: usize, d: usize, rng: &mut R) -> DensePolynomial<Fr> {
+        let coeffs = (0..num_coeffs(l, d)).map(|_| Fr::rand(rng)).collect();
+        DensePolynomial::from_coefficients_vec(l, d, coeffs)
+    }
+
+    fn rand_point<R: Rng>(l: usize, rng: &mut R) -> Vec<Fr> {
+        (0..l).map(|_| Fr::rand(rng)).collect()
+    }
+
+    #[test]
+    fn sparse_roundtrip() {
+        let rng = &mut test_rng();
+        for var_count in 0..4 {
+            for max_degree in 0..4 {
+                let p = rand_poly(var_count, max_degree, rng);
+                let sparse = p.to_sparse();
+                let point = rand_point(var_count, rng);
+                assert_eq!(sparse.evaluate(&point), p.evaluate(&point));
+                let dense = DensePolynomial::from(&sparse);
+                assert_eq!(dense.coeffs, p.coeffs);
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic_matches_sparse() {
+        let rng = &mut test_rng();
+        for a_var_count in 0..4 {
+            for b_var_count in 0..4 {
+                let p1 = rand_poly(a_var_count, 2, rng);
+                let p2 = rand_poly(b_var_count, 3, rng);
+                let (s1, s2) = (p1.to_sparse(), p2.to_sparse());
+                assert_eq!((&p1 + &p2).to_sparse(), &s1 + &s2);
+                assert_eq!((&p1 - &p2).to_sparse(), &s1 - &s2);
+                assert_eq!((&p1 * &p2).to_sparse(), &s1 * &s2);
+                assert_eq!(-p1.clone(), DensePolynomial::from(&-s1));
+
+                let point = rand_point(a_var_count.max(b_var_count), rng);
+                assert_eq!(
+                    (&p1 * &p2).evaluate(&point),
+                    p1.evaluate(&point) * p2.evaluate(&point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coefficients_and_degree() {
+        // 3 + 2 * x_0 * x_1^2
+        let mut coeffs = vec![Fr::zero(); 9];
+        coeffs[0] = Fr::from(3);
+        coeffs[1 + 2 * 3] = Fr::from(2);
+        let p = DensePolynomial::from_coefficients_vec(2, 2, coeffs);
+        assert_eq!(p.coeff(&[1, 2]), Fr::from(2));
+        assert_eq!(p.coeff(&[0, 0]), Fr::from(3));
+        assert_eq!(p.coeff(&[2, 1]), Fr::zero());
+        assert_eq!(p.coeff(&[5, 0]), Fr::zero());
+        assert_eq!(p.degree(), 3);
+        assert_eq!(
+            p.evaluate(&vec![Fr::from(2), Fr::from(5)]),
+            Fr::from(3 + 2 * 2 * 25)
+        );
+        assert!(DensePolynomial::<Fr>::zero().is_zero());
+        assert_eq!(DensePolynomial::<Fr>::zero().degree(), 0);
+    }
+
+    #[test]
+    fn divide_by_linear_form_matches_sparse() {
+        let rng = &mut test_rng();
+        for var_count in 1..4 {
+            let p = rand_poly(var_count, 3, rng);
+            let form = rand_poly(var_count, 1, rng).to_sparse();
+            // Keep the terms of total degree at most one.
+            let form = DensePolynomial::from(&SparsePolynomial::from_coefficients_vec(
+                var_count,
+                form.terms
+                    .into_iter()
+                    .filter(|(_, term)| term.degree() <= 1)
+                    .collect(),
+            ));
+            let (q, r) = p.divide_by_linear_form(&form).unwrap();
+            let (sparse_q, sparse_r) = p
+                .to_sparse()
+                .divide_by_linear_form(&form.to_sparse())
+                .unwrap();
+            assert_eq!(q.to_sparse(), sparse_q);
+            assert_eq!(r.to_sparse(), sparse_r);
+            assert_eq!(&(&q * &form) + &r, p);
+        }
+        let constant = DensePolynomial::from_coefficients_vec(1, 0, vec![Fr::from(2)]);
+        assert!(rand_poly(1, 2, rng)
+            .divide_by_linear_form(&constant)
+            .is_none());
+    }
+
+    #[test]
+    fn equality_ignores_bounds() {
+        let rng = &mut test_rng();
+        let p = rand_poly(2, 2, rng);
+        let q = p.resize(3, 4);
+        assert_eq!(q.coeffs.len(), 125);
+        assert_eq!(p, q);
+        assert_eq!(&q - &p, DensePolynomial::zero());
+    }
+}
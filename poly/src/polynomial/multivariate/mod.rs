@@ -13,6 +13,9 @@ use ark_std::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod dense;
+pub use dense::DensePolynomial;
+
 mod sparse;
 pub use sparse::SparsePolynomial;
 
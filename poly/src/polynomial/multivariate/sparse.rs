@@ -1,15 +1,16 @@
 //! A sparse multivariate polynomial represented in coefficient form.
 use crate::{
     multivariate::{SparseTerm, Term},
-    DenseMVPolynomial, Polynomial,
+    univariate::DensePolynomial as UVDensePolynomial,
+    DenseMVPolynomial, DenseMultilinearExtension, DenseUVPolynomial, Polynomial,
 };
-use ark_ff::{Field, Zero};
+use ark_ff::{FftField, Field, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{
     cfg_into_iter,
     cmp::Ordering,
     fmt,
-    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
     rand::Rng,
     vec,
     vec::*,
@@ -172,6 +173,244 @@ impl<F: Field> DenseMVPolynomial<F> for SparsePolynomial<F, SparseTerm> {
     }
 }
 
+impl<F: Field> SparsePolynomial<F, SparseTerm> {
+    /// Returns the polynomial in the remaining variables obtained by setting
+    /// the variable `var` to `value`. The number of variables is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use ark_poly::{
+    ///     polynomial::multivariate::{SparsePolynomial, SparseTerm, Term},
+    ///     DenseMVPolynomial,
+    /// };
+    /// use ark_test_curves::bls12_381::Fq;
+    ///
+    /// // 2 * x_0^2 * x_1 + x_1 with x_0 = 3 is 19 * x_1
+    /// let poly = SparsePolynomial::from_coefficients_vec(
+    ///     2,
+    ///     vec![
+    ///         (Fq::from(2), SparseTerm::new(vec![(0, 2), (1, 1)])),
+    ///         (Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+    ///     ],
+    /// );
+    /// let expected =
+    ///     SparsePolynomial::from_coefficients_vec(2, vec![(Fq::from(19), SparseTerm::new(vec![(1, 1)]))]);
+    /// assert_eq!(poly.partial_evaluate(0, Fq::from(3)), expected);
+    /// ```
+    pub fn partial_evaluate(&self, var: usize, value: F) -> Self {
+        assert!(var < self.num_vars, "Invalid variable");
+        let terms = cfg_into_iter!(&self.terms)
+            .map(|(coeff, term)| {
+                let mut coeff = *coeff;
+                let mut remaining = Vec::with_capacity(term.len());
+                for (v, power) in term.iter() {
+                    if *v == var {
+                        coeff *= value.pow([*power as u64]);
+                    } else {
+                        remaining.push((*v, *power));
+                    }
+                }
+                (coeff, SparseTerm::new(remaining))
+            })
+            .collect();
+        Self::from_coefficients_vec(self.num_vars, terms)
+    }
+
+    /// Divides `self` by a polynomial `form` of total degree one, and returns
+    /// `(q, r)` such that `self = q * form + r`, where `r` does not depend on
+    /// the variable `x_j` of largest index in `form`. That is, `r` is `self`
+    /// with `x_j` replaced by the solution of `form = 0` in `x_j`, and `r` is
+    /// zero if and only if `form` divides `self`.
+    ///
+    /// Returns `None` if `form` does not have total degree one.
+    ///
+    /// # Examples
+    /// ```
+    /// use ark_ff::Zero;
+    /// use ark_poly::{
+    ///     polynomial::multivariate::{SparsePolynomial, SparseTerm, Term},
+    ///     DenseMVPolynomial,
+    /// };
+    /// use ark_test_curves::bls12_381::Fq;
+    ///
+    /// // x_0^2 - x_1^2 = (x_0 - x_1) * (x_0 + x_1)
+    /// let poly = SparsePolynomial::from_coefficients_vec(
+    ///     2,
+    ///     vec![
+    ///         (Fq::from(1), SparseTerm::new(vec![(0, 2)])),
+    ///         (-Fq::from(1), SparseTerm::new(vec![(1, 2)])),
+    ///     ],
+    /// );
+    /// let form = SparsePolynomial::from_coefficients_vec(
+    ///     2,
+    ///     vec![
+    ///         (Fq::from(1), SparseTerm::new(vec![(0, 1)])),
+    ///         (-Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+    ///     ],
+    /// );
+    /// let (quotient, remainder) = poly.divide_by_linear_form(&form).unwrap();
+    /// let expected = SparsePolynomial::from_coefficients_vec(
+    ///     2,
+    ///     vec![
+    ///         (Fq::from(1), SparseTerm::new(vec![(0, 1)])),
+    ///         (Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+    ///     ],
+    /// );
+    /// assert_eq!(quotient, expected);
+    /// assert!(remainder.is_zero());
+    /// ```
+    pub fn divide_by_linear_form(&self, form: &Self) -> Option<(Self, Self)> {
+        if form.degree() != 1 {
+            return None;
+        }
+        let num_vars = self.num_vars.max(form.num_vars);
+        let (pivot_coeff, pivot) = form
+            .terms
+            .iter()
+            .filter_map(|(coeff, term)| term.first().map(|(var, _)| (*coeff, *var)))
+            .max_by_key(|(_, var)| *var)?;
+
+        // `form = pivot_coeff * (x_j - root)`, where `root` does not depend
+        // on `x_j`.
+        let pivot_coeff_inv = pivot_coeff.inverse().unwrap();
+        let root = Self::from_coefficients_vec(
+            num_vars,
+            form.terms
+                .iter()
+                .filter(|(_, term)| term.first().map(|(var, _)| *var) != Some(pivot))
+                .map(|(coeff, term)| (-*coeff * pivot_coeff_inv, term.clone()))
+                .collect(),
+        );
+
+        // Write `self = sum_k coeffs[k] * x_j^k`, and divide by `x_j - root`
+        // with Horner's rule.
+        let mut coeffs: Vec<Vec<(F, SparseTerm)>> = Vec::new();
+        for (coeff, term) in &self.terms {
+            let power = term
+                .iter()
+                .find(|(var, _)| *var == pivot)
+                .map_or(0, |(_, power)| *power);
+            if coeffs.len() <= power {
+                coeffs.resize(power + 1, Vec::new());
+            }
+            let rest = term.iter().filter(|(var, _)| *var != pivot).copied();
+            coeffs[power].push((*coeff, SparseTerm::new(rest.collect())));
+        }
+        let mut quotient_terms = Vec::new();
+        let mut acc = Self::zero();
+        for (power, coeffs) in coeffs.into_iter().enumerate().rev() {
+            acc = &Self::from_coefficients_vec(num_vars, coeffs) + &(&root * &acc);
+            if power == 0 {
+                break;
+            }
+            quotient_terms.extend(acc.terms.iter().map(|(coeff, term)| {
+                let mut term = term.to_vec();
+                term.push((pivot, power - 1));
+                (*coeff * pivot_coeff_inv, SparseTerm::new(term))
+            }));
+        }
+        let quotient = Self::from_coefficients_vec(num_vars, quotient_terms);
+        let mut remainder = acc;
+        remainder.num_vars = num_vars;
+        Some((quotient, remainder))
+    }
+
+    /// Returns the multilinear extension with the same evaluations over the
+    /// hypercube {0,1}^`num_vars` as `self`, or `None` if `self` is not
+    /// multilinear, i.e. if some variable appears with a power larger than one.
+    pub fn to_dense_multilinear(&self) -> Option<DenseMultilinearExtension<F>> {
+        let mut coeffs = vec![F::zero(); 1 << self.num_vars];
+        for (coeff, term) in &self.terms {
+            let mut index = 0;
+            for (var, power) in term.iter() {
+                if *power > 1 {
+                    return None;
+                }
+                index |= 1 << var;
+            }
+            coeffs[index] += coeff;
+        }
+        Some(DenseMultilinearExtension::from_monomial_coefficients(
+            self.num_vars,
+            coeffs,
+        ))
+    }
+}
+
+impl<F: FftField> SparsePolynomial<F, SparseTerm> {
+    /// Returns the univariate polynomial `self(polys[0](X), ..., polys[n - 1](X))`,
+    /// e.g. the composition of an AIR constraint with the trace polynomials.
+    ///
+    /// Powers of each `polys[i]` are computed once, and products use FFTs.
+    pub fn compose(&self, polys: &[UVDensePolynomial<F>]) -> UVDensePolynomial<F> {
+        assert!(
+            polys.len() >= self.num_vars,
+            "Invalid number of polynomials"
+        );
+        // `powers[var][k]` is `polys[var]^(k + 1)`.
+        let mut powers: Vec<Vec<UVDensePolynomial<F>>> = vec![Vec::new(); polys.len()];
+        for (_, term) in &self.terms {
+            for (var, power) in term.iter() {
+                let powers = &mut powers[*var];
+                while powers.len() < *power {
+                    let next = match powers.last() {
+                        Some(last) => last * &polys[*var],
+                        None => polys[*var].clone(),
+                    };
+                    powers.push(next);
+                }
+            }
+        }
+        let products: Vec<UVDensePolynomial<F>> = cfg_into_iter!(&self.terms)
+            .map(|(coeff, term)| {
+                let constant = UVDensePolynomial::from_coefficients_vec(vec![*coeff]);
+                term.iter().fold(constant, |acc, (var, power)| {
+                    &acc * &powers[*var][power - 1]
+                })
+            })
+            .collect();
+        products
+            .iter()
+            .fold(UVDensePolynomial::zero(), |acc, product| &acc + product)
+    }
+}
+
+impl<'a, F: Field> Mul<&'a SparsePolynomial<F, SparseTerm>> for &SparsePolynomial<F, SparseTerm> {
+    type Output = SparsePolynomial<F, SparseTerm>;
+
+    /// Multiplies `self` by `other`, term by term.
+    fn mul(self, other: &'a SparsePolynomial<F, SparseTerm>) -> Self::Output {
+        if self.is_zero() || other.is_zero() {
+            return SparsePolynomial::zero();
+        }
+        let terms: Vec<Vec<_>> = cfg_into_iter!(&self.terms)
+            .map(|(cur_coeff, cur_term)| {
+                other
+                    .terms
+                    .iter()
+                    .map(|(other_coeff, other_term)| {
+                        let mut term = cur_term.to_vec();
+                        term.extend_from_slice(other_term);
+                        (*cur_coeff * other_coeff, SparseTerm::new(term))
+                    })
+                    .collect()
+            })
+            .collect();
+        SparsePolynomial::from_coefficients_vec(
+            core::cmp::max(self.num_vars, other.num_vars),
+            terms.into_iter().flatten().collect(),
+        )
+    }
+}
+
+impl<F: Field> Mul for SparsePolynomial<F, SparseTerm> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
 impl<F: Field, T: Term> Add for SparsePolynomial<F, T> {
     type Output = Self;
 
@@ -340,6 +579,69 @@ mod tests {
         }
     }
 
+    /// Returns a random polynomial of total degree one in `l` variables.
+    fn rand_linear_form<R: Rng>(l: usize, rng: &mut R) -> SparsePolynomial<Fr, SparseTerm> {
+        let mut terms = Vec::new();
+        for i in 0..l {
+            if rng.gen_bool(0.5) {
+                terms.push((Fr::rand(rng), SparseTerm::new(vec![(i, 1)])));
+            }
+        }
+        terms.push((
+            Fr::rand(rng),
+            SparseTerm::new(vec![(rng.gen_range(0..l), 1)]),
+        ));
+        terms.push((Fr::rand(rng), SparseTerm::new(vec![])));
+        SparsePolynomial::from_coefficients_vec(l, terms)
+    }
+
+    #[test]
+    fn divide_by_linear_form() {
+        let rng = &mut test_rng();
+        for var_count in 1..6 {
+            let form = rand_linear_form(var_count, rng);
+            let pivot = form
+                .terms
+                .iter()
+                .flat_map(|(_, term)| term.vars())
+                .max()
+                .unwrap();
+            let p = rand_poly(var_count, 4, rng);
+            let (q, r) = p.divide_by_linear_form(&form).unwrap();
+            assert_eq!(&(&q * &form) + &r, p);
+            assert!(r
+                .terms
+                .iter()
+                .all(|(_, term)| !term.vars().contains(&pivot)));
+
+            // The remainder is `p` at the root of `form` in the pivot variable.
+            let mut point: Vec<Fr> = (0..var_count).map(|_| Fr::rand(rng)).collect();
+            point[pivot] = Fr::zero();
+            let slope = form
+                .partial_evaluate(pivot, Fr::from(1u64))
+                .evaluate(&point)
+                - form.evaluate(&point);
+            point[pivot] = -form.evaluate(&point) / slope;
+            assert!(form.evaluate(&point).is_zero());
+            assert_eq!(r.evaluate(&point), p.evaluate(&point));
+
+            // Exact division.
+            let (q, r) = (&p * &form).divide_by_linear_form(&form).unwrap();
+            assert_eq!(q, p);
+            assert!(r.is_zero());
+        }
+
+        let p = rand_poly(2, 3, rng);
+        let quadratic = &rand_linear_form(2, rng) * &rand_linear_form(2, rng);
+        assert!(p.divide_by_linear_form(&quadratic).is_none());
+        let constant = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![(Fr::from(3u64), SparseTerm::new(vec![]))],
+        );
+        assert!(p.divide_by_linear_form(&constant).is_none());
+        assert!(p.divide_by_linear_form(&SparsePolynomial::zero()).is_none());
+    }
+
     #[test]
     fn add_polynomials() {
         let rng = &mut test_rng();
@@ -562,4 +864,81 @@ mod tests {
         assert_eq!(poly2.degree(), 2, "Degree of poly2 should be 2");
         assert_eq!(poly3.degree(), 0, "Degree of poly3 should be 0");
     }
+
+    #[test]
+    fn mul_polynomials() {
+        let rng = &mut test_rng();
+        for a_var_count in 1..6 {
+            for b_var_count in 1..6 {
+                let p1 = rand_poly(a_var_count, 4, rng);
+                let p2 = rand_poly(b_var_count, 4, rng);
+                let product = &p1 * &p2;
+                // `naive_mul` keeps the number of variables of its first argument.
+                if a_var_count >= b_var_count {
+                    assert_eq!(product, naive_mul(&p1, &p2));
+                } else {
+                    assert_eq!(product, naive_mul(&p2, &p1));
+                }
+                let point: Vec<_> = (0..a_var_count.max(b_var_count))
+                    .map(|_| Fr::rand(rng))
+                    .collect();
+                assert_eq!(
+                    product.evaluate(&point),
+                    p1.evaluate(&point) * p2.evaluate(&point)
+                );
+            }
+        }
+        let p = rand_poly(3, 4, rng);
+        assert!((&p * &SparsePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn partial_evaluate_polynomials() {
+        let rng = &mut test_rng();
+        for var_count in 1..10 {
+            let p = rand_poly(var_count, 5, rng);
+            let point: Vec<_> = (0..var_count).map(|_| Fr::rand(rng)).collect();
+            for var in 0..var_count {
+                let q = p.partial_evaluate(var, point[var]);
+                assert_eq!(q.num_vars, var_count);
+                assert!(q
+                    .terms
+                    .iter()
+                    .all(|(_, t)| t.iter().all(|(v, _)| *v != var)));
+                assert_eq!(q.evaluate(&point), p.evaluate(&point));
+            }
+        }
+    }
+
+    #[test]
+    fn compose_with_univariate_polynomials() {
+        let rng = &mut test_rng();
+        for var_count in 1..6 {
+            let p = rand_poly(var_count, 3, rng);
+            let polys: Vec<_> = (0..var_count)
+                .map(|_| UVDensePolynomial::rand(rng.gen_range(0..5), rng))
+                .collect();
+            let composed = p.compose(&polys);
+            let x = Fr::rand(rng);
+            let point: Vec<_> = polys.iter().map(|q| q.evaluate(&x)).collect();
+            assert_eq!(composed.evaluate(&x), p.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn to_dense_multilinear() {
+        let rng = &mut test_rng();
+        for var_count in 1..8 {
+            let p = rand_poly(var_count, 1, rng);
+            let mle = p.to_dense_multilinear().unwrap();
+            assert_eq!(mle.num_vars, var_count);
+            let point: Vec<_> = (0..var_count).map(|_| Fr::rand(rng)).collect();
+            assert_eq!(mle.evaluate(&point), p.evaluate(&point));
+        }
+        let p = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![(Fr::from(1), SparseTerm::new(vec![(0, 2)]))],
+        );
+        assert!(p.to_dense_multilinear().is_none());
+    }
 }
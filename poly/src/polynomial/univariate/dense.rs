@@ -233,6 +233,18 @@ impl<F: Field> DensePolynomial<F> {
         }
     }
 
+    /// Returns the formal derivative of `self`.
+    pub fn derivative(&self) -> Self {
+        let coeffs = self
+            .coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| F::from(i as u64) * c)
+            .collect();
+        Self::from_coefficients_vec(coeffs)
+    }
+
     /// Returns the quotient of the division of `self` by `other`
     /// using a naive O(nk) algorithm, with n, k the respective degrees of
     /// the dividend and divisor
@@ -875,6 +887,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn derivative() {
+        // (3 + 2x + 5x^3)' = 2 + 15x^2
+        let poly = DensePolynomial::from_coefficients_vec(vec![
+            Fr::from(3u64),
+            Fr::from(2u64),
+            Fr::zero(),
+            Fr::from(5u64),
+        ]);
+        let expected = DensePolynomial::from_coefficients_vec(vec![
+            Fr::from(2u64),
+            Fr::zero(),
+            Fr::from(15u64),
+        ]);
+        assert_eq!(poly.derivative(), expected);
+
+        // (fg)' = f'g + fg'
+        let rng = &mut test_rng();
+        let f = DensePolynomial::<Fr>::rand(20, rng);
+        let g = DensePolynomial::<Fr>::rand(15, rng);
+        assert_eq!(
+            (&f * &g).derivative(),
+            &(&f.derivative() * &g) + &(&f * &g.derivative())
+        );
+        assert!(DensePolynomial::from_coefficients_vec(vec![Fr::one()])
+            .derivative()
+            .is_zero());
+    }
+
     #[test]
     fn divide_polynomials_fixed() {
         let dividend = DensePolynomial::from_coefficients_slice(&[
@@ -921,6 +962,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn divide_polynomials_with_sparse_quotient() {
+        // A quotient with zero low-order coefficients has fewer coefficients
+        // than its degree bound once reversed.
+        let rng = &mut test_rng();
+        let divisor = DensePolynomial::<Fr>::rand(300, rng);
+        let mut quotient_coeffs = vec![Fr::zero(); 50];
+        quotient_coeffs.push(Fr::rand(rng));
+        let quotient = DensePolynomial::from_coefficients_vec(quotient_coeffs);
+        let dividend = &(&divisor * &quotient) + &DensePolynomial::rand(10, rng);
+        assert_eq!(
+            DenseOrSparsePolynomial::hensel_div(&(&dividend).into(), &(&divisor).into()),
+            Some(quotient)
+        );
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut test_rng();
@@ -0,0 +1,529 @@
+//! Root finding and factorization of dense univariate polynomials over prime
+//! fields of odd characteristic.
+//!
+//! The factorization follows the classical three stages, as described in
+//! "Modern Computer Algebra", 3rd edition, chapter 14:
+//! 1. square-free factorization,
+//! 2. distinct-degree factorization, which splits a square-free polynomial into
+//!    products of irreducible factors of equal degree,
+//! 3. equal-degree factorization with the Cantor–Zassenhaus algorithm.
+
+use crate::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain, Polynomial,
+};
+use ark_ff::{BigInteger, BitIteratorBE, PrimeField, Zero};
+use ark_std::{rand::Rng, vec, vec::*};
+
+/// Below this size, products are computed with the schoolbook algorithm.
+const NAIVE_MUL_THRESHOLD: usize = 1 << 6;
+
+/// Multiplies `a` and `b`, falling back to the schoolbook algorithm for small
+/// inputs, or if the field does not contain a large enough FFT domain.
+fn mul<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let size = a.coeffs.len() + b.coeffs.len();
+    if size <= NAIVE_MUL_THRESHOLD
+        || GeneralEvaluationDomain::<F>::compute_size_of_domain(size).is_none()
+    {
+        a.naive_mul(b)
+    } else {
+        a * b
+    }
+}
+
+/// Returns `a / b`, where `b` must divide `a`.
+fn div_exact<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if GeneralEvaluationDomain::<F>::compute_size_of_domain(a.coeffs.len()).is_none() {
+        a.naive_div(b)
+    } else {
+        a / b
+    }
+}
+
+fn monic<F: PrimeField>(p: &DensePolynomial<F>) -> DensePolynomial<F> {
+    p * p.last().unwrap().inverse().unwrap()
+}
+
+fn is_one<F: PrimeField>(p: &DensePolynomial<F>) -> bool {
+    p.coeffs.len() == 1 && p.coeffs[0].is_one()
+}
+
+fn x<F: PrimeField>() -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(vec![F::zero(), F::one()])
+}
+
+/// Arithmetic modulo a fixed polynomial `f`, which precomputes the inverse of
+/// the reversal of `f` so that every reduction only costs two multiplications.
+struct PolyModulus<F: PrimeField> {
+    modulus: DensePolynomial<F>,
+    /// `rev(f)^{-1} mod x^{deg(f)}`
+    reversed_inverse: DensePolynomial<F>,
+}
+
+impl<F: PrimeField> PolyModulus<F> {
+    fn new(modulus: &DensePolynomial<F>) -> Self {
+        let degree = modulus.degree();
+        let reversed =
+            DensePolynomial::from_coefficients_vec(modulus.coeffs.iter().rev().copied().collect());
+        // Computes the inverse with Newton iteration.
+        let mut inverse =
+            DensePolynomial::from_coefficients_vec(vec![reversed.coeffs[0].inverse().unwrap()]);
+        let mut precision = 1;
+        while precision < degree {
+            precision *= 2;
+            // inverse = inverse * (2 - reversed * inverse) mod x^precision
+            let mut e = truncate(&mul(&truncate(&reversed, precision), &inverse), precision);
+            e = -e;
+            e.coeffs[0] += F::from(2u64);
+            inverse = truncate(&mul(&inverse, &e), precision);
+        }
+        Self {
+            modulus: modulus.clone(),
+            reversed_inverse: truncate(&inverse, degree),
+        }
+    }
+
+    /// Reduces `a` modulo `f`. This is fastest when `a` has degree less than
+    /// `2 * deg(f) - 1`, e.g. when it is a product of two reduced polynomials.
+    fn reduce(&self, a: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let m = self.modulus.degree();
+        if a.is_zero() || a.degree() < m {
+            return a.clone();
+        }
+        let n = a.degree();
+        if n + 2 > 2 * m {
+            let a = DenseOrSparsePolynomial::from(a);
+            let modulus = DenseOrSparsePolynomial::from(&self.modulus);
+            return a.divide_with_q_and_r(&modulus).unwrap().1;
+        }
+        // The quotient has `k` coefficients, and its reversal is
+        // `rev(a) * rev(f)^{-1} mod x^k`.
+        let k = n - m + 1;
+        let reversed_a =
+            DensePolynomial::from_coefficients_vec(a.coeffs[m..].iter().rev().copied().collect());
+        let reversed_q = truncate(&mul(&reversed_a, &truncate(&self.reversed_inverse, k)), k);
+        let mut q_coeffs = reversed_q.coeffs;
+        q_coeffs.resize(k, F::zero());
+        q_coeffs.reverse();
+        let q = DensePolynomial::from_coefficients_vec(q_coeffs);
+        truncate(&(a - &mul(&q, &self.modulus)), m)
+    }
+
+    fn mul(&self, a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.reduce(&mul(a, b))
+    }
+
+    /// Computes `base^exp mod f`, where `exp` is given by its little-endian limbs.
+    fn pow(&self, base: &DensePolynomial<F>, exp: &[u64]) -> DensePolynomial<F> {
+        let base = self.reduce(base);
+        let mut result = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        for bit in BitIteratorBE::without_leading_zeros(exp) {
+            result = self.mul(&result, &result);
+            if bit {
+                result = self.mul(&result, &base);
+            }
+        }
+        self.reduce(&result)
+    }
+
+    /// Computes `a^p mod f`, where `p` is the characteristic of the field.
+    fn frobenius(&self, a: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.pow(a, F::MODULUS.as_ref())
+    }
+}
+
+/// Returns `p mod x^n`.
+fn truncate<F: PrimeField>(p: &DensePolynomial<F>, n: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(&p.coeffs[..n.min(p.coeffs.len())])
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Returns the square-free factorization of `self`, i.e. pairwise coprime,
+    /// square-free, monic polynomials `g_i` such that `self` is a constant
+    /// multiple of `prod_i g_i^{e_i}`, as pairs `(g_i, e_i)` sorted by
+    /// increasing multiplicity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn squarefree_factorization(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "cannot factor the zero polynomial");
+        let mut factors = Vec::new();
+        if self.degree() == 0 {
+            return factors;
+        }
+        let f = monic(self);
+
+        // `w` is the product of all distinct factors of `f` whose multiplicity
+        // is not divisible by `p`.
+        let mut c = f.gcd(&f.derivative());
+        let mut w = div_exact(&f, &c);
+        let mut i = 1;
+        while !is_one(&w) {
+            let y = w.gcd(&c);
+            let factor = div_exact(&w, &y);
+            if !is_one(&factor) {
+                factors.push((factor, i));
+            }
+            c = div_exact(&c, &y);
+            w = y;
+            i += 1;
+        }
+
+        // `c` is now a `p`-th power: since `a^p = a` in a prime field,
+        // `c(x) = sum_i c_i x^{ip} = (sum_i c_i x^i)^p`.
+        if !is_one(&c) {
+            // `c` has degree at least `p`, so `p` fits in a `usize`.
+            let limbs = F::MODULUS;
+            let limbs = limbs.as_ref();
+            debug_assert!(limbs[1..].iter().all(|l| *l == 0));
+            let p = limbs[0] as usize;
+            let root = Self::from_coefficients_vec(c.coeffs.iter().step_by(p).copied().collect());
+            factors.extend(
+                root.squarefree_factorization()
+                    .into_iter()
+                    .map(|(g, e)| (g, e * p)),
+            );
+            factors.sort_by_key(|(_, e)| *e);
+        }
+        factors
+    }
+
+    /// Given a square-free polynomial `self`, returns pairs `(g_d, d)` such
+    /// that `g_d` is the monic product of all irreducible factors of `self` of
+    /// degree `d`, sorted by increasing `d`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, and may return an incorrect result if `self`
+    /// is not square-free.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "cannot factor the zero polynomial");
+        let mut factors = Vec::new();
+        let mut f = monic(self);
+        let mut modulus = PolyModulus::new(&f);
+        // Invariant: `h = x^{p^d} mod f`.
+        let mut h = modulus.reduce(&x());
+        let mut d = 1;
+        while f.degree() >= 2 * d {
+            h = modulus.frobenius(&h);
+            // `x^{p^d} - x` is the product of all monic irreducible
+            // polynomials whose degree divides `d`.
+            let g = f.gcd(&(&h - &x()));
+            if !is_one(&g) {
+                f = div_exact(&f, &g);
+                factors.push((g, d));
+                modulus = PolyModulus::new(&f);
+                h = modulus.reduce(&h);
+            }
+            d += 1;
+        }
+        if f.degree() > 0 {
+            let degree = f.degree();
+            factors.push((f, degree));
+        }
+        factors
+    }
+
+    /// Given a square-free polynomial `self` whose irreducible factors all
+    /// have degree `degree`, returns its monic irreducible factors using the
+    /// Cantor–Zassenhaus algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, if `degree` does not divide `self.degree()`,
+    /// or if the characteristic of the field is 2.
+    pub fn equal_degree_factorization<R: Rng>(&self, degree: usize, rng: &mut R) -> Vec<Self> {
+        assert!(!self.is_zero(), "cannot factor the zero polynomial");
+        assert!(degree > 0 && self.degree() % degree == 0);
+        assert!(F::MODULUS.is_odd(), "the characteristic must be odd");
+        let mut factors = Vec::new();
+        Self::equal_degree_factorization_helper(monic(self), degree, rng, &mut factors);
+        factors.sort_by(|a, b| a.coeffs.cmp(&b.coeffs));
+        factors
+    }
+
+    fn equal_degree_factorization_helper<R: Rng>(
+        f: Self,
+        degree: usize,
+        rng: &mut R,
+        factors: &mut Vec<Self>,
+    ) {
+        let n = f.degree();
+        if n <= degree {
+            if n > 0 {
+                factors.push(f);
+            }
+            return;
+        }
+        let modulus = PolyModulus::new(&f);
+        loop {
+            let a = Self::rand(n - 1, rng);
+            let mut g = f.gcd(&a);
+            if is_one(&g) {
+                // For every irreducible factor `f_i` of degree `d`,
+                // `a^{(p^d - 1) / 2}` is `1` or `-1` modulo `f_i` with
+                // probability 1/2 each. We compute it as
+                // `(a^{1 + p + ... + p^{d - 1}})^{(p - 1) / 2}`.
+                let mut frobenius_power = a.clone();
+                let mut norm = a;
+                for _ in 1..degree {
+                    frobenius_power = modulus.frobenius(&frobenius_power);
+                    norm = modulus.mul(&norm, &frobenius_power);
+                }
+                let mut b = modulus.pow(&norm, F::MODULUS_MINUS_ONE_DIV_TWO.as_ref());
+                if b.is_zero() {
+                    continue;
+                }
+                b.coeffs[0] -= F::one();
+                g = f.gcd(&b);
+            }
+            if g.degree() > 0 && g.degree() < n {
+                let h = div_exact(&f, &g);
+                Self::equal_degree_factorization_helper(g, degree, rng, factors);
+                Self::equal_degree_factorization_helper(h, degree, rng, factors);
+                return;
+            }
+        }
+    }
+
+    /// Returns the monic irreducible factors of `self` together with their
+    /// multiplicities, sorted by degree. The leading coefficient of `self` is
+    /// not included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or if the characteristic of the field is 2.
+    pub fn factor<R: Rng>(&self, rng: &mut R) -> Vec<(Self, usize)> {
+        let mut factors = Vec::new();
+        for (squarefree, multiplicity) in self.squarefree_factorization() {
+            for (product, degree) in squarefree.distinct_degree_factorization() {
+                factors.extend(
+                    product
+                        .equal_degree_factorization(degree, rng)
+                        .into_iter()
+                        .map(|factor| (factor, multiplicity)),
+                );
+            }
+        }
+        factors.sort_by(|(a, _), (b, _)| (a.degree(), &a.coeffs).cmp(&(b.degree(), &b.coeffs)));
+        factors
+    }
+
+    /// Returns the distinct roots of `self` in `F`, in increasing order.
+    ///
+    /// The roots are found by splitting the product of all linear factors of
+    /// `self`, `gcd(self, x^p - x)`, with `gcd((x + a)^{(p - 1) / 2} - 1, .)`
+    /// for random `a`, and quadratic factors are solved with `Field::sqrt`.
+    /// Each `a` separates any two roots with probability 1/2, so the expected
+    /// number of attempts does not depend on `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or if the characteristic of the field is 2.
+    pub fn roots<R: Rng>(&self, rng: &mut R) -> Vec<F> {
+        assert!(!self.is_zero(), "the zero polynomial vanishes everywhere");
+        assert!(F::MODULUS.is_odd(), "the characteristic must be odd");
+        if self.degree() == 0 {
+            return Vec::new();
+        }
+        let f = monic(self);
+        let x_to_the_p = PolyModulus::new(&f).frobenius(&x());
+        let linear_part = f.gcd(&(&x_to_the_p - &x()));
+
+        let mut roots = Vec::new();
+        Self::split_linear_factors(linear_part, rng, &mut roots);
+        roots.sort();
+        roots
+    }
+
+    /// Finds the roots of `f`, which must be a monic product of distinct
+    /// linear factors.
+    fn split_linear_factors<R: Rng>(f: Self, rng: &mut R, roots: &mut Vec<F>) {
+        match f.degree() {
+            0 => {},
+            1 => roots.push(-f.coeffs[0]),
+            2 => {
+                // x^2 + bx + c = 0 iff x = (-b ± sqrt(b^2 - 4c)) / 2
+                let (c, b) = (f.coeffs[0], f.coeffs[1]);
+                let sqrt = (b.square() - c.double().double()).sqrt().unwrap();
+                let two_inv = F::from(2u64).inverse().unwrap();
+                roots.push((sqrt - b) * two_inv);
+                roots.push((-sqrt - b) * two_inv);
+            },
+            n => {
+                let modulus = PolyModulus::new(&f);
+                loop {
+                    let base = Self::from_coefficients_vec(vec![F::rand(rng), F::one()]);
+                    let mut b = modulus.pow(&base, F::MODULUS_MINUS_ONE_DIV_TWO.as_ref());
+                    if b.is_zero() {
+                        b = base;
+                    } else {
+                        b.coeffs[0] -= F::one();
+                    }
+                    let g = f.gcd(&b);
+                    if g.degree() > 0 && g.degree() < n {
+                        let h = div_exact(&f, &g);
+                        Self::split_linear_factors(g, rng, roots);
+                        Self::split_linear_factors(h, rng, roots);
+                        return;
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::{univariate::*, DenseUVPolynomial, Polynomial};
+    use ark_ff::{Field, Fp64, MontBackend, MontConfig, One, PrimeField, UniformRand, Zero};
+    use ark_std::test_rng;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[derive(MontConfig)]
+    #[modulus = "17"]
+    #[generator = "3"]
+    pub(crate) struct F17Config;
+
+    pub(crate) type F17 = Fp64<MontBackend<F17Config, 1>>;
+
+    fn from_roots<F: PrimeField>(roots: &[F]) -> DensePolynomial<F> {
+        roots.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![F::one()]),
+            |acc, r| acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![-*r, F::one()])),
+        )
+    }
+
+    fn product<F: PrimeField>(factors: &[(DensePolynomial<F>, usize)]) -> DensePolynomial<F> {
+        let mut result = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        for (factor, multiplicity) in factors {
+            for _ in 0..*multiplicity {
+                result = result.naive_mul(factor);
+            }
+        }
+        result
+    }
+
+    /// Checks that `f` has no non-trivial factor of degree at most `deg(f) / 2`.
+    fn is_irreducible<F: PrimeField>(f: &DensePolynomial<F>) -> bool {
+        let distinct_degree = f.distinct_degree_factorization();
+        distinct_degree.len() == 1 && distinct_degree[0].1 == f.degree()
+    }
+
+    #[test]
+    fn roots_of_split_polynomial() {
+        let rng = &mut test_rng();
+        let mut roots: Vec<Fr> = (0..20).map(|_| Fr::rand(rng)).collect();
+        roots.push(Fr::zero());
+        // Repeated roots are only reported once.
+        let mut with_repetitions = roots.clone();
+        with_repetitions.extend_from_slice(&roots[..5]);
+        with_repetitions.push(roots[0]);
+
+        // x^2 - nonresidue has no root.
+        let irreducible =
+            DensePolynomial::from_coefficients_vec(vec![-Fr::GENERATOR, Fr::zero(), Fr::one()]);
+        let f = &from_roots(&with_repetitions).naive_mul(&irreducible) * Fr::from(7u64);
+
+        roots.sort();
+        assert_eq!(f.roots(rng), roots);
+        for r in f.roots(rng) {
+            assert!(f.evaluate(&r).is_zero());
+        }
+    }
+
+    #[test]
+    fn roots_of_small_polynomials() {
+        let rng = &mut test_rng();
+        let constant = DensePolynomial::from_coefficients_vec(vec![Fr::from(3u64)]);
+        assert!(constant.roots(rng).is_empty());
+        for n in 1..6 {
+            let mut roots: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+            roots.sort();
+            assert_eq!(from_roots(&roots).roots(rng), roots);
+        }
+        // Every element of F_17 is a root of x^17 - x.
+        let mut coeffs = vec![F17::zero(); 18];
+        coeffs[1] = -F17::one();
+        coeffs[17] = F17::one();
+        let f = DensePolynomial::from_coefficients_vec(coeffs);
+        let all: Vec<F17> = (0..17u64).map(F17::from).collect();
+        assert_eq!(f.roots(rng), all);
+    }
+
+    #[test]
+    fn squarefree_factorization() {
+        let rng = &mut test_rng();
+        let a = DensePolynomial::<Fr>::rand(3, rng);
+        let b = DensePolynomial::<Fr>::rand(4, rng);
+        let f = &a.naive_mul(&b).naive_mul(&b).naive_mul(&b) * Fr::from(5u64);
+        let factors = f.squarefree_factorization();
+        assert_eq!(factors.len(), 2);
+        assert_eq!(factors[0], (&a * a.last().unwrap().inverse().unwrap(), 1));
+        assert_eq!(factors[1], (&b * b.last().unwrap().inverse().unwrap(), 3));
+
+        // (x + 1)^17 * (x + 2)^2 = (x^17 + 1) * (x + 2)^2 has a zero derivative
+        // part which must be handled by taking a p-th root.
+        let x_plus_1 = DensePolynomial::from_coefficients_vec(vec![F17::one(), F17::one()]);
+        let x_plus_2 = DensePolynomial::from_coefficients_vec(vec![F17::from(2u64), F17::one()]);
+        let factors = vec![(x_plus_2, 2), (x_plus_1, 17)];
+        assert_eq!(product(&factors).squarefree_factorization(), factors);
+    }
+
+    #[test]
+    fn factor() {
+        let rng = &mut test_rng();
+        for _ in 0..5 {
+            // Random polynomials over F_17 have factors of many different degrees.
+            let a = DensePolynomial::<F17>::rand(12, rng);
+            let b = DensePolynomial::<F17>::rand(3, rng);
+            let f = a.naive_mul(&b).naive_mul(&b);
+            let factors = f.factor(rng);
+            assert_eq!(
+                &product(&factors) * *f.last().unwrap(),
+                f,
+                "factors do not multiply to the input"
+            );
+            for (factor, _) in &factors {
+                assert!(factor.last().unwrap().is_one());
+                assert!(is_irreducible(factor));
+            }
+            assert!(factors.windows(2).all(|w| w[0].0 != w[1].0));
+        }
+
+        for _ in 0..3 {
+            let f = DensePolynomial::<Fr>::rand(20, rng);
+            let factors = f.factor(rng);
+            assert_eq!(&product(&factors) * *f.last().unwrap(), f);
+            for (factor, _) in &factors {
+                assert!(is_irreducible(factor));
+            }
+        }
+    }
+
+    #[test]
+    fn equal_degree_factorization() {
+        let rng = &mut test_rng();
+        // 3, 6 and 7 are quadratic non-residues in F_17, so each x^2 - c is
+        // irreducible.
+        let quadratics: Vec<_> = [3u64, 6, 7]
+            .iter()
+            .map(|c| {
+                DensePolynomial::from_coefficients_vec(vec![
+                    -F17::from(*c),
+                    F17::zero(),
+                    F17::one(),
+                ])
+            })
+            .collect();
+        let f = quadratics.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![F17::one()]),
+            |acc, q| acc.naive_mul(q),
+        );
+        let mut expected = quadratics;
+        expected.sort_by(|a, b| a.coeffs.cmp(&b.coeffs));
+        assert_eq!(f.equal_degree_factorization(2, rng), expected);
+        assert_eq!(f.distinct_degree_factorization(), vec![(f.clone(), 2)]);
+    }
+}
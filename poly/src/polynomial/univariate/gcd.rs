@@ -0,0 +1,507 @@
+//! Greatest common divisors and resultants of dense univariate polynomials.
+//!
+//! For small degrees, the classical Euclidean algorithm is used. For large
+//! degrees, the remainder sequence is traversed with the half-GCD algorithm,
+//! which computes the quotients of roughly `n / 2` consecutive Euclidean steps
+//! from the top `n / 2` coefficients of the inputs only, following
+//! "Modern Computer Algebra", 3rd edition, section 11.1. Together with FFT
+//! multiplication, this runs in time `O(M(n) log n)`.
+
+use crate::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    DenseUVPolynomial, Polynomial,
+};
+use ark_ff::{FftField, Zero};
+use ark_std::{cmp::min, vec, vec::*};
+
+/// Below this degree, the half-GCD algorithm falls back to the classical
+/// Euclidean algorithm.
+const HALF_GCD_THRESHOLD: usize = 1 << 6;
+
+/// The matrix `[[m00, m01], [m10, m11]]` mapping a pair of consecutive
+/// remainders `(a, b)` to `(m00 * a + m01 * b, m10 * a + m11 * b)`, i.e.
+/// a pair of remainders further down the remainder sequence.
+#[derive(Clone)]
+struct EuclidMatrix<F: FftField>([[DensePolynomial<F>; 2]; 2]);
+
+impl<F: FftField> EuclidMatrix<F> {
+    fn identity() -> Self {
+        let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        Self([
+            [one.clone(), DensePolynomial::zero()],
+            [DensePolynomial::zero(), one],
+        ])
+    }
+
+    fn apply(
+        &self,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        let [[m00, m01], [m10, m11]] = &self.0;
+        (&(m00 * a) + &(m01 * b), &(m10 * a) + &(m11 * b))
+    }
+
+    /// Returns `[[0, 1], [1, -q]] * self`, i.e. appends the Euclidean step with
+    /// quotient `q`.
+    fn then_step(self, q: &DensePolynomial<F>) -> Self {
+        let [[m00, m01], [m10, m11]] = self.0;
+        let n10 = &m00 - &(q * &m10);
+        let n11 = &m01 - &(q * &m11);
+        Self([[m10, m11], [n10, n11]])
+    }
+
+    /// Returns `other * self`, i.e. appends the steps of `other`.
+    fn then(self, other: &Self) -> Self {
+        let [[a00, a01], [a10, a11]] = &other.0;
+        let [[b00, b01], [b10, b11]] = &self.0;
+        Self([
+            [&(a00 * b00) + &(a01 * b10), &(a00 * b01) + &(a01 * b11)],
+            [&(a10 * b00) + &(a11 * b10), &(a10 * b01) + &(a11 * b11)],
+        ])
+    }
+}
+
+/// A step `(a, b) -> (b, a mod b)` of the Euclidean algorithm, as needed to
+/// compute the resultant. The half-GCD algorithm works on truncated
+/// polynomials, whose quotients and leading coefficients are exact, but whose
+/// last remainder is not. So only the degree of the quotient is recorded: the
+/// degree of `a mod b` follows from the quotient of the next step.
+struct EuclidStep<F> {
+    /// The leading coefficient of `b`.
+    leading_coeff: F,
+    /// `deg(a) - deg(b)`, if `deg(a) >= deg(b)`.
+    quotient_degree: usize,
+}
+
+impl<F: FftField> EuclidStep<F> {
+    /// Performs the step `(a, b) -> (b, a mod b)`, and returns the step and
+    /// the quotient `a / b`.
+    fn new(a: &mut DensePolynomial<F>, b: &mut DensePolynomial<F>) -> (Self, DensePolynomial<F>) {
+        let (q, r) = div_rem(a, b);
+        let step = Self {
+            leading_coeff: *b.last().unwrap(),
+            quotient_degree: q.degree(),
+        };
+        *a = ark_std::mem::replace(b, r);
+        (step, q)
+    }
+}
+
+fn div_rem<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    DenseOrSparsePolynomial::from(a)
+        .divide_with_q_and_r(&DenseOrSparsePolynomial::from(b))
+        .expect("division failed")
+}
+
+/// Returns `p / x^k`, discarding the remainder.
+fn shift_down<F: FftField>(p: &DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(&p.coeffs[min(k, p.coeffs.len())..])
+}
+
+/// Given `deg(a) >= deg(b)`, returns the matrix `M` of the Euclidean steps
+/// that take `(a, b)` to the first pair of consecutive remainders `(c, d)`
+/// with `deg(d) < ceil(deg(a) / 2)`, together with those steps.
+fn half_gcd<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (EuclidMatrix<F>, Vec<EuclidStep<F>>) {
+    let m = a.degree().div_ceil(2);
+    if b.is_zero() || b.degree() < m {
+        return (EuclidMatrix::identity(), Vec::new());
+    }
+
+    if a.degree() < HALF_GCD_THRESHOLD {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        let mut matrix = EuclidMatrix::identity();
+        let mut steps = Vec::new();
+        while !b.is_zero() && b.degree() >= m {
+            let (step, q) = EuclidStep::new(&mut a, &mut b);
+            matrix = matrix.then_step(&q);
+            steps.push(step);
+        }
+        return (matrix, steps);
+    }
+
+    // The quotients of the first half of the steps only depend on the top
+    // halves of `a` and `b`.
+    let (r, mut steps) = half_gcd(&shift_down(a, m), &shift_down(b, m));
+    let (mut a, mut b) = r.apply(a, b);
+    if b.is_zero() || b.degree() < m {
+        return (r, steps);
+    }
+
+    let (step, q) = EuclidStep::new(&mut a, &mut b);
+    steps.push(step);
+    let r = r.then_step(&q);
+
+    // `deg(a) < 2 * m` here, so `k > 0`.
+    let k = 2 * m - a.degree();
+    let (s, more_steps) = half_gcd(&shift_down(&a, k), &shift_down(&b, k));
+    steps.extend(more_steps);
+    (r.then(&s), steps)
+}
+
+/// Runs the Euclidean algorithm on `(a, b)`, and returns the last non-zero
+/// remainder, all steps, and, if `with_matrix` is set, the matrix `M` such
+/// that `M * (a, b) = (gcd, 0)`, where `gcd` is not normalized.
+fn euclid<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+    with_matrix: bool,
+) -> (
+    DensePolynomial<F>,
+    Vec<EuclidStep<F>>,
+    Option<EuclidMatrix<F>>,
+) {
+    let mut a = DensePolynomial::from_coefficients_slice(a);
+    let mut b = DensePolynomial::from_coefficients_slice(b);
+    let mut matrix = with_matrix.then(EuclidMatrix::identity);
+    let mut steps = Vec::new();
+    while !b.is_zero() {
+        if a.degree() >= HALF_GCD_THRESHOLD
+            && b.degree() <= a.degree()
+            && 2 * b.degree() > a.degree()
+        {
+            // This performs at least one step, since `deg(b) >= ceil(deg(a) / 2)`.
+            let (m, more_steps) = half_gcd(&a, &b);
+            (a, b) = m.apply(&a, &b);
+            matrix = matrix.map(|matrix| matrix.then(&m));
+            steps.extend(more_steps);
+        } else {
+            let (step, q) = EuclidStep::new(&mut a, &mut b);
+            matrix = matrix.map(|matrix| matrix.then_step(&q));
+            steps.push(step);
+        }
+    }
+    (a, steps, matrix)
+}
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Returns the monic greatest common divisor of `self` and `other`, or the
+    /// zero polynomial if both are zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (gcd, ..) = euclid(self, other, false);
+        match gcd.last() {
+            Some(lc) => &gcd * lc.inverse().unwrap(),
+            None => gcd,
+        }
+    }
+
+    /// Returns `(g, s, t)` such that `g` is the monic greatest common divisor
+    /// of `self` and `other`, and `s * self + t * other = g`. If both `self`
+    /// and `other` are zero, all three are zero.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (gcd, _, matrix) = euclid(self, other, true);
+        let Some(lc_inv) = gcd.last().map(|lc| lc.inverse().unwrap()) else {
+            return (Self::zero(), Self::zero(), Self::zero());
+        };
+        let [[s, t], _] = matrix.unwrap().0;
+        (&gcd * lc_inv, &s * lc_inv, &t * lc_inv)
+    }
+
+    /// Returns the resultant of `self` and `other`, i.e. the determinant of
+    /// their Sylvester matrix. The resultant is zero if and only if `self`
+    /// and `other` have a common root (in the algebraic closure), or if either
+    /// of them is zero.
+    pub fn resultant(&self, other: &Self) -> F {
+        if self.is_zero() || other.is_zero() {
+            return F::zero();
+        }
+        let (deg_a, deg_b) = (self.degree(), other.degree());
+        if deg_a < deg_b {
+            let res = other.resultant(self);
+            return if deg_a % 2 == 1 && deg_b % 2 == 1 {
+                -res
+            } else {
+                res
+            };
+        }
+        let (_, steps, _) = euclid(self, other, false);
+
+        // Res(a, b) = (-1)^{deg(a) deg(b)} lc(b)^{deg(a) - deg(r)} Res(b, r),
+        // where r = a mod b, and Res(a, c) = c^{deg(a)} for a constant c.
+        let (mut deg_a, mut deg_b) = (deg_a, deg_b);
+        let mut result = F::one();
+        let mut steps = steps.into_iter().peekable();
+        while let Some(step) = steps.next() {
+            if deg_b == 0 {
+                return result * step.leading_coeff.pow([deg_a as u64]);
+            }
+            // `deg(r) = deg(b) - deg(b / r)`, and `r` is zero after the last step.
+            let Some(next_step) = steps.peek() else {
+                return F::zero();
+            };
+            let deg_r = deg_b - next_step.quotient_degree;
+            result *= step.leading_coeff.pow([(deg_a - deg_r) as u64]);
+            if deg_a % 2 == 1 && deg_b % 2 == 1 {
+                result = -result;
+            }
+            (deg_a, deg_b) = (deg_b, deg_r);
+        }
+        unreachable!("the Euclidean algorithm ends with a constant or a zero remainder")
+    }
+
+    /// Returns whether `self` has no repeated factors. The zero polynomial is
+    /// not square-free.
+    pub fn is_squarefree(&self) -> bool {
+        !self.is_zero() && self.gcd(&self.derivative()).degree() == 0
+    }
+
+    /// Runs the extended Euclidean algorithm on `(self, other)`, where
+    /// `deg(self) > deg(other)`, until the first remainder `r` of degree less
+    /// than `degree`, and returns `(r, t)` such that `r = s * self + t * other`
+    /// for some `s`.
+    pub(crate) fn partial_xgcd(&self, other: &Self, degree: usize) -> (Self, Self) {
+        debug_assert!(other.is_zero() || self.degree() > other.degree());
+        let mut a = self.clone();
+        let mut b = other.clone();
+        let mut matrix = if a.degree() >= HALF_GCD_THRESHOLD && !b.is_zero() && b.degree() >= degree
+        {
+            // The steps until the remainders drop below `degree` only
+            // depend on the top `2 * (deg(a) - degree)` coefficients, so
+            // the half-GCD of the truncated inputs stops exactly at
+            // `degree`.
+            let k = (2 * degree).saturating_sub(a.degree());
+            let (m, _) = half_gcd(&shift_down(&a, k), &shift_down(&b, k));
+            (a, b) = m.apply(&a, &b);
+            m
+        } else {
+            EuclidMatrix::identity()
+        };
+        while !b.is_zero() && b.degree() >= degree {
+            let (_, q) = EuclidStep::new(&mut a, &mut b);
+            matrix = matrix.then_step(&q);
+        }
+        let [_, [_, t]] = matrix.0;
+        (b, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polynomial::{univariate::*, DenseUVPolynomial, Polynomial};
+    use ark_ff::{Field, One, UniformRand, Zero};
+    use ark_std::{rand::Rng, test_rng};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// The classical Euclidean algorithm, used as a reference.
+    fn naive_gcd(a: &DensePolynomial<Fr>, b: &DensePolynomial<Fr>) -> DensePolynomial<Fr> {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while !b.is_zero() {
+            let r = &a - &(&a.naive_div(&b) * &b);
+            (a, b) = (b, r);
+        }
+        if a.is_zero() {
+            a
+        } else {
+            let lc_inv = a.last().unwrap().inverse().unwrap();
+            &a * lc_inv
+        }
+    }
+
+    fn from_roots(roots: &[Fr]) -> DensePolynomial<Fr> {
+        roots.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![Fr::one()]),
+            |acc, root| {
+                acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![
+                    -*root,
+                    Fr::one(),
+                ]))
+            },
+        )
+    }
+
+    fn sparse_poly<R: Rng>(degree: usize, rng: &mut R) -> DensePolynomial<Fr> {
+        let mut coeffs = vec![Fr::zero(); degree + 1];
+        coeffs[degree] = Fr::rand(rng);
+        for _ in 0..3 {
+            coeffs[rng.gen_range(0..degree)] = Fr::rand(rng);
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    #[test]
+    fn gcd_matches_naive_gcd() {
+        let rng = &mut test_rng();
+        for (deg_a, deg_b, deg_common) in [
+            (0, 0, 0),
+            (5, 3, 0),
+            (3, 5, 2),
+            (20, 20, 7),
+            (150, 100, 0),
+            (150, 149, 40),
+            (300, 200, 100),
+            (257, 300, 1),
+        ] {
+            let common = DensePolynomial::<Fr>::rand(deg_common, rng);
+            let a = &DensePolynomial::rand(deg_a, rng) * &common;
+            let b = &DensePolynomial::rand(deg_b, rng) * &common;
+            let gcd = a.gcd(&b);
+            assert_eq!(gcd, naive_gcd(&a, &b));
+            assert_eq!(gcd.degree(), deg_common);
+        }
+    }
+
+    #[test]
+    fn gcd_of_sparse_polynomials() {
+        // Sparse polynomials have remainder sequences where the degree
+        // drops by more than one.
+        let rng = &mut test_rng();
+        for (deg_a, deg_b) in [(200, 150), (400, 399), (300, 100)] {
+            let common = sparse_poly(30, rng);
+            let a = &sparse_poly(deg_a, rng) * &common;
+            let b = &sparse_poly(deg_b, rng) * &common;
+            assert_eq!(a.gcd(&b), naive_gcd(&a, &b));
+        }
+    }
+
+    #[test]
+    fn gcd_and_resultant_of_sparse_polynomials_with_common_factor() {
+        // The quotients of these remainder sequences have zero low-order
+        // coefficients, and the last remainders of the truncated half-GCD
+        // steps differ from the actual ones.
+        let rng = &mut test_rng();
+        for (deg_a, deg_b, deg_common) in [
+            (311, 251, 88),
+            (81, 282, 89),
+            (304, 33, 57),
+            (366, 351, 41),
+            (298, 345, 21),
+        ] {
+            let common = sparse_poly(deg_common, rng);
+            let a = &sparse_poly(deg_a, rng) * &common;
+            let b = &sparse_poly(deg_b, rng) * &common;
+            let gcd = a.gcd(&b);
+            assert_eq!(gcd, naive_gcd(&a, &b));
+            assert!(gcd.degree() >= deg_common);
+            assert!(a.resultant(&b).is_zero());
+            assert!(b.resultant(&a).is_zero());
+        }
+    }
+
+    #[test]
+    fn gcd_with_zero() {
+        let rng = &mut test_rng();
+        let a = DensePolynomial::<Fr>::rand(10, rng);
+        let zero = DensePolynomial::zero();
+        let monic_a = &a * a.last().unwrap().inverse().unwrap();
+        assert_eq!(a.gcd(&zero), monic_a);
+        assert_eq!(zero.gcd(&a), monic_a);
+        assert!(zero.gcd(&zero).is_zero());
+        assert_eq!(a.xgcd(&zero).0, monic_a);
+        assert!(zero.xgcd(&zero).0.is_zero());
+    }
+
+    #[test]
+    fn xgcd_bezout_identity() {
+        let rng = &mut test_rng();
+        for (deg_a, deg_b, deg_common) in [(1, 0, 0), (10, 12, 3), (200, 180, 50), (500, 300, 0)] {
+            let common = DensePolynomial::<Fr>::rand(deg_common, rng);
+            let a = &DensePolynomial::rand(deg_a, rng) * &common;
+            let b = &DensePolynomial::rand(deg_b, rng) * &common;
+            let (g, s, t) = a.xgcd(&b);
+            assert_eq!(g, a.gcd(&b));
+            assert_eq!(&(&s * &a) + &(&t * &b), g);
+            assert!(s.is_zero() || s.degree() < b.degree());
+            assert!(t.is_zero() || t.degree() < a.degree());
+        }
+    }
+
+    #[test]
+    fn partial_xgcd_matches_naive_euclid() {
+        let rng = &mut test_rng();
+        for (deg_a, deg_b, degree) in [(10, 9, 5), (200, 150, 120), (300, 299, 150), (300, 250, 40)]
+        {
+            let a = DensePolynomial::<Fr>::rand(deg_a, rng);
+            let b = sparse_poly(deg_b, rng);
+
+            // Reference: the classical extended Euclidean algorithm.
+            let (mut r0, mut r1) = (a.clone(), b.clone());
+            let (mut t0, mut t1) = (
+                DensePolynomial::zero(),
+                DensePolynomial::from_coefficients_vec(vec![Fr::one()]),
+            );
+            while !r1.is_zero() && r1.degree() >= degree {
+                let q = r0.naive_div(&r1);
+                let r = &r0 - &(&q * &r1);
+                let t = &t0 - &(&q * &t1);
+                (r0, r1, t0, t1) = (r1, r, t1, t);
+            }
+
+            let (r, t) = a.partial_xgcd(&b, degree);
+            assert_eq!(r, r1);
+            assert_eq!(t, t1);
+        }
+    }
+
+    #[test]
+    fn resultant_from_roots() {
+        // For a monic `a` with roots `r_i`, `Res(a, b) = prod_i b(r_i)`.
+        let rng = &mut test_rng();
+        for (deg_a, deg_b) in [
+            (1, 0),
+            (0, 3),
+            (3, 5),
+            (5, 3),
+            (100, 80),
+            (200, 300),
+            (300, 150),
+        ] {
+            let roots: Vec<Fr> = (0..deg_a).map(|_| Fr::rand(rng)).collect();
+            let a = from_roots(&roots);
+            let b = DensePolynomial::rand(deg_b, rng);
+            let expected: Fr = roots.iter().map(|r| b.evaluate(r)).product();
+            assert_eq!(a.resultant(&b), expected);
+
+            // Res(b, a) = (-1)^{deg(a) deg(b)} Res(a, b)
+            let sign = if deg_a % 2 == 1 && deg_b % 2 == 1 {
+                -Fr::one()
+            } else {
+                Fr::one()
+            };
+            assert_eq!(b.resultant(&a), sign * expected);
+
+            // Scaling `a` by `c` scales the resultant by `c^{deg(b)}`.
+            let c = Fr::rand(rng);
+            assert_eq!((&a * c).resultant(&b), c.pow([deg_b as u64]) * expected);
+        }
+    }
+
+    #[test]
+    fn resultant_of_sparse_polynomials() {
+        let rng = &mut test_rng();
+        for (deg_a, deg_b) in [(150, 200), (257, 100)] {
+            let roots: Vec<Fr> = (0..deg_a).map(|_| Fr::rand(rng)).collect();
+            let a = from_roots(&roots);
+            let b = sparse_poly(deg_b, rng);
+            let expected: Fr = roots.iter().map(|r| b.evaluate(r)).product();
+            assert_eq!(a.resultant(&b), expected);
+            assert_eq!(b.resultant(&a), expected);
+        }
+    }
+
+    #[test]
+    fn resultant_with_common_root() {
+        let rng = &mut test_rng();
+        let common = DensePolynomial::<Fr>::rand(1, rng);
+        let a = &DensePolynomial::rand(150, rng) * &common;
+        let b = &DensePolynomial::rand(120, rng) * &common;
+        assert!(a.resultant(&b).is_zero());
+        assert!(a.resultant(&DensePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn squarefree() {
+        let rng = &mut test_rng();
+        let a = DensePolynomial::<Fr>::rand(100, rng);
+        let b = DensePolynomial::<Fr>::rand(3, rng);
+        assert!(a.is_squarefree());
+        assert!(!(&a * &(&b * &b)).is_squarefree());
+        assert!(DensePolynomial::from_coefficients_vec(vec![Fr::from(3u64)]).is_squarefree());
+        assert!(!DensePolynomial::<Fr>::zero().is_squarefree());
+    }
+}
@@ -8,6 +8,8 @@ use ark_std::{borrow::Cow, cfg_iter_mut, vec, vec::*};
 use DenseOrSparsePolynomial::{DPolynomial, SPolynomial};
 
 mod dense;
+mod factor;
+mod gcd;
 mod sparse;
 
 pub use dense::DensePolynomial;
@@ -187,9 +189,9 @@ impl<'a, F: FftField> DenseOrSparsePolynomial<'a, F> {
             let reverted_divisor_inverse = Self::inverse_mod(&reverted_divisor, inversion_degree);
 
             // rev(q) = rev(divisor)^-1 * rev(dividend) mod X^(deg q + 1)
-            let reverted_q = DensePolynomial::from_coefficients_slice(
-                &(&reverted_divisor_inverse * &reverted_dividend).coeffs[..inversion_degree],
-            );
+            let mut reverted_q = &reverted_divisor_inverse * &reverted_dividend;
+            reverted_q.coeffs.truncate(inversion_degree);
+            let reverted_q = DensePolynomial::from_coefficients_vec(reverted_q.coeffs);
             let q = DensePolynomial::from_coefficients_slice(
                 &Self::reverse_coeffs(&reverted_q, inversion_degree)
                     .coeffs
@@ -276,8 +278,10 @@ impl<'a, F: FftField> DenseOrSparsePolynomial<'a, F> {
             );
         }
 
+        // `poly` may have fewer than `max_degree` coefficients, in which case
+        // the reversed coefficients start with zeros.
         let mut rev_coeffs = vec![F::zero(); max_degree];
-        rev_coeffs[..poly.coeffs.len()].clone_from_slice(
+        rev_coeffs[max_degree - poly.coeffs.len()..].clone_from_slice(
             &poly
                 .coeffs
                 .clone()
@@ -0,0 +1,512 @@
+//! Reed–Solomon codes over the evaluation domains of this crate.
+//!
+//! A message of length `k` is identified with a polynomial of degree less than
+//! `k`, and its codeword is the list of evaluations of that polynomial over a
+//! domain of size `n >= k`. Any two codewords differ in at least `n - k + 1`
+//! positions, so a codeword can be recovered from `s` erasures and `e` errors
+//! as long as `s + 2 * e <= n - k`.
+
+use crate::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain, Polynomial,
+};
+use ark_ff::{FftField, Zero};
+use ark_std::{vec, vec::*};
+
+/// Below this number of roots, vanishing polynomials are computed with
+/// schoolbook multiplication.
+const NAIVE_VANISHING_POLYNOMIAL_THRESHOLD: usize = 1 << 5;
+
+/// A Reed–Solomon code of dimension `message_len` whose codewords are
+/// evaluations over the domain `D`.
+///
+/// Messages can be encoded in two ways:
+/// * non-systematically, where the message holds the coefficients of the
+///   encoded polynomial, with [`Self::encode`];
+/// * systematically, where the message holds the evaluations of the encoded
+///   polynomial at `message_len` elements of the domain, so that the message
+///   appears verbatim in the codeword at the positions given by
+///   [`Self::systematic_index`], with [`Self::encode_systematic`]. These are
+///   the elements of the subdomain of size `message_len` if `D` has one, and
+///   the first `message_len` elements of the domain otherwise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ReedSolomonCode<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
+    /// The length of messages.
+    message_len: usize,
+    /// The subdomain of size `message_len` over which messages are evaluated
+    /// in systematic encoding, if there is one.
+    message_domain: Option<D>,
+    /// The domain over which codewords are evaluated.
+    domain: D,
+    #[doc(hidden)]
+    _field: ark_std::marker::PhantomData<F>,
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> ReedSolomonCode<F, D> {
+    /// Constructs a code with messages of length `message_len` and codewords
+    /// of length at least `codeword_len`, evaluated over the smallest domain
+    /// of type `D` of that size.
+    ///
+    /// Returns `None` if `D` has no domain of size at least `codeword_len`,
+    /// or if `message_len` is zero or larger than the size of that domain.
+    pub fn new(message_len: usize, codeword_len: usize) -> Option<Self> {
+        Self::from_domain(message_len, D::new(codeword_len)?)
+    }
+
+    /// Constructs a code with messages of length `message_len` and codewords
+    /// evaluated over `domain`, which may be a coset.
+    ///
+    /// Returns `None` if `message_len` is zero or larger than the size of
+    /// `domain`.
+    pub fn from_domain(message_len: usize, domain: D) -> Option<Self> {
+        if message_len == 0 || message_len > domain.size() {
+            return None;
+        }
+        let message_domain = D::new(message_len)
+            .filter(|message_domain| {
+                message_domain.size() == message_len
+                    && domain.size() % message_len == 0
+                    && domain
+                        .group_gen()
+                        .pow([(domain.size() / message_len) as u64])
+                        == message_domain.group_gen()
+            })
+            .and_then(|message_domain| message_domain.get_coset(domain.coset_offset()));
+        Some(Self {
+            message_len,
+            message_domain,
+            domain,
+            _field: ark_std::marker::PhantomData,
+        })
+    }
+
+    /// The length `k` of messages, i.e. the dimension of the code.
+    pub const fn message_len(&self) -> usize {
+        self.message_len
+    }
+
+    /// The length `n` of codewords.
+    pub fn codeword_len(&self) -> usize {
+        self.domain.size()
+    }
+
+    /// The domain over which codewords are evaluated.
+    pub const fn domain(&self) -> D {
+        self.domain
+    }
+
+    /// The subdomain over which messages are evaluated in systematic
+    /// encoding, or `None` if `D` has no subdomain of size `message_len`, in
+    /// which case messages are evaluated at the first `message_len` elements
+    /// of the domain.
+    pub const fn message_domain(&self) -> Option<D> {
+        self.message_domain
+    }
+
+    /// The minimum distance `n - k + 1` of the code.
+    pub fn minimum_distance(&self) -> usize {
+        self.codeword_len() - self.message_len() + 1
+    }
+
+    /// The maximum number of errors that can be corrected in the absence of
+    /// erasures, i.e. `(n - k) / 2`.
+    pub fn unique_decoding_radius(&self) -> usize {
+        (self.codeword_len() - self.message_len()) / 2
+    }
+
+    /// Returns the position in the codeword of the `i`-th message element in
+    /// systematic encoding.
+    pub fn systematic_index(&self, i: usize) -> usize {
+        assert!(i < self.message_len());
+        match self.message_domain {
+            Some(message_domain) => self.domain.reindex_by_subdomain(message_domain, i),
+            None => i,
+        }
+    }
+
+    /// Encodes `message` as the evaluations of the polynomial whose
+    /// coefficients are the elements of `message`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` does not have length `self.message_len()`.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        assert_eq!(message.len(), self.message_len(), "invalid message length");
+        self.domain.fft(message)
+    }
+
+    /// Encodes `message` as the evaluations of the polynomial of degree less
+    /// than `self.message_len()` such that the `i`-th element of `message` is
+    /// the `self.systematic_index(i)`-th element of the codeword.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `message` does not have length `self.message_len()`.
+    pub fn encode_systematic(&self, message: &[F]) -> Vec<F> {
+        assert_eq!(message.len(), self.message_len(), "invalid message length");
+        let mut coeffs = match self.message_domain {
+            Some(message_domain) => message_domain.ifft(message),
+            None => self.interpolate_prefix(message).coeffs,
+        };
+        self.domain.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Fills in the erased elements of `received`, given as `None`, under the
+    /// assumption that all other elements are correct.
+    ///
+    /// Returns `None` if more than `n - k` elements are erased, or if the
+    /// remaining elements are not consistent with any codeword.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `received` does not have length `self.codeword_len()`.
+    pub fn recover_erasures(&self, received: &[Option<F>]) -> Option<Vec<F>> {
+        let (erasure_locator, numerator) = self.remove_erasures(received)?;
+        if numerator.degree() >= self.message_len() + erasure_locator.degree() {
+            return None;
+        }
+        let poly = divide_exact(&numerator, &erasure_locator)?;
+        Some(self.domain.fft(&poly))
+    }
+
+    /// Decodes `received` with Gao's algorithm, where erased elements are
+    /// given as `None`, and returns the encoded polynomial.
+    ///
+    /// Returns `None` if `received` is not within the unique decoding radius,
+    /// i.e. if for `s` erasures, there is no codeword which disagrees with the
+    /// remaining elements of `received` in at most `(n - k - s) / 2` positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `received` does not have length `self.codeword_len()`.
+    pub fn decode_to_polynomial(&self, received: &[Option<F>]) -> Option<DensePolynomial<F>> {
+        let n = self.codeword_len();
+        let (erasure_locator, numerator) = self.remove_erasures(received)?;
+
+        // `numerator` interpolates the evaluations of `erasure_locator * poly`
+        // over the whole domain, with the errors left in place. This is a
+        // codeword of a code of dimension `k + s`.
+        let k = self.message_len() + erasure_locator.degree();
+        let numerator = if numerator.degree() < k {
+            numerator
+        } else {
+            // Gao's algorithm: the first remainder `g` in the Euclidean
+            // algorithm on `(Z, numerator)`, where `Z` is the vanishing
+            // polynomial of the domain, of degree less than `(n + k) / 2` is
+            // `g = v * erasure_locator * poly` for the error locator `v`.
+            let vanishing_poly: DensePolynomial<F> = self.domain.vanishing_polynomial().into();
+            let (g, v) = vanishing_poly.partial_xgcd(&numerator, (n + k).div_ceil(2));
+            let numerator = divide_exact(&g, &v)?;
+            if numerator.degree() >= k {
+                return None;
+            }
+            numerator
+        };
+        let poly = divide_exact(&numerator, &erasure_locator)?;
+
+        // Beyond the unique decoding radius, Gao's algorithm may return a
+        // codeword which is too far from `received`.
+        let errors = self
+            .domain
+            .fft(&poly)
+            .iter()
+            .zip(received)
+            .filter(|(c, r)| r.is_some_and(|r| r != **c))
+            .count();
+        (2 * errors <= n - k).then_some(poly)
+    }
+
+    /// Decodes `received`, where erased elements are given as `None`, and
+    /// returns the message in non-systematic encoding. See
+    /// [`Self::decode_to_polynomial`].
+    pub fn decode(&self, received: &[Option<F>]) -> Option<Vec<F>> {
+        let mut message = self.decode_to_polynomial(received)?.coeffs;
+        message.resize(self.message_len(), F::zero());
+        Some(message)
+    }
+
+    /// Decodes `received`, where erased elements are given as `None`, and
+    /// returns the message in systematic encoding. See
+    /// [`Self::decode_to_polynomial`].
+    pub fn decode_systematic(&self, received: &[Option<F>]) -> Option<Vec<F>> {
+        let poly = self.decode_to_polynomial(received)?;
+        Some(match self.message_domain {
+            Some(message_domain) => message_domain.fft(&poly),
+            None => {
+                let mut evals = self.domain.fft(&poly);
+                evals.truncate(self.message_len());
+                evals
+            },
+        })
+    }
+
+    /// Returns the polynomial of degree less than `values.len()` which takes
+    /// the values `values` at the first `values.len()` elements of the domain.
+    ///
+    /// Following "Modern Computer Algebra", 3rd edition, section 10.3, this is
+    /// `sum_i values[i] / Z'(x_i) * Z / (x - x_i)` for the vanishing
+    /// polynomial `Z` of these elements.
+    fn interpolate_prefix(&self, values: &[F]) -> DensePolynomial<F> {
+        let points: Vec<F> = self.domain.elements().take(values.len()).collect();
+        let vanishing_poly = vanishing_polynomial(&points);
+        let mut weights = self.domain.fft(&vanishing_poly.derivative());
+        weights.truncate(values.len());
+        ark_ff::batch_inversion(&mut weights);
+        weights.iter_mut().zip(values).for_each(|(w, v)| *w *= v);
+        linear_combination(&points, &weights).1
+    }
+
+    /// Returns the erasure locator `E`, i.e. the polynomial vanishing on the
+    /// domain elements at the erased positions, together with the polynomial
+    /// of degree less than `n` which takes the values `E(x_i) * received[i]`
+    /// over the domain, where erased values are replaced by zero.
+    fn remove_erasures(
+        &self,
+        received: &[Option<F>],
+    ) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
+        assert_eq!(
+            received.len(),
+            self.codeword_len(),
+            "invalid codeword length"
+        );
+        let erased: Vec<F> = received
+            .iter()
+            .zip(self.domain.elements())
+            .filter_map(|(r, x)| r.is_none().then_some(x))
+            .collect();
+        if erased.len() > self.codeword_len() - self.message_len() {
+            return None;
+        }
+        let erasure_locator = vanishing_polynomial(&erased);
+        let mut evals = self.domain.fft(&erasure_locator);
+        evals
+            .iter_mut()
+            .zip(received)
+            .for_each(|(e, r)| *e *= r.unwrap_or_else(F::zero));
+        self.domain.ifft_in_place(&mut evals);
+        Some((
+            erasure_locator,
+            DensePolynomial::from_coefficients_vec(evals),
+        ))
+    }
+}
+
+/// Returns `prod_i (x - roots[i])`, computed with a product tree.
+fn vanishing_polynomial<F: FftField>(roots: &[F]) -> DensePolynomial<F> {
+    if roots.len() <= NAIVE_VANISHING_POLYNOMIAL_THRESHOLD {
+        roots.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![F::one()]),
+            |acc, root| {
+                acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![
+                    -*root,
+                    F::one(),
+                ]))
+            },
+        )
+    } else {
+        let (left, right) = roots.split_at(roots.len() / 2);
+        &vanishing_polynomial(left) * &vanishing_polynomial(right)
+    }
+}
+
+/// Returns `prod_i (x - roots[i])` and `sum_i weights[i] * prod_{j != i} (x -
+/// roots[j])`, computed with a product tree.
+fn linear_combination<F: FftField>(
+    roots: &[F],
+    weights: &[F],
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    if roots.len() == 1 {
+        return (
+            DensePolynomial::from_coefficients_vec(vec![-roots[0], F::one()]),
+            DensePolynomial::from_coefficients_vec(vec![weights[0]]),
+        );
+    }
+    let mid = roots.len() / 2;
+    let (left, left_sum) = linear_combination(&roots[..mid], &weights[..mid]);
+    let (right, right_sum) = linear_combination(&roots[mid..], &weights[mid..]);
+    (
+        &left * &right,
+        &(&left_sum * &right) + &(&right_sum * &left),
+    )
+}
+
+/// Returns `a / b` if `b` divides `a`.
+fn divide_exact<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> Option<DensePolynomial<F>> {
+    let (q, r) =
+        DenseOrSparsePolynomial::from(a).divide_with_q_and_r(&DenseOrSparsePolynomial::from(b))?;
+    r.is_zero().then_some(q)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        reed_solomon::ReedSolomonCode, EvaluationDomain, GeneralEvaluationDomain,
+        MixedRadixEvaluationDomain, Radix2EvaluationDomain,
+    };
+    use ark_ff::{FftField, Field, UniformRand, Zero};
+    use ark_std::{rand::Rng, test_rng, vec, vec::*};
+    use ark_test_curves::{bls12_381::Fr, bn384_small_two_adicity::Fq as BNFq};
+
+    fn random_message<F: FftField, R: Rng>(len: usize, rng: &mut R) -> Vec<F> {
+        (0..len).map(|_| F::rand(rng)).collect()
+    }
+
+    /// Erases `num_erasures` and corrupts `num_errors` random distinct
+    /// positions of `codeword`.
+    fn corrupt<F: FftField, R: Rng>(
+        codeword: &[F],
+        num_erasures: usize,
+        num_errors: usize,
+        rng: &mut R,
+    ) -> Vec<Option<F>> {
+        let mut received: Vec<Option<F>> = codeword.iter().copied().map(Some).collect();
+        let mut positions: Vec<usize> = (0..codeword.len()).collect();
+        for i in 0..num_erasures + num_errors {
+            let j = rng.gen_range(i..positions.len());
+            positions.swap(i, j);
+        }
+        for &i in &positions[..num_erasures] {
+            received[i] = None;
+        }
+        for &i in &positions[num_erasures..num_erasures + num_errors] {
+            received[i] = Some(received[i].unwrap() + F::rand(rng));
+        }
+        received
+    }
+
+    /// Checks encoding and decoding with random corruptions within and beyond
+    /// the unique decoding radius.
+    fn check_code<F: FftField, D: EvaluationDomain<F>>(code: &ReedSolomonCode<F, D>) {
+        let rng = &mut test_rng();
+        let (n, k) = (code.codeword_len(), code.message_len());
+        for _ in 0..10 {
+            let message = random_message::<F, _>(k, rng);
+            let codeword = code.encode(&message);
+            let systematic_codeword = code.encode_systematic(&message);
+            for (i, m) in message.iter().enumerate() {
+                assert_eq!(systematic_codeword[code.systematic_index(i)], *m);
+            }
+
+            let num_erasures = rng.gen_range(0..=n - k);
+            let num_errors = rng.gen_range(0..=(n - k - num_erasures) / 2);
+            let received = corrupt(&codeword, num_erasures, num_errors, rng);
+            assert_eq!(code.decode(&received).unwrap(), message);
+            let received = corrupt(&systematic_codeword, num_erasures, num_errors, rng);
+            assert_eq!(code.decode_systematic(&received).unwrap(), message);
+
+            let received = corrupt(&codeword, num_erasures, 0, rng);
+            assert_eq!(code.recover_erasures(&received).unwrap(), codeword);
+
+            // Too many erasures.
+            let received = corrupt(&codeword, n - k + 1, 0, rng);
+            assert!(code.recover_erasures(&received).is_none());
+            assert!(code.decode(&received).is_none());
+
+            // A random word is far from every codeword with high probability.
+            if n > k {
+                let received: Vec<_> = random_message::<F, _>(n, rng)
+                    .into_iter()
+                    .map(Some)
+                    .collect();
+                assert!(code.decode(&received).is_none());
+                let received = corrupt(&codeword, n - k - 1, 1, rng);
+                assert!(code.recover_erasures(&received).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn radix2_code() {
+        for (k, n) in [
+            (1, 1),
+            (1, 8),
+            (4, 4),
+            (4, 16),
+            (32, 64),
+            (64, 256),
+            (128, 256),
+        ] {
+            let code = ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::new(k, n).unwrap();
+            assert_eq!(code.codeword_len(), n);
+            check_code(&code);
+        }
+    }
+
+    #[test]
+    fn message_len_without_subdomain() {
+        // There is no radix-2 domain of size 3, 5 or 100, and 4 does not
+        // divide 6, so messages are evaluated at the first elements of the
+        // domain in systematic encoding.
+        for (k, n) in [(3, 8), (5, 16), (7, 8), (100, 256)] {
+            let code = ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::new(k, n).unwrap();
+            assert!(code.message_domain().is_none());
+            assert_eq!(code.systematic_index(k - 1), k - 1);
+            check_code(&code);
+        }
+        let code = ReedSolomonCode::<BNFq, MixedRadixEvaluationDomain<BNFq>>::new(4, 6).unwrap();
+        assert!(code.message_domain().is_none());
+        check_code(&code);
+
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::new_coset(64, Fr::rand(rng)).unwrap();
+        let code = ReedSolomonCode::from_domain(20, domain).unwrap();
+        check_code(&code);
+    }
+
+    #[test]
+    fn decode_structured_errors() {
+        // Errors of equal value along a subgroup of the domain, or following a
+        // monomial, make the interpolated received word sparse, so that the
+        // remainders of the Euclidean algorithm in Gao's algorithm are sparse
+        // as well.
+        let rng = &mut test_rng();
+        let (n, k) = (512, 128);
+        let code = ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::new(k, n).unwrap();
+        let domain = code.domain();
+        for message in [vec![Fr::zero(); k], random_message(k, rng)] {
+            let codeword = code.encode(&message);
+            for (num_errors, exponent) in [(64, 0), (128, 0), (128, 3), (128, 200), (32, 100)] {
+                let step = n / num_errors;
+                let value = Fr::rand(rng);
+                let mut received: Vec<_> = codeword.iter().copied().map(Some).collect();
+                for i in (0..n).step_by(step) {
+                    let error = value * domain.element(i).pow([exponent]);
+                    received[i] = Some(codeword[i] + error);
+                }
+                assert_eq!(code.decode(&received).unwrap(), message);
+            }
+        }
+    }
+
+    #[test]
+    fn coset_code() {
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::new_coset(128, Fr::rand(rng)).unwrap();
+        let code = ReedSolomonCode::from_domain(32, domain).unwrap();
+        check_code(&code);
+    }
+
+    #[test]
+    fn mixed_radix_code() {
+        for (k, n) in [(2, 6), (6, 18), (9, 36), (16, 48)] {
+            let code =
+                ReedSolomonCode::<BNFq, MixedRadixEvaluationDomain<BNFq>>::new(k, n).unwrap();
+            assert_eq!(code.codeword_len(), n);
+            check_code(&code);
+        }
+        let code = ReedSolomonCode::<BNFq, GeneralEvaluationDomain<BNFq>>::new(4, 12).unwrap();
+        check_code(&code);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert!(ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::new(0, 8).is_none());
+        // Messages must not be longer than codewords.
+        assert!(ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::new(16, 8).is_none());
+        assert!(ReedSolomonCode::<BNFq, MixedRadixEvaluationDomain<BNFq>>::new(7, 6).is_none());
+    }
+}
@@ -0,0 +1,318 @@
+//! The sumcheck protocol for sums of products of multilinear extensions.
+//!
+//! The prover convinces the verifier that a polynomial `g` in `n` variables
+//! sums to a claimed value `s` over the hypercube {0,1}^`n`. In round `i`, the
+//! prover sends the univariate polynomial
+//! `g_i(t) = sum_{x} g(r_1, ..., r_{i-1}, t, x)`, the verifier checks that
+//! `g_i(0) + g_i(1)` equals the previous claim, and replies with a random
+//! challenge `r_i`, so that the new claim is `g_i(r_i)`. After `n` rounds,
+//! the verifier is left with checking that `g(r_1, ..., r_n)` equals the last
+//! claim, see [`SubClaim`].
+//!
+//! Variables are fixed from the first to the last, consistently with
+//! [`crate::MultilinearExtension::fix_variables`]. Challenges are drawn from a
+//! [`ChallengeSource`], so that any transcript or Fiat–Shamir construction can
+//! be used.
+
+mod prover;
+mod verifier;
+mod virtual_polynomial;
+
+pub use prover::SumcheckProver;
+pub use verifier::{SubClaim, SumcheckVerifier};
+pub use virtual_polynomial::VirtualPolynomial;
+
+use crate::{univariate::DensePolynomial, DenseUVPolynomial};
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{vec, vec::*};
+
+/// The message of the prover in a round of the sumcheck protocol: the
+/// evaluations of the round polynomial at `0, 1, ..., degree`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct RoundMessage<F: Field> {
+    /// The evaluations of the round polynomial at `0, 1, ..., degree`.
+    pub evaluations: Vec<F>,
+}
+
+impl<F: Field> RoundMessage<F> {
+    /// Evaluates the round polynomial at `point` by Lagrange interpolation.
+    pub fn evaluate(&self, point: F) -> F {
+        let d = self.evaluations.len() - 1;
+        let xs: Vec<F> = (0..=d as u64).map(|j| point - F::from(j)).collect();
+        // `prefix[i] = prod_{j < i} (point - j)`, `suffix[i] = prod_{j > i} (point - j)`
+        let mut prefix = vec![F::one(); d + 1];
+        let mut suffix = vec![F::one(); d + 1];
+        for i in 1..=d {
+            prefix[i] = prefix[i - 1] * xs[i - 1];
+            suffix[d - i] = suffix[d - i + 1] * xs[d - i + 1];
+        }
+        self.evaluations
+            .iter()
+            .zip(lagrange_denominator_inverses::<F>(d))
+            .enumerate()
+            .map(|(i, (e, w))| *e * w * prefix[i] * suffix[i])
+            .sum()
+    }
+
+    /// Returns the round polynomial in coefficient form.
+    pub fn to_polynomial(&self) -> DensePolynomial<F> {
+        let d = self.evaluations.len() - 1;
+        // `vanishing = prod_{j <= d} (x - j)`
+        let mut vanishing = vec![F::one()];
+        for j in 0..=d as u64 {
+            vanishing.insert(0, F::zero());
+            let j = F::from(j);
+            for k in 0..vanishing.len() - 1 {
+                let next = vanishing[k + 1];
+                vanishing[k] -= j * next;
+            }
+        }
+        let mut coeffs = vec![F::zero(); d + 1];
+        for (i, (e, w)) in self
+            .evaluations
+            .iter()
+            .zip(lagrange_denominator_inverses::<F>(d))
+            .enumerate()
+        {
+            // Divides `vanishing` by `x - i` with synthetic division.
+            let i = F::from(i as u64);
+            let scale = *e * w;
+            let mut carry = F::zero();
+            for k in (0..=d).rev() {
+                carry = vanishing[k + 1] + i * carry;
+                coeffs[k] += scale * carry;
+            }
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+}
+
+/// Returns `1 / prod_{j != i} (i - j)` for `i, j = 0, ..., d`.
+fn lagrange_denominator_inverses<F: Field>(d: usize) -> Vec<F> {
+    // prod_{j != i} (i - j) = i! * (d - i)! * (-1)^{d - i}
+    let mut factorials = vec![F::one(); d + 1];
+    for i in 1..=d {
+        factorials[i] = factorials[i - 1] * F::from(i as u64);
+    }
+    let mut denominators: Vec<F> = (0..=d)
+        .map(|i| {
+            let denominator = factorials[i] * factorials[d - i];
+            if (d - i) % 2 == 1 {
+                -denominator
+            } else {
+                denominator
+            }
+        })
+        .collect();
+    ark_ff::batch_inversion(&mut denominators);
+    denominators
+}
+
+/// A source of verifier challenges, e.g. a Fiat–Shamir transcript, which
+/// absorbs each round message before producing the challenge of that round.
+pub trait ChallengeSource<F: Field> {
+    /// Absorbs `message` and returns the challenge for the current round.
+    fn challenge(&mut self, message: &RoundMessage<F>) -> F;
+}
+
+impl<F: Field, C: FnMut(&RoundMessage<F>) -> F> ChallengeSource<F> for C {
+    fn challenge(&mut self, message: &RoundMessage<F>) -> F {
+        self(message)
+    }
+}
+
+/// A non-interactive sumcheck proof, consisting of the round messages.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SumcheckProof<F: Field> {
+    /// The messages of the prover, one per variable.
+    pub round_messages: Vec<RoundMessage<F>>,
+}
+
+/// Runs the prover on `poly` with challenges from `challenges`, and returns the
+/// proof together with the final prover state, from which the challenges and
+/// the evaluations of the multilinear extensions at the challenges can be read.
+pub fn prove<F: Field>(
+    poly: VirtualPolynomial<F>,
+    challenges: &mut impl ChallengeSource<F>,
+) -> (SumcheckProof<F>, SumcheckProver<F>) {
+    let mut prover = SumcheckProver::new(poly);
+    let mut round_messages = Vec::with_capacity(prover.remaining_rounds());
+    while prover.remaining_rounds() > 0 {
+        let message = prover.round_message();
+        prover.receive_challenge(challenges.challenge(&message));
+        round_messages.push(message);
+    }
+    (SumcheckProof { round_messages }, prover)
+}
+
+/// Verifies `proof` for a polynomial in `num_vars` variables of degree at most
+/// `degree` in each variable summing to `claimed_sum`, with challenges from
+/// `challenges`. Returns the claim left to check, or `None` if the proof is
+/// rejected.
+pub fn verify<F: Field>(
+    num_vars: usize,
+    degree: usize,
+    claimed_sum: F,
+    proof: &SumcheckProof<F>,
+    challenges: &mut impl ChallengeSource<F>,
+) -> Option<SubClaim<F>> {
+    if proof.round_messages.len() != num_vars {
+        return None;
+    }
+    let mut verifier = SumcheckVerifier::new(num_vars, degree, claimed_sum);
+    for message in &proof.round_messages {
+        if !verifier.verify_round(message, challenges.challenge(message)) {
+            return None;
+        }
+    }
+    Some(verifier.into_subclaim())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sumcheck::{prove, verify, RoundMessage, SumcheckProof, VirtualPolynomial},
+        univariate::DensePolynomial,
+        DenseMultilinearExtension, DenseUVPolynomial, MultilinearExtension, Polynomial,
+    };
+    use ark_ff::{One, UniformRand};
+    use ark_std::{rand::Rng, test_rng, vec, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Returns a random virtual polynomial with `num_products` products of
+    /// degree at most `max_degree`, whose factors are drawn from `num_mles`
+    /// multilinear extensions.
+    fn random_virtual_polynomial<R: Rng>(
+        num_vars: usize,
+        num_mles: usize,
+        num_products: usize,
+        max_degree: usize,
+        rng: &mut R,
+    ) -> VirtualPolynomial<Fr> {
+        let mut poly = VirtualPolynomial::new(num_vars);
+        for _ in 0..num_mles {
+            poly.add_mle(DenseMultilinearExtension::rand(num_vars, rng));
+        }
+        for _ in 0..num_products {
+            let degree = rng.gen_range(1..=max_degree);
+            let indices: Vec<usize> = (0..degree).map(|_| rng.gen_range(0..num_mles)).collect();
+            poly.add_product(Fr::rand(rng), &indices);
+        }
+        poly
+    }
+
+    /// Evaluates `poly` at a point whose first coordinate is `t` and whose
+    /// remaining coordinates are the bits of `x`.
+    fn evaluate_at(poly: &VirtualPolynomial<Fr>, t: Fr, x: usize) -> Fr {
+        let mut point = vec![t];
+        point.extend((0..poly.num_vars - 1).map(|i| Fr::from(((x >> i) & 1) as u64)));
+        poly.evaluate(&point)
+    }
+
+    #[test]
+    fn round_message_interpolation() {
+        let rng = &mut test_rng();
+        for degree in 1..6 {
+            let poly = DensePolynomial::<Fr>::rand(degree, rng);
+            let message = RoundMessage {
+                evaluations: (0..=degree as u64)
+                    .map(|i| poly.evaluate(&Fr::from(i)))
+                    .collect(),
+            };
+            assert_eq!(message.to_polynomial(), poly);
+            let point = Fr::rand(rng);
+            assert_eq!(message.evaluate(point), poly.evaluate(&point));
+            assert_eq!(
+                message.evaluate(Fr::from(2u64)),
+                poly.evaluate(&Fr::from(2u64))
+            );
+        }
+    }
+
+    #[test]
+    fn sum_and_round_messages_match_brute_force() {
+        let rng = &mut test_rng();
+        let poly = random_virtual_polynomial(4, 3, 4, 3, rng);
+        let expected: Fr = (0..1 << 4)
+            .map(|x| evaluate_at(&poly, Fr::from((x & 1) as u64), x >> 1))
+            .sum();
+        assert_eq!(poly.sum_over_hypercube(), expected);
+
+        let (proof, _) = prove(poly.clone(), &mut |_: &RoundMessage<Fr>| Fr::rand(rng));
+        let message = &proof.round_messages[0];
+        for t in 0..=poly.degree() as u64 {
+            let t = Fr::from(t);
+            let expected: Fr = (0..1 << 3).map(|x| evaluate_at(&poly, t, x)).sum();
+            assert_eq!(message.evaluate(t), expected);
+        }
+    }
+
+    #[test]
+    fn completeness() {
+        let rng = &mut test_rng();
+        for (num_vars, num_mles, num_products, max_degree) in
+            [(1, 1, 1, 1), (5, 4, 3, 2), (8, 5, 5, 4), (10, 2, 1, 2)]
+        {
+            let poly = random_virtual_polynomial(num_vars, num_mles, num_products, max_degree, rng);
+            let sum = poly.sum_over_hypercube();
+
+            // Both parties derive the same challenges from the same seed.
+            let mut prover_rng = test_rng();
+            let (proof, prover) = prove(poly.clone(), &mut |_: &RoundMessage<Fr>| {
+                Fr::rand(&mut prover_rng)
+            });
+            let mut verifier_rng = test_rng();
+            let subclaim = verify(
+                num_vars,
+                poly.degree(),
+                sum,
+                &proof,
+                &mut |_: &RoundMessage<Fr>| Fr::rand(&mut verifier_rng),
+            )
+            .unwrap();
+
+            assert_eq!(subclaim.point, prover.challenges());
+            assert_eq!(subclaim.expected_evaluation, poly.evaluate(&subclaim.point));
+            let final_evals = prover.final_mle_evaluations();
+            assert_eq!(
+                subclaim.expected_evaluation,
+                poly.combine(|i| final_evals[i])
+            );
+        }
+    }
+
+    #[test]
+    fn soundness() {
+        let rng = &mut test_rng();
+        let poly = random_virtual_polynomial(6, 3, 3, 3, rng);
+        let sum = poly.sum_over_hypercube();
+        let (proof, _) = prove(poly.clone(), &mut |_: &RoundMessage<Fr>| Fr::rand(rng));
+        let verify = |sum: Fr, proof: &SumcheckProof<Fr>| {
+            let mut rng = test_rng();
+            verify(6, poly.degree(), sum, proof, &mut |_: &RoundMessage<Fr>| {
+                Fr::rand(&mut rng)
+            })
+        };
+
+        // A wrong sum is rejected in the first round.
+        assert!(verify(sum + Fr::one(), &proof).is_none());
+
+        // Tampering with any round message is detected.
+        for round in 0..6 {
+            let mut tampered = proof.clone();
+            tampered.round_messages[round].evaluations[0] += Fr::one();
+            assert!(verify(sum, &tampered).is_none());
+        }
+
+        // A round message of the wrong degree is rejected.
+        let mut tampered = proof.clone();
+        tampered.round_messages[0].evaluations.push(Fr::one());
+        assert!(verify(sum, &tampered).is_none());
+
+        // A proof with too few rounds is rejected.
+        let mut tampered = proof;
+        tampered.round_messages.pop();
+        assert!(verify(sum, &tampered).is_none());
+    }
+}
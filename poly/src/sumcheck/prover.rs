@@ -0,0 +1,74 @@
+//! The prover of the sumcheck protocol.
+
+use crate::sumcheck::{RoundMessage, VirtualPolynomial};
+use ark_ff::Field;
+use ark_std::vec::*;
+
+/// The state of the prover of the sumcheck protocol for a
+/// [`VirtualPolynomial`].
+///
+/// In each round, the prover sends the evaluations of the round polynomial
+/// with [`Self::round_message`], then fixes the first remaining variable to
+/// the verifier's challenge with [`Self::receive_challenge`].
+#[derive(Clone, Debug)]
+pub struct SumcheckProver<F: Field> {
+    poly: VirtualPolynomial<F>,
+    challenges: Vec<F>,
+}
+
+impl<F: Field> SumcheckProver<F> {
+    /// Starts the protocol for proving the sum of `poly` over the hypercube.
+    pub const fn new(poly: VirtualPolynomial<F>) -> Self {
+        Self {
+            poly,
+            challenges: Vec::new(),
+        }
+    }
+
+    /// The number of rounds left.
+    pub const fn remaining_rounds(&self) -> usize {
+        self.poly.num_vars
+    }
+
+    /// The challenges received so far.
+    pub fn challenges(&self) -> &[F] {
+        &self.challenges
+    }
+
+    /// Returns the message of the current round, i.e. the evaluations at
+    /// `0, 1, ..., degree` of the round polynomial
+    /// `g(t) = sum_{x in {0,1}^{n-1}} poly(t, x)`, where `poly` has its
+    /// variables fixed to the previous challenges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all rounds have been completed.
+    pub fn round_message(&self) -> RoundMessage<F> {
+        RoundMessage {
+            evaluations: self.poly.round_evaluations(),
+        }
+    }
+
+    /// Fixes the first remaining variable to `challenge`, moving on to the
+    /// next round.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all rounds have been completed.
+    pub fn receive_challenge(&mut self, challenge: F) {
+        assert!(self.poly.num_vars > 0, "no variables left");
+        self.poly.fix_first_variable(challenge);
+        self.challenges.push(challenge);
+    }
+
+    /// Returns the evaluations of the multilinear extensions of the virtual
+    /// polynomial at the challenges, once all rounds have been completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are rounds left.
+    pub fn final_mle_evaluations(&self) -> Vec<F> {
+        assert_eq!(self.poly.num_vars, 0, "rounds left");
+        self.poly.mles.iter().map(|mle| mle[0]).collect()
+    }
+}
@@ -0,0 +1,76 @@
+//! The verifier of the sumcheck protocol.
+
+use crate::sumcheck::RoundMessage;
+use ark_ff::Field;
+use ark_std::vec::*;
+
+/// The claim left to check by the verifier after all rounds of the sumcheck
+/// protocol: the polynomial evaluates to `expected_evaluation` at `point`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SubClaim<F: Field> {
+    /// The point made of the challenges of all rounds.
+    pub point: Vec<F>,
+    /// The claimed evaluation of the polynomial at `point`.
+    pub expected_evaluation: F,
+}
+
+/// The state of the verifier of the sumcheck protocol.
+#[derive(Clone, Debug)]
+pub struct SumcheckVerifier<F: Field> {
+    num_vars: usize,
+    degree: usize,
+    expected_sum: F,
+    challenges: Vec<F>,
+}
+
+impl<F: Field> SumcheckVerifier<F> {
+    /// Starts the protocol for checking that a polynomial in `num_vars`
+    /// variables of degree at most `degree` in each variable sums to
+    /// `claimed_sum` over the hypercube. A `degree` of zero is treated as
+    /// one, as round messages always consist of at least two evaluations.
+    pub fn new(num_vars: usize, degree: usize, claimed_sum: F) -> Self {
+        Self {
+            num_vars,
+            degree: degree.max(1),
+            expected_sum: claimed_sum,
+            challenges: Vec::new(),
+        }
+    }
+
+    /// The number of rounds left.
+    pub fn remaining_rounds(&self) -> usize {
+        self.num_vars - self.challenges.len()
+    }
+
+    /// Checks `message` against the claim of the previous round, and, if it
+    /// is consistent, records `challenge` as the challenge of this round.
+    /// Returns `false` if `message` is rejected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all rounds have been completed.
+    pub fn verify_round(&mut self, message: &RoundMessage<F>, challenge: F) -> bool {
+        assert!(self.remaining_rounds() > 0, "no rounds left");
+        let evals = &message.evaluations;
+        if evals.len() != self.degree + 1 || evals[0] + evals[1] != self.expected_sum {
+            return false;
+        }
+        self.expected_sum = message.evaluate(challenge);
+        self.challenges.push(challenge);
+        true
+    }
+
+    /// Returns the claim to be checked with an evaluation of the polynomial
+    /// once all rounds have been completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are rounds left.
+    pub fn into_subclaim(self) -> SubClaim<F> {
+        assert_eq!(self.remaining_rounds(), 0, "rounds left");
+        SubClaim {
+            point: self.challenges,
+            expected_evaluation: self.expected_sum,
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Sums of products of multilinear extensions.
+
+use crate::{DenseMultilinearExtension, Polynomial};
+use ark_ff::Field;
+use ark_std::{cfg_into_iter, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A polynomial of the form `sum_j c_j * prod_{i in S_j} f_i`, where the
+/// `f_i` are multilinear extensions in the same number of variables, as used
+/// in HyperPlonk.
+///
+/// Each multilinear extension is stored once, and products refer to it by the
+/// index returned by [`Self::add_mle`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VirtualPolynomial<F: Field> {
+    /// The number of variables of every multilinear extension.
+    pub num_vars: usize,
+    /// The multilinear extensions appearing in the products.
+    pub mles: Vec<DenseMultilinearExtension<F>>,
+    /// The products, as pairs of a coefficient and the indices of the factors
+    /// in `mles`.
+    pub products: Vec<(F, Vec<usize>)>,
+}
+
+impl<F: Field> VirtualPolynomial<F> {
+    /// Returns the zero polynomial in `num_vars` variables.
+    pub const fn new(num_vars: usize) -> Self {
+        Self {
+            num_vars,
+            mles: Vec::new(),
+            products: Vec::new(),
+        }
+    }
+
+    /// Adds `mle` to the list of multilinear extensions without adding any
+    /// term, and returns its index.
+    pub fn add_mle(&mut self, mle: DenseMultilinearExtension<F>) -> usize {
+        assert_eq!(mle.num_vars, self.num_vars, "invalid number of variables");
+        self.mles.push(mle);
+        self.mles.len() - 1
+    }
+
+    /// Adds the term `coefficient * prod_{i in indices} mles[i]`.
+    pub fn add_product(&mut self, coefficient: F, indices: &[usize]) {
+        assert!(!indices.is_empty(), "empty products are not supported");
+        assert!(
+            indices.iter().all(|i| *i < self.mles.len()),
+            "invalid multilinear extension index"
+        );
+        self.products.push((coefficient, indices.to_vec()));
+    }
+
+    /// Adds the term `coefficient * prod_i mles[i]` for new multilinear
+    /// extensions `mles`.
+    pub fn add_mle_product(
+        &mut self,
+        coefficient: F,
+        mles: impl IntoIterator<Item = DenseMultilinearExtension<F>>,
+    ) {
+        let indices: Vec<usize> = mles.into_iter().map(|mle| self.add_mle(mle)).collect();
+        self.add_product(coefficient, &indices);
+    }
+
+    /// The degree of `self` in each variable, i.e. the maximum number of
+    /// factors of a product.
+    pub fn degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates `self` at `point`.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "invalid size of point");
+        let point = point.to_vec();
+        let evals: Vec<F> = self.mles.iter().map(|mle| mle.evaluate(&point)).collect();
+        self.combine(|i| evals[i])
+    }
+
+    /// Returns the sum of the evaluations of `self` over the hypercube.
+    pub fn sum_over_hypercube(&self) -> F {
+        cfg_into_iter!(0..1usize << self.num_vars)
+            .map(|x| self.combine(|i| self.mles[i][x]))
+            .sum()
+    }
+
+    /// Combines the values `eval(i)` of the multilinear extensions according
+    /// to the products.
+    pub(crate) fn combine(&self, eval: impl Fn(usize) -> F) -> F {
+        self.products
+            .iter()
+            .map(|(coefficient, indices)| {
+                indices
+                    .iter()
+                    .fold(*coefficient, |product, i| product * eval(*i))
+            })
+            .sum()
+    }
+
+    /// Fixes the first variable of every multilinear extension to `r`.
+    pub(crate) fn fix_first_variable(&mut self, r: F) {
+        self.mles
+            .iter_mut()
+            .for_each(|mle| mle.fix_variables_in_place(&[r]));
+        self.num_vars -= 1;
+    }
+
+    /// Returns `sum_{x in {0,1}^{n-1}} self(t, x)` for `t = 0, 1, ..., d`,
+    /// where `d` is the degree of `self`, or `1` if `self` has no products.
+    pub(crate) fn round_evaluations(&self) -> Vec<F> {
+        assert!(self.num_vars > 0, "no variables left");
+        let degree = self.degree().max(1);
+        let half = 1usize << (self.num_vars - 1);
+        let ts: Vec<F> = (0..=degree as u64).map(F::from).collect();
+        ts.iter()
+            .map(|t| {
+                cfg_into_iter!(0..half)
+                    .map(|x| {
+                        self.combine(|i| {
+                            let (lo, hi) = (self.mles[i][x << 1], self.mles[i][(x << 1) + 1]);
+                            lo + *t * (hi - lo)
+                        })
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
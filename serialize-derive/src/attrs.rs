@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Field, LitStr, Path};
+
+/// The `#[ark(...)]` attributes of a field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// `#[ark(skip)]`: the field is neither serialized nor checked, and is
+    /// deserialized as `Default::default()`, or as `default()` with
+    /// `#[ark(skip, default = "path")]`.
+    pub(crate) skip: bool,
+    pub(crate) default: Option<Path>,
+    /// `#[ark(compress = "yes" | "no")]`: the field is always (de)serialized
+    /// in the given mode.
+    pub(crate) compress: Option<TokenStream>,
+    /// `#[ark(with = "module")]`: the field is (de)serialized with
+    /// `module::serialize_with_mode`, `module::serialized_size` and
+    /// `module::deserialize_with_mode`, and is not checked by `Valid`.
+    pub(crate) with: Option<Path>,
+    /// `#[ark(validate = "path")]`: `path(&field)` is called when checking
+    /// the field.
+    pub(crate) validate: Option<Path>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("ark"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                } else if meta.path.is_ident("default") {
+                    attrs.default = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("compress") {
+                    let value = meta.value()?.parse::<LitStr>()?;
+                    attrs.compress = Some(match value.value().as_str() {
+                        "yes" => quote! { ark_serialize::Compress::Yes },
+                        "no" => quote! { ark_serialize::Compress::No },
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                value,
+                                "expected `compress = \"yes\"` or `compress = \"no\"`",
+                            ))
+                        },
+                    });
+                } else if meta.path.is_ident("with") {
+                    attrs.with = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("validate") {
+                    attrs.validate = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported `ark` attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        if attrs.default.is_some() && !attrs.skip {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`default` can only be used together with `skip`",
+            ));
+        }
+        if attrs.skip
+            && (attrs.compress.is_some() || attrs.with.is_some() || attrs.validate.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "a skipped field cannot have other `ark` attributes",
+            ));
+        }
+        Ok(attrs)
+    }
+
+    /// Returns the compression mode to (de)serialize the field with.
+    pub(crate) fn compress(&self) -> TokenStream {
+        self.compress.clone().unwrap_or_else(|| quote! { compress })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::serialize::IdentOrIndex;
+use crate::{attrs::FieldAttrs, serialize::IdentOrIndex};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, Index, Type};
@@ -44,7 +44,7 @@ fn impl_valid_field(
     }
 }
 
-fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_valid(ast: &syn::DeriveInput, attrs: &[FieldAttrs]) -> TokenStream {
     let name = &ast.ident;
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -66,7 +66,7 @@ fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
         Data::Struct(ref data_struct) => {
             let mut idents = Vec::<IdentOrIndex>::new();
 
-            for (i, field) in data_struct.fields.iter().enumerate() {
+            for (i, (field, attrs)) in data_struct.fields.iter().zip(attrs).enumerate() {
                 match field.ident {
                     None => {
                         let index = Index::from(i);
@@ -77,13 +77,28 @@ fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
                     },
                 }
 
-                impl_valid_field(
-                    &mut check_body,
-                    &mut batch_body,
-                    &mut trivial_check_body,
-                    &mut idents,
-                    &field.ty,
-                );
+                // Skipped fields and fields with a custom deserializer are
+                // not checked by their type.
+                if !attrs.skip && attrs.with.is_none() {
+                    impl_valid_field(
+                        &mut check_body,
+                        &mut batch_body,
+                        &mut trivial_check_body,
+                        &mut idents,
+                        &field.ty,
+                    );
+                }
+                if let Some(validate) = &attrs.validate {
+                    trivial_check_body.push(quote! { false });
+                    check_body.push(quote! {
+                        #validate(&self.#(#idents).*)?;
+                    });
+                    batch_body.push(quote! {
+                        for v in &batch {
+                            #validate(&v.#(#idents).*)?;
+                        }
+                    });
+                }
 
                 idents.clear();
             }
@@ -104,6 +119,9 @@ fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
             #(#check_body)*
         }
     };
+    if trivial_check_body.is_empty() {
+        trivial_check_body.push(quote! { true });
+    }
 
     let gen = quote! {
         impl #impl_generics ark_serialize::Valid for #name #ty_generics #where_clause {
@@ -130,71 +148,195 @@ fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
     gen
 }
 
-/// Returns a `TokenStream` for `deserialize_with_mode`.
-/// uncompressed.
-fn impl_deserialize_field(ty: &Type) -> TokenStream {
+/// Whether a field is deserialized by `deserialize_with_mode` or
+/// `deserialize_with_limits`.
+#[derive(Clone, Copy)]
+enum Method {
+    WithMode,
+    WithLimits,
+}
+
+/// Returns a `TokenStream` deserializing a field of type `ty` with `method`.
+fn impl_deserialize_field(ty: &Type, method: Method, compress: &TokenStream) -> TokenStream {
     // Check if type is a tuple.
     match ty {
         Type::Tuple(tuple) => {
-            let compressed_fields: Vec<_> =
-                tuple.elems.iter().map(impl_deserialize_field).collect();
+            let compressed_fields: Vec<_> = tuple
+                .elems
+                .iter()
+                .map(|ty| impl_deserialize_field(ty, method, compress))
+                .collect();
             quote! { (#(#compressed_fields)*), }
         },
-        _ => {
-            quote! { CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?, }
+        _ => match method {
+            Method::WithMode => quote! {
+                CanonicalDeserialize::deserialize_with_mode(&mut reader, #compress, validate)?,
+            },
+            Method::WithLimits => quote! {
+                CanonicalDeserialize::deserialize_with_limits(&mut reader, #compress, validate, limits)?,
+            },
         },
     }
 }
 
-pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
-    let valid_impl = impl_valid(ast);
-    let name = &ast.ident;
+/// Returns a `TokenStream` deserializing a field with the given attributes.
+fn impl_deserialize_attributed_field(ty: &Type, attrs: &FieldAttrs, method: Method) -> TokenStream {
+    let compress = attrs.compress();
+    if attrs.skip {
+        match &attrs.default {
+            Some(default) => quote! { #default(), },
+            None => quote! { ::core::default::Default::default(), },
+        }
+    } else if let Some(with) = &attrs.with {
+        match method {
+            Method::WithMode => quote! {
+                #with::deserialize_with_mode(&mut reader, #compress, validate)?,
+            },
+            Method::WithLimits => quote! {
+                limits.bounded(&mut reader, |reader| {
+                    #with::deserialize_with_mode(reader, #compress, validate)
+                })?,
+            },
+        }
+    } else {
+        impl_deserialize_field(ty, method, &compress)
+    }
+}
 
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+/// Returns a `TokenStream` computing the fixed serialized size of a field of
+/// type `ty`, returning `None` from the enclosing function if it has none.
+fn impl_fixed_size_field(ty: &Type, compress: &TokenStream) -> TokenStream {
+    match ty {
+        Type::Tuple(tuple) => {
+            let sizes: Vec<_> = tuple
+                .elems
+                .iter()
+                .map(|ty| impl_fixed_size_field(ty, compress))
+                .collect();
+            quote! { 0usize #(+ #sizes)* }
+        },
+        _ => quote! {
+            <#ty as CanonicalDeserialize>::fixed_serialized_size(#compress)?
+        },
+    }
+}
 
-    let deserialize_body;
+/// Returns a `TokenStream` computing the fixed serialized size of the struct,
+/// which is the sum of those of its fields.
+fn impl_fixed_size_body(ast: &syn::DeriveInput, attrs: &[FieldAttrs]) -> TokenStream {
+    match ast.data {
+        Data::Struct(ref data_struct) => {
+            let mut sizes = Vec::with_capacity(data_struct.fields.len());
+            for (field, attrs) in data_struct.fields.iter().zip(attrs) {
+                if attrs.with.is_some() {
+                    // The size of a custom serialization is unknown.
+                    return quote! { None };
+                }
+                if !attrs.skip {
+                    sizes.push(impl_fixed_size_field(&field.ty, &attrs.compress()));
+                }
+            }
+            quote! { Some(0usize #(+ #sizes)*) }
+        },
+        _ => unreachable!(),
+    }
+}
 
+/// Returns a `TokenStream` constructing the struct from fields deserialized
+/// with `method`, and running the custom validators of its fields.
+fn impl_deserialize_body(
+    ast: &syn::DeriveInput,
+    attrs: &[FieldAttrs],
+    method: Method,
+) -> TokenStream {
+    let name = &ast.ident;
     match ast.data {
         Data::Struct(ref data_struct) => {
             let mut field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
+            let mut validators = Vec::<TokenStream>::new();
             let mut tuple = false;
-            for field in &data_struct.fields {
-                match &field.ident {
+            for (i, (field, attrs)) in data_struct.fields.iter().zip(attrs).enumerate() {
+                let compressed = impl_deserialize_attributed_field(&field.ty, attrs, method);
+                let ident = match &field.ident {
                     None => {
                         tuple = true;
-                        let compressed = impl_deserialize_field(&field.ty);
                         field_cases.push(compressed);
+                        IdentOrIndex::Index(Index::from(i))
                     },
                     // struct field without len_type
                     Some(ident) => {
-                        let compressed = impl_deserialize_field(&field.ty);
                         field_cases.push(quote! { #ident: #compressed });
+                        IdentOrIndex::Ident(ident.clone())
                     },
+                };
+                if let Some(validate) = &attrs.validate {
+                    validators.push(quote! { #validate(&value.#ident)?; });
                 }
             }
 
-            deserialize_body = if tuple {
-                quote!({
-                    Ok(#name (
+            let value = if tuple {
+                quote! {
+                    #name (
                         #(#field_cases)*
-                     ))
-                })
+                    )
+                }
             } else {
-                quote!({
-                    Ok(#name {
+                quote! {
+                    #name {
                         #(#field_cases)*
-                    })
-                })
+                    }
+                }
             };
+            if validators.is_empty() {
+                quote!({ Ok(#value) })
+            } else {
+                quote!({
+                    let value = #value;
+                    if validate == ark_serialize::Validate::Yes {
+                        #(#validators)*
+                    }
+                    Ok(value)
+                })
+            }
+        },
+        _ => panic!(
+            "`CanonicalDeserialize` can only be derived for structs, {} is not a Struct",
+            name
+        ),
+    }
+}
+
+pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+
+    let attrs = match &ast.data {
+        Data::Struct(data_struct) => {
+            match data_struct
+                .fields
+                .iter()
+                .map(FieldAttrs::parse)
+                .collect::<syn::Result<Vec<_>>>()
+            {
+                Ok(attrs) => attrs,
+                Err(err) => return err.to_compile_error(),
+            }
         },
         _ => panic!(
             "`CanonicalDeserialize` can only be derived for structs, {} is not a Struct",
             name
         ),
     };
+    let valid_impl = impl_valid(ast, &attrs);
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let deserialize_body = impl_deserialize_body(ast, &attrs, Method::WithMode);
+    let deserialize_with_limits_body = impl_deserialize_body(ast, &attrs, Method::WithLimits);
+    let fixed_size_body = impl_fixed_size_body(ast, &attrs);
 
     let mut gen = quote! {
         impl #impl_generics CanonicalDeserialize for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
             fn deserialize_with_mode<R: ark_serialize::Read>(
                 mut reader: R,
                 compress: ark_serialize::Compress,
@@ -202,6 +344,23 @@ pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream
             ) -> Result<Self, ark_serialize::SerializationError> {
                 #deserialize_body
             }
+
+            #[allow(unused_mut, unused_variables)]
+            fn deserialize_with_limits<R: ark_serialize::Read>(
+                mut reader: R,
+                compress: ark_serialize::Compress,
+                validate: ark_serialize::Validate,
+                limits: &mut ark_serialize::DeserializeLimits,
+            ) -> Result<Self, ark_serialize::SerializationError> {
+                limits.nested(|limits| #deserialize_with_limits_body)
+            }
+
+            #[allow(unused_variables)]
+            fn fixed_serialized_size(
+                compress: ark_serialize::Compress,
+            ) -> Option<usize> {
+                #fixed_size_body
+            }
         }
     };
     gen.extend(valid_impl);
@@ -9,19 +9,21 @@
 
 use syn::{parse_macro_input, DeriveInput};
 
+mod attrs;
+
 mod serialize;
 use serialize::impl_canonical_serialize;
 
 mod deserialize;
 use deserialize::impl_canonical_deserialize;
 
-#[proc_macro_derive(CanonicalSerialize)]
+#[proc_macro_derive(CanonicalSerialize, attributes(ark))]
 pub fn derive_canonical_serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(impl_canonical_serialize(&ast))
 }
 
-#[proc_macro_derive(CanonicalDeserialize)]
+#[proc_macro_derive(CanonicalDeserialize, attributes(ark))]
 pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(impl_canonical_deserialize(&ast))
@@ -1,3 +1,4 @@
+use crate::attrs::FieldAttrs;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{Data, Index, Type};
@@ -21,6 +22,7 @@ fn impl_serialize_field(
     serialized_size_body: &mut Vec<TokenStream>,
     idents: &mut Vec<IdentOrIndex>,
     ty: &Type,
+    compress: &TokenStream,
 ) {
     // Check if type is a tuple.
     match ty {
@@ -28,15 +30,21 @@ fn impl_serialize_field(
             for (i, elem_ty) in tuple.elems.iter().enumerate() {
                 let index = Index::from(i);
                 idents.push(IdentOrIndex::Index(index));
-                impl_serialize_field(serialize_body, serialized_size_body, idents, elem_ty);
+                impl_serialize_field(
+                    serialize_body,
+                    serialized_size_body,
+                    idents,
+                    elem_ty,
+                    compress,
+                );
                 idents.pop();
             }
         },
         _ => {
             serialize_body
-                .push(quote! { CanonicalSerialize::serialize_with_mode(&self.#(#idents).*, &mut writer, compress)?; });
+                .push(quote! { CanonicalSerialize::serialize_with_mode(&self.#(#idents).*, &mut writer, #compress)?; });
             serialized_size_body
-                .push(quote! { size += CanonicalSerialize::serialized_size(&self.#(#idents).*, compress); });
+                .push(quote! { size += CanonicalSerialize::serialized_size(&self.#(#idents).*, #compress); });
         },
     }
 }
@@ -63,6 +71,13 @@ pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
             let mut idents = Vec::<IdentOrIndex>::new();
 
             for (i, field) in data_struct.fields.iter().enumerate() {
+                let attrs = match FieldAttrs::parse(field) {
+                    Ok(attrs) => attrs,
+                    Err(err) => return err.to_compile_error(),
+                };
+                if attrs.skip {
+                    continue;
+                }
                 match field.ident {
                     None => {
                         let index = Index::from(i);
@@ -73,12 +88,23 @@ pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
                     },
                 }
 
-                impl_serialize_field(
-                    &mut serialize_body,
-                    &mut serialized_size_body,
-                    &mut idents,
-                    &field.ty,
-                );
+                let compress = attrs.compress();
+                if let Some(with) = &attrs.with {
+                    serialize_body.push(
+                        quote! { #with::serialize_with_mode(&self.#(#idents).*, &mut writer, #compress)?; },
+                    );
+                    serialized_size_body.push(
+                        quote! { size += #with::serialized_size(&self.#(#idents).*, #compress); },
+                    );
+                } else {
+                    impl_serialize_field(
+                        &mut serialize_body,
+                        &mut serialized_size_body,
+                        &mut idents,
+                        &field.ty,
+                        &compress,
+                    );
+                }
 
                 idents.clear();
             }
@@ -91,10 +117,12 @@ pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
 
     let gen = quote! {
         impl #impl_generics ark_serialize::CanonicalSerialize for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
             fn serialize_with_mode<W: ark_serialize::Write>(&self, mut writer: W, compress: ark_serialize::Compress) -> Result<(), ark_serialize::SerializationError> {
                 #(#serialize_body)*
                 Ok(())
             }
+            #[allow(unused_mut, unused_variables)]
             fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
                 let mut size = 0;
                 #(#serialized_size_body)*
@@ -0,0 +1,359 @@
+use crate::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate};
+use ark_std::{
+    cfg_chunks,
+    io::{Read, Write},
+    marker::PhantomData,
+    vec,
+    vec::*,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of bytes that are read or buffered at once by the bulk
+/// (de)serialization methods.
+const WINDOW_BYTES: usize = 1 << 22;
+
+/// The number of elements (de)serialized by each task within a window.
+const TASK_ELEMS: usize = 1 << 10;
+
+/// The first `u64` of a slice serialized as raw limbs. As a length, it would
+/// not fit in memory, so it cannot start a canonically serialized slice.
+const RAW_MAGIC: u64 = u64::from_le_bytes(*b"arkraw\x01\xff");
+
+/// The layout of the in-memory representation of a type as `u64` limbs, such
+/// as the Montgomery form of field elements, which
+/// [`CanonicalSerialize::serialize_slice_uncompressed`] copies as is.
+///
+/// Raw limbs are only meaningful for the representation they were copied
+/// from, so `id` identifies it, and they are only read back into a type with
+/// the same layout.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RawLayout {
+    /// The number of limbs of each value.
+    pub limbs: usize,
+    /// An identifier of the representation, typically computed with a
+    /// [`ParametersHasher`](crate::envelope::ParametersHasher).
+    pub id: u64,
+}
+
+/// Serializes `items` as a `[T]`, serializing windows of elements in parallel
+/// before writing them.
+pub(crate) fn serialize_slice<T: CanonicalSerialize + Sync, W: Write>(
+    items: &[T],
+    mut writer: W,
+    compress: Compress,
+) -> Result<(), SerializationError> {
+    (items.len() as u64).serialize_with_mode(&mut writer, compress)?;
+    let size = items
+        .first()
+        .map_or(1, |item| item.serialized_size(compress));
+    let window = (WINDOW_BYTES / size.max(1)).max(1);
+    for items in items.chunks(window) {
+        let buffers = cfg_chunks!(items, TASK_ELEMS)
+            .map(|items| {
+                let mut buffer = Vec::with_capacity(items.len() * size);
+                for item in items {
+                    item.serialize_with_mode(&mut buffer, compress)?;
+                }
+                Ok(buffer)
+            })
+            .collect::<Result<Vec<_>, SerializationError>>()?;
+        for buffer in buffers {
+            writer.write_all(&buffer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Serializes `items` as raw limbs if `T` has a [`RawLayout`], and as a
+/// `[T]` otherwise.
+///
+/// The raw format is [`RAW_MAGIC`], the number of limbs of each element and
+/// the identifier of their layout, the number of elements, and then the limbs
+/// of every element, all as little-endian `u64`s.
+pub(crate) fn serialize_slice_raw<T: CanonicalSerialize + Sync, W: Write>(
+    items: &[T],
+    mut writer: W,
+) -> Result<(), SerializationError> {
+    let layout = match T::raw_layout() {
+        Some(layout) if layout.limbs > 0 => layout,
+        _ => return serialize_slice(items, writer, Compress::No),
+    };
+    let header = [
+        RAW_MAGIC,
+        layout.limbs as u64,
+        layout.id,
+        items.len() as u64,
+    ];
+    for word in header {
+        writer.write_all(&word.to_le_bytes())?;
+    }
+    let size = layout.limbs * 8;
+    let window = (WINDOW_BYTES / size).max(1);
+    for items in items.chunks(window) {
+        let buffers = cfg_chunks!(items, TASK_ELEMS)
+            .map(|items| {
+                let mut buffer = Vec::with_capacity(items.len() * size);
+                let mut limbs = vec![0u64; layout.limbs];
+                for item in items {
+                    item.write_raw_limbs(&mut limbs);
+                    for limb in &limbs {
+                        buffer.extend_from_slice(&limb.to_le_bytes());
+                    }
+                }
+                buffer
+            })
+            .collect::<Vec<_>>();
+        for buffer in buffers {
+            writer.write_all(&buffer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Deserializes a `Vec<T>`. If `T` has a fixed serialized size, windows of
+/// elements are read at once, and decoded and validated in parallel.
+///
+/// Uncompressed slices may also have been serialized as raw limbs by
+/// [`serialize_slice_raw`], which the first `u64` tells apart.
+pub(crate) fn deserialize_vec<T: CanonicalDeserialize + Send, R: Read>(
+    mut reader: R,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<T>, SerializationError> {
+    let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+    if compress == Compress::No && len == RAW_MAGIC {
+        return deserialize_vec_raw(reader, validate);
+    }
+    let len = usize::try_from(len).map_err(|_| SerializationError::InvalidData)?;
+    let size = match T::fixed_serialized_size(compress) {
+        Some(size) if size > 0 => size,
+        _ => {
+            let items = (0..len)
+                .map(|_| T::deserialize_with_mode(&mut reader, compress, Validate::No))
+                .collect::<Result<Vec<_>, _>>()?;
+            if validate == Validate::Yes {
+                T::batch_check(items.iter())?;
+            }
+            return Ok(items);
+        },
+    };
+
+    // The length is not trusted: memory is allocated as windows are read.
+    let window = (WINDOW_BYTES / size).max(1);
+    let mut result = Vec::with_capacity(len.min(window));
+    let mut bytes = vec![0u8; len.min(window) * size];
+    let mut remaining = len;
+    while remaining > 0 {
+        let count = remaining.min(window);
+        let bytes = &mut bytes[..count * size];
+        reader.read_exact(bytes)?;
+        let items = decode_chunk::<T>(bytes, compress, validate)?;
+        result.extend(items);
+        remaining -= count;
+    }
+    Ok(result)
+}
+
+/// Deserializes the rest of a slice serialized by [`serialize_slice_raw`],
+/// after its magic number. The raw layout of the slice must be that of `T`.
+fn deserialize_vec_raw<T: CanonicalDeserialize + Send, R: Read>(
+    mut reader: R,
+    validate: Validate,
+) -> Result<Vec<T>, SerializationError> {
+    let mut header = [0u64; 3];
+    for word in &mut header {
+        *word = u64::deserialize_uncompressed_unchecked(&mut reader)?;
+    }
+    let [limbs, id, len] = header;
+    // `read_raw_limbs` relies on the layout being that of `T`.
+    let layout = T::expected_raw_layout()
+        .filter(|layout| layout.limbs > 0 && (layout.limbs as u64, layout.id) == (limbs, id))
+        .ok_or(SerializationError::InvalidData)?;
+    let len = usize::try_from(len).map_err(|_| SerializationError::InvalidData)?;
+
+    // The length is not trusted: memory is allocated as windows are read.
+    let size = layout.limbs * 8;
+    let window = (WINDOW_BYTES / size).max(1);
+    let mut result = Vec::with_capacity(len.min(window));
+    let mut bytes = vec![0u8; len.min(window) * size];
+    let mut remaining = len;
+    while remaining > 0 {
+        let count = remaining.min(window);
+        let bytes = &mut bytes[..count * size];
+        reader.read_exact(bytes)?;
+        let items = cfg_chunks!(bytes, TASK_ELEMS * size)
+            .map(|bytes| {
+                let mut limbs = vec![0u64; layout.limbs];
+                bytes
+                    .chunks_exact(size)
+                    .map(|bytes| {
+                        for (limb, bytes) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+                            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+                        }
+                        T::read_raw_limbs(&limbs).ok_or(SerializationError::InvalidData)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for items in items {
+            if validate == Validate::Yes {
+                T::batch_check(items.iter())?;
+            }
+            result.extend(items);
+        }
+        remaining -= count;
+    }
+    Ok(result)
+}
+
+/// Decodes the elements in `bytes` with
+/// [`CanonicalDeserialize::deserialize_chunk_unchecked`], and then validates
+/// them in a batch.
+fn decode_chunk<T: CanonicalDeserialize + Send>(
+    bytes: &[u8],
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<T>, SerializationError> {
+    let items = T::deserialize_chunk_unchecked(bytes, compress)?;
+    if validate == Validate::Yes {
+        T::batch_check(items.iter())?;
+    }
+    Ok(items)
+}
+
+/// Decodes the elements of fixed serialized size in `bytes` in parallel,
+/// independently of each other.
+pub(crate) fn decode_chunk_unchecked<T: CanonicalDeserialize + Send>(
+    bytes: &[u8],
+    compress: Compress,
+) -> Result<Vec<T>, SerializationError> {
+    let size = T::fixed_serialized_size(compress)
+        .filter(|size| *size > 0)
+        .ok_or(SerializationError::InvalidData)?;
+    if bytes.len() % size != 0 {
+        return Err(SerializationError::InvalidData);
+    }
+    cfg_chunks!(bytes, size)
+        .map(|bytes| T::deserialize_with_mode(bytes, compress, Validate::No))
+        .collect()
+}
+
+/// A lazily deserialized view of a serialized `[T]`, whose elements have a
+/// fixed serialized size.
+///
+/// The bytes are typically a memory-mapped file, such as a
+/// `memmap2::Mmap`, holding a large table of curve points: elements are only
+/// deserialized, and checked, when they are accessed.
+///
+/// # Example
+/// ```
+/// use ark_serialize::*;
+///
+/// let mut bytes = Vec::new();
+/// u32::serialize_slice_uncompressed(&[1, 2, 3], &mut bytes).unwrap();
+///
+/// let view = LazySlice::<u32, _>::new(bytes, Compress::No).unwrap();
+/// assert_eq!(view.len(), 3);
+/// assert_eq!(view.get(1).unwrap(), 2);
+/// assert_eq!(view.to_vec().unwrap(), vec![1, 2, 3]);
+/// ```
+pub struct LazySlice<T, B> {
+    bytes: B,
+    compress: Compress,
+    len: usize,
+    size: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: CanonicalDeserialize + Send, B: AsRef<[u8]>> LazySlice<T, B> {
+    /// Creates a view of the `[T]` serialized in `bytes` with the compression
+    /// mode `compress`.
+    ///
+    /// Returns an error if `T` does not have a fixed, non-zero serialized
+    /// size, or if the length of `bytes` does not match the serialized length
+    /// of the slice. The elements themselves are not checked.
+    pub fn new(bytes: B, compress: Compress) -> Result<Self, SerializationError> {
+        let size = T::fixed_serialized_size(compress)
+            .filter(|size| *size > 0)
+            .ok_or(SerializationError::InvalidData)?;
+        let data = bytes.as_ref();
+        let len = u64::deserialize_with_mode(data, compress, Validate::No)?;
+        let len = usize::try_from(len).map_err(|_| SerializationError::InvalidData)?;
+        let expected = len
+            .checked_mul(size)
+            .and_then(|l| l.checked_add(8))
+            .ok_or(SerializationError::InvalidData)?;
+        if data.len() != expected {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self {
+            bytes,
+            compress,
+            len,
+            size,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements in the slice.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slice has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the serialized bytes of the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn bytes_of(&self, index: usize) -> &[u8] {
+        assert!(index < self.len, "index {index} out of bounds");
+        let start = 8 + index * self.size;
+        &self.bytes.as_ref()[start..start + self.size]
+    }
+
+    /// Deserializes and checks the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Result<T, SerializationError> {
+        T::deserialize_with_mode(self.bytes_of(index), self.compress, Validate::Yes)
+    }
+
+    /// Deserializes the element at `index` without checking it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get_unchecked(&self, index: usize) -> Result<T, SerializationError> {
+        T::deserialize_with_mode(self.bytes_of(index), self.compress, Validate::No)
+    }
+
+    /// Returns an iterator deserializing and checking the elements in order.
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, SerializationError>> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    /// Deserializes all elements in parallel, checking them if `validate` is
+    /// `Validate::Yes`.
+    pub fn to_vec_with_mode(&self, validate: Validate) -> Result<Vec<T>, SerializationError> {
+        decode_chunk(&self.bytes.as_ref()[8..], self.compress, validate)
+    }
+
+    /// Deserializes and checks all elements in parallel.
+    pub fn to_vec(&self) -> Result<Vec<T>, SerializationError> {
+        self.to_vec_with_mode(Validate::Yes)
+    }
+
+    /// Returns the underlying bytes.
+    pub fn into_inner(self) -> B {
+        self.bytes
+    }
+}
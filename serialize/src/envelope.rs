@@ -0,0 +1,388 @@
+//! An opt-in, self-describing container format for serialized artifacts such
+//! as structured reference strings and proving keys.
+//!
+//! An envelope wraps the canonical serialization of a value with a header
+//! identifying what it contains, and a checksum:
+//!
+//! | Field           | Encoding                                              |
+//! |-----------------|-------------------------------------------------------|
+//! | magic           | the 4 bytes [`ENVELOPE_MAGIC`]                        |
+//! | version         | `u16`, currently [`ENVELOPE_VERSION`]                 |
+//! | mode            | `u8`: bit 0 is set if compressed, bit 1 if validated  |
+//! | parameters id   | `u64`, see [`ParametersId`]                           |
+//! | type name       | `String`                                              |
+//! | payload         | `Vec<u8>`, the canonical serialization of the value   |
+//! | checksum        | `u32`, the CRC-32 of all the preceding bytes          |
+//!
+//! All integers are little-endian, and `String`s and `Vec<u8>`s are prefixed
+//! by their length as a `u64`, as in the canonical serialization.
+//!
+//! # Example
+//! ```
+//! use ark_serialize::{envelope::Envelope, *};
+//!
+//! let envelope = Envelope::new::<()>("example::Counts");
+//! let mut bytes = Vec::new();
+//! envelope
+//!     .serialize(&vec![1u64, 2, 3], &mut bytes, Compress::Yes, Validate::Yes)
+//!     .unwrap();
+//!
+//! let counts: Vec<u64> = envelope.deserialize(&bytes[..], Validate::Yes).unwrap();
+//! assert_eq!(counts, vec![1, 2, 3]);
+//!
+//! // Envelopes of other types are rejected.
+//! let other = Envelope::new::<()>("example::Sizes");
+//! assert!(matches!(
+//!     other.deserialize::<Vec<u64>, _>(&bytes[..], Validate::Yes),
+//!     Err(SerializationError::EnvelopeMismatch { field: "type name", .. })
+//! ));
+//! ```
+use crate::{CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate};
+use ark_std::{
+    format,
+    io::{self, Read, Write},
+    string::*,
+    vec::*,
+};
+
+/// The magic number at the start of every envelope.
+pub const ENVELOPE_MAGIC: [u8; 4] = *b"ARKE";
+
+/// The version of the envelope format written by this crate.
+pub const ENVELOPE_VERSION: u16 = 1;
+
+const MODE_COMPRESSED: u8 = 1 << 0;
+const MODE_VALIDATED: u8 = 1 << 1;
+
+/// The number of bytes of the payload read at once, so that no memory is
+/// allocated for the advertised length of a payload before it is read.
+const READ_CHUNK: usize = 1 << 16;
+
+/// Identifies the algebraic parameters, such as a field modulus or curve
+/// coefficients, that the serialization of `Self` depends on.
+///
+/// Values serialized for different parameters are not interchangeable, even
+/// if they have the same size. Envelopes record the identifier of the
+/// parameters they were written for, and reject values read for others.
+pub trait ParametersId {
+    /// Returns an identifier of the parameters of `Self`, typically computed
+    /// with a [`ParametersHasher`].
+    fn parameters_id() -> u64;
+}
+
+/// Values that do not depend on any algebraic parameters.
+impl ParametersId for () {
+    fn parameters_id() -> u64 {
+        ParametersHasher::new(b"()").finish()
+    }
+}
+
+/// A hasher computing [`ParametersId`]s, by writing the canonical
+/// serialization of the parameters into it.
+///
+/// This is the 64-bit FNV-1a hash, which is stable across platforms and
+/// versions. It is not collision-resistant, and only aims at detecting
+/// accidental mismatches.
+#[derive(Clone, Debug)]
+pub struct ParametersHasher(u64);
+
+impl ParametersHasher {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    /// Returns a hasher for parameters of a kind described by `domain`, such
+    /// as `b"Fp"` for prime fields.
+    pub fn new(domain: &[u8]) -> Self {
+        let mut hasher = Self(Self::OFFSET_BASIS);
+        hasher.update(&(domain.len() as u64).to_le_bytes());
+        hasher.update(domain);
+        hasher
+    }
+
+    /// Hashes `bytes`.
+    pub fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    /// Hashes the uncompressed serialization of `value`.
+    pub fn update_serialized<T: CanonicalSerialize + ?Sized>(&mut self, value: &T) {
+        // Writing into a hasher cannot fail.
+        value.serialize_uncompressed(&mut *self).unwrap();
+    }
+
+    /// Returns the identifier.
+    pub const fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Write for ParametersHasher {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The CRC-32 (IEEE 802.3) lookup table.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// A reader or writer computing the CRC-32 of the bytes that go through it.
+struct Crc32<T> {
+    inner: T,
+    crc: u32,
+}
+
+impl<T> Crc32<T> {
+    const fn new(inner: T) -> Self {
+        Self {
+            inner,
+            crc: 0xffffffff,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.crc =
+                CRC32_TABLE[((self.crc ^ u32::from(*byte)) & 0xff) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    const fn checksum(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl<W: Write> Write for Crc32<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<R: Read> Read for Crc32<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// The header of an envelope, describing its payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvelopeHeader {
+    /// The version of the envelope format.
+    pub version: u16,
+    /// Whether the payload is compressed.
+    pub compressed: bool,
+    /// Whether the payload must be validated when it is deserialized.
+    pub validated: bool,
+    /// The [`ParametersId`] of the payload.
+    pub parameters_id: u64,
+    /// The name of the type of the payload.
+    pub type_name: String,
+}
+
+impl EnvelopeHeader {
+    fn write<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        writer.write_all(&ENVELOPE_MAGIC)?;
+        self.version.serialize_uncompressed(&mut writer)?;
+        let mut mode = 0u8;
+        if self.compressed {
+            mode |= MODE_COMPRESSED;
+        }
+        if self.validated {
+            mode |= MODE_VALIDATED;
+        }
+        mode.serialize_uncompressed(&mut writer)?;
+        self.parameters_id.serialize_uncompressed(&mut writer)?;
+        self.type_name.serialize_uncompressed(&mut writer)?;
+        Ok(())
+    }
+
+    /// Reads the header at the start of an envelope, leaving `reader` at the
+    /// start of the payload.
+    ///
+    /// Returns [`SerializationError::EnvelopeMismatch`] if the input is not
+    /// an envelope, and [`SerializationError::UnsupportedVersion`] if it was
+    /// written by a newer version of the format.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != ENVELOPE_MAGIC {
+            return Err(SerializationError::EnvelopeMismatch {
+                field: "magic",
+                expected: format!("{:02x?}", ENVELOPE_MAGIC),
+                found: format!("{:02x?}", magic),
+            });
+        }
+        let version = u16::deserialize_uncompressed(&mut reader)?;
+        if version == 0 || version > ENVELOPE_VERSION {
+            return Err(SerializationError::UnsupportedVersion(version));
+        }
+        let mode = u8::deserialize_uncompressed(&mut reader)?;
+        if mode & !(MODE_COMPRESSED | MODE_VALIDATED) != 0 {
+            return Err(SerializationError::UnexpectedFlags);
+        }
+        let parameters_id = u64::deserialize_uncompressed(&mut reader)?;
+        let len = u64::deserialize_uncompressed(&mut reader)?;
+        let type_name = read_bytes(&mut reader, len)?;
+        let type_name =
+            String::from_utf8(type_name).map_err(|_| SerializationError::InvalidData)?;
+        Ok(Self {
+            version,
+            compressed: mode & MODE_COMPRESSED != 0,
+            validated: mode & MODE_VALIDATED != 0,
+            parameters_id,
+            type_name,
+        })
+    }
+}
+
+/// Reads `len` bytes, allocating memory as they are read.
+fn read_bytes<R: Read>(mut reader: R, len: u64) -> Result<Vec<u8>, SerializationError> {
+    let len = usize::try_from(len).map_err(|_| SerializationError::InvalidData)?;
+    let mut bytes = Vec::with_capacity(len.min(READ_CHUNK));
+    while bytes.len() < len {
+        let start = bytes.len();
+        bytes.resize(start + (len - start).min(READ_CHUNK), 0);
+        reader.read_exact(&mut bytes[start..])?;
+    }
+    Ok(bytes)
+}
+
+/// Describes the values that an envelope contains: their type name, and the
+/// algebraic parameters they depend on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Envelope<'a> {
+    /// The name of the type of the values, such as `"groth16::ProvingKey"`.
+    ///
+    /// This should be stable across versions of the code, so it is chosen
+    /// by the caller rather than derived from the Rust type.
+    pub type_name: &'a str,
+    /// The [`ParametersId`] of the values.
+    pub parameters_id: u64,
+}
+
+impl<'a> Envelope<'a> {
+    /// Returns an envelope for values named `type_name`, which depend on the
+    /// parameters of `P`, such as a field or curve point type.
+    pub fn new<P: ParametersId>(type_name: &'a str) -> Self {
+        Self {
+            type_name,
+            parameters_id: P::parameters_id(),
+        }
+    }
+
+    /// Serializes `value` into `writer` in an envelope.
+    ///
+    /// If `validate` is `Validate::Yes`, the envelope requires readers to
+    /// validate the value.
+    pub fn serialize<T: CanonicalSerialize, W: Write>(
+        &self,
+        value: &T,
+        writer: W,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<(), SerializationError> {
+        let header = EnvelopeHeader {
+            version: ENVELOPE_VERSION,
+            compressed: compress == Compress::Yes,
+            validated: validate == Validate::Yes,
+            parameters_id: self.parameters_id,
+            type_name: self.type_name.into(),
+        };
+        let mut writer = Crc32::new(writer);
+        header.write(&mut writer)?;
+        (value.serialized_size(compress) as u64).serialize_uncompressed(&mut writer)?;
+        value.serialize_with_mode(&mut writer, compress)?;
+        let checksum = writer.checksum();
+        checksum.serialize_uncompressed(&mut writer.inner)?;
+        Ok(())
+    }
+
+    /// Deserializes a value from the envelope in `reader`.
+    ///
+    /// The value is validated if either `validate` or the envelope requires
+    /// it. Returns [`SerializationError::EnvelopeMismatch`] if the envelope
+    /// contains another type, or values for other parameters, and
+    /// [`SerializationError::ChecksumMismatch`] if it is corrupted.
+    pub fn deserialize<T: CanonicalDeserialize, R: Read>(
+        &self,
+        reader: R,
+        validate: Validate,
+    ) -> Result<T, SerializationError> {
+        let mut reader = Crc32::new(reader);
+        let header = EnvelopeHeader::read(&mut reader)?;
+        if header.type_name != self.type_name {
+            return Err(SerializationError::EnvelopeMismatch {
+                field: "type name",
+                expected: self.type_name.into(),
+                found: header.type_name,
+            });
+        }
+        if header.parameters_id != self.parameters_id {
+            return Err(SerializationError::EnvelopeMismatch {
+                field: "parameters id",
+                expected: format!("{:#018x}", self.parameters_id),
+                found: format!("{:#018x}", header.parameters_id),
+            });
+        }
+
+        let len = u64::deserialize_uncompressed(&mut reader)?;
+        let payload = read_bytes(&mut reader, len)?;
+        let expected = reader.checksum();
+        let checksum = u32::deserialize_uncompressed(&mut reader.inner)?;
+        if checksum != expected {
+            return Err(SerializationError::ChecksumMismatch);
+        }
+
+        let compress = if header.compressed {
+            Compress::Yes
+        } else {
+            Compress::No
+        };
+        let validate = if header.validated {
+            Validate::Yes
+        } else {
+            validate
+        };
+        let mut payload = &payload[..];
+        let value = T::deserialize_with_mode(&mut payload, compress, validate)?;
+        if !payload.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(value)
+    }
+}
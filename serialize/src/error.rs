@@ -1,7 +1,8 @@
-use ark_std::{fmt, io};
+use ark_std::{fmt, io, string::String};
 
 /// This is an error that could occur during serialization
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum SerializationError {
     /// During serialization, we didn't have enough space to write extra info.
     NotEnoughSpace,
@@ -12,6 +13,24 @@ pub enum SerializationError {
     UnexpectedFlags,
     /// During serialization, we countered an I/O error.
     IoError(io::Error),
+    /// During deserialization, the input exceeded the given `DeserializeLimits`.
+    LimitExceeded,
+    /// During deserialization, the header of an envelope did not match the
+    /// expected one.
+    EnvelopeMismatch {
+        /// The mismatching field of the header.
+        field: &'static str,
+        /// The expected value of the field.
+        expected: String,
+        /// The value of the field in the input.
+        found: String,
+    },
+    /// During deserialization, the envelope was written with an unsupported
+    /// version of the format.
+    UnsupportedVersion(u16),
+    /// During deserialization, the checksum of an envelope did not match its
+    /// contents.
+    ChecksumMismatch,
 }
 
 impl ark_std::error::Error for SerializationError {}
@@ -32,6 +51,22 @@ impl fmt::Display for SerializationError {
             Self::InvalidData => write!(f, "the input buffer contained invalid data"),
             Self::UnexpectedFlags => write!(f, "the call expects empty flags"),
             Self::IoError(err) => write!(f, "I/O error: {:?}", err),
+            Self::LimitExceeded => write!(f, "the input exceeds the deserialization limits"),
+            Self::EnvelopeMismatch {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "the envelope has {} `{}`, but `{}` was expected",
+                field, found, expected
+            ),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported envelope format version {}", version)
+            },
+            Self::ChecksumMismatch => {
+                write!(f, "the envelope checksum does not match its contents")
+            },
         }
     }
 }
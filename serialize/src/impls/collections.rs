@@ -1,5 +1,6 @@
 use crate::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    CanonicalDeserialize, CanonicalSerialize, Compress, DeserializeLimits, SerializationError,
+    Valid, Validate,
 };
 use ark_std::{
     borrow::*,
@@ -9,6 +10,22 @@ use ark_std::{
     vec::*,
 };
 
+/// Deserializes a collection as `len || entry 1 || ... || entry len` within
+/// `limits`, reading each entry with `entry`.
+fn deserialize_collection_with_limits<R: Read, T, C: FromIterator<T>>(
+    mut reader: R,
+    compress: Compress,
+    validate: Validate,
+    limits: &mut DeserializeLimits,
+    mut entry: impl FnMut(&mut R, &mut DeserializeLimits) -> Result<T, SerializationError>,
+) -> Result<C, SerializationError> {
+    limits.nested(|limits| {
+        let len = u64::deserialize_with_limits(&mut reader, compress, validate, limits)?;
+        let len = limits.take_elements(len)?;
+        (0..len).map(|_| entry(&mut reader, limits)).collect()
+    })
+}
+
 macro_rules! impl_valid_seq {
     ($type:ty  $( ; $($extra:tt)+ )?) => {
         impl<T: Valid $( , $($extra)+ )? > Valid for $type {
@@ -90,6 +107,18 @@ macro_rules! impl_canonical_deserialize_seq {
                 }
                 Ok(values)
             }
+
+            #[inline]
+            fn deserialize_with_limits<R: Read>(reader: R, compress: Compress, validate: Validate, limits: &mut DeserializeLimits) -> Result<Self, SerializationError> {
+                let values: Self = deserialize_collection_with_limits(reader, compress, validate, limits, |reader, limits| {
+                    T::deserialize_with_limits(reader, compress, Validate::No, limits)
+                })?;
+
+                if validate == Validate::Yes {
+                    T::batch_check(values.iter())?;
+                }
+                Ok(values)
+            }
         }
     };
 }
@@ -131,6 +160,32 @@ impl<T: CanonicalDeserialize, const N: usize> CanonicalDeserialize for [T; N] {
         }
         Ok(data.map(|x| unsafe { x.assume_init() }))
     }
+
+    #[inline]
+    #[allow(unsafe_code)]
+    fn deserialize_with_limits<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        use core::mem::MaybeUninit;
+        let mut data: [MaybeUninit<T>; N] = [const { MaybeUninit::uninit() }; N];
+        for elem in &mut data[..] {
+            elem.write(T::deserialize_with_limits(
+                &mut reader,
+                compress,
+                validate,
+                limits,
+            )?);
+        }
+        Ok(data.map(|x| unsafe { x.assume_init() }))
+    }
+
+    #[inline]
+    fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+        T::fixed_serialized_size(compress)?.checked_mul(N)
+    }
 }
 
 impl<T: CanonicalSerialize> CanonicalSerialize for Vec<T> {
@@ -212,6 +267,17 @@ impl CanonicalDeserialize for String {
         let bytes = <Vec<u8>>::deserialize_with_mode(reader, compress, validate)?;
         Self::from_utf8(bytes).map_err(|_| SerializationError::InvalidData)
     }
+
+    #[inline]
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        let bytes = <Vec<u8>>::deserialize_with_limits(reader, compress, validate, limits)?;
+        Self::from_utf8(bytes).map_err(|_| SerializationError::InvalidData)
+    }
 }
 
 impl<K, V> CanonicalSerialize for BTreeMap<K, V>
@@ -298,6 +364,20 @@ where
             })
             .collect()
     }
+
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        deserialize_collection_with_limits(reader, compress, validate, limits, |reader, limits| {
+            Ok((
+                K::deserialize_with_limits(&mut *reader, compress, validate, limits)?,
+                V::deserialize_with_limits(reader, compress, validate, limits)?,
+            ))
+        })
+    }
 }
 
 #[cfg(feature = "std")]
@@ -387,4 +467,18 @@ where
             })
             .collect()
     }
+
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        deserialize_collection_with_limits(reader, compress, validate, limits, |reader, limits| {
+            Ok((
+                K::deserialize_with_limits(&mut *reader, compress, validate, limits)?,
+                V::deserialize_with_limits(reader, compress, validate, limits)?,
+            ))
+        })
+    }
 }
@@ -44,6 +44,11 @@ impl CanonicalDeserialize for bool {
             _ => Err(SerializationError::InvalidData),
         }
     }
+
+    #[inline]
+    fn fixed_serialized_size(_compress: Compress) -> Option<usize> {
+        Some(1)
+    }
 }
 
 macro_rules! impl_uint {
@@ -94,6 +99,11 @@ macro_rules! impl_uint {
                 reader.read_exact(&mut bytes)?;
                 Ok(<$type>::from_le_bytes(bytes))
             }
+
+            #[inline]
+            fn fixed_serialized_size(_compress: Compress) -> Option<usize> {
+                Some(core::mem::size_of::<$type>())
+            }
         }
     };
 }
@@ -151,6 +161,11 @@ impl CanonicalDeserialize for usize {
         reader.read_exact(&mut bytes)?;
         Ok(<u64>::from_le_bytes(bytes) as Self)
     }
+
+    #[inline]
+    fn fixed_serialized_size(_compress: Compress) -> Option<usize> {
+        Some(core::mem::size_of::<u64>())
+    }
 }
 
 impl CanonicalSerialize for isize {
@@ -1,5 +1,6 @@
 use crate::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    CanonicalDeserialize, CanonicalSerialize, Compress, DeserializeLimits, SerializationError,
+    Valid, Validate,
 };
 use ark_std::{
     borrow::*,
@@ -72,6 +73,21 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
 
         Ok(data)
     }
+
+    #[inline]
+    fn deserialize_with_limits<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        let is_some = bool::deserialize_with_limits(&mut reader, compress, validate, limits)?;
+        let data = is_some
+            .then(|| T::deserialize_with_limits(&mut reader, compress, validate, limits))
+            .transpose()?;
+
+        Ok(data)
+    }
 }
 
 // No-op
@@ -215,6 +231,16 @@ impl<T: CanonicalDeserialize + ToOwned + Sync + Send> CanonicalDeserialize
     ) -> Result<Self, SerializationError> {
         T::deserialize_with_mode(reader, compress, validate).map(Self::new)
     }
+
+    #[inline]
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        T::deserialize_with_limits(reader, compress, validate, limits).map(Self::new)
+    }
 }
 
 impl<T: ?Sized + CanonicalSerialize + ToOwned> CanonicalSerialize for Cow<'_, T> {
@@ -279,4 +305,16 @@ where
             reader, compress, validate,
         )?))
     }
+
+    #[inline]
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        Ok(Cow::Owned(<T as ToOwned>::Owned::deserialize_with_limits(
+            reader, compress, validate, limits,
+        )?))
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+    CanonicalDeserialize, CanonicalSerialize, Compress, DeserializeLimits, SerializationError,
+    Valid, Validate,
 };
 use ark_std::io::{Read, Write};
 
@@ -51,6 +52,19 @@ macro_rules! impl_tuple {
                     $ty::deserialize_with_mode(&mut reader, compress, validate)?,
                 )*))
             }
+
+            #[inline]
+            #[allow(unused)]
+            fn deserialize_with_limits<R: Read>(mut reader: R, compress: Compress, validate: Validate, limits: &mut DeserializeLimits) -> Result<Self, SerializationError> {
+                Ok(($(
+                    $ty::deserialize_with_limits(&mut reader, compress, validate, limits)?,
+                )*))
+            }
+
+            #[inline]
+            fn fixed_serialized_size(compress: Compress) -> Option<usize> {
+                Some(0 $( + $ty::fixed_serialized_size(compress)? )*)
+            }
         }
     }
 }
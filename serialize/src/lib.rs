@@ -8,16 +8,21 @@
 )]
 #![deny(unsafe_code)]
 #![doc = include_str!("../README.md")]
+mod bulk;
 mod error;
 mod flags;
 mod impls;
+mod limits;
 
+pub mod envelope;
 pub mod serde;
 
 pub use ark_std::io::{Read, Write};
 
+pub use bulk::{LazySlice, RawLayout};
 pub use error::*;
 pub use flags::*;
+pub use limits::{BoundedReader, DeserializeLimits};
 pub use serde::{
     CompressedChecked, CompressedUnchecked, UncompressedChecked, UncompressedUnchecked,
 };
@@ -25,10 +30,15 @@ pub use serde::{
 #[cfg(test)]
 mod test;
 
+// Lets the derives used in tests refer to this crate as `ark_serialize`.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as ark_serialize;
+
 #[cfg(feature = "derive")]
 #[doc(hidden)]
 pub use ark_serialize_derive::*;
 
+use ark_std::vec::Vec;
 use digest::{generic_array::GenericArray, Digest, OutputSizeUser};
 
 /// Serializes the given `CanonicalSerialize` items in sequence. `serialize_to_vec![a, b, c, d, e]`
@@ -125,6 +135,22 @@ pub trait Valid: Sync {
 ///     b: (u64, (u64, u64)),
 /// }
 /// ```
+///
+/// The derivation of `CanonicalSerialize` and [`CanonicalDeserialize`] can be
+/// customized with the following field attributes:
+/// - `#[ark(skip)]` does not serialize the field, which is deserialized as
+///   `Default::default()`, or as `path()` with `#[ark(skip, default = "path")]`.
+/// - `#[ark(compress = "yes")]` and `#[ark(compress = "no")]` always
+///   (de)serialize the field in the given mode.
+/// - `#[ark(with = "module")]` (de)serializes the field with
+///   `module::serialize_with_mode`, `module::serialized_size` and
+///   `module::deserialize_with_mode`, which have the same signatures as the
+///   methods of these traits.
+/// - `#[ark(validate = "path")]` additionally checks the field with
+///   `path(&field) -> Result<(), SerializationError>`.
+///
+/// Skipped fields and fields with a custom deserializer are not checked by
+/// the derived [`Valid`] implementation, except by their `validate` function.
 pub trait CanonicalSerialize {
     /// The general serialize method that takes in customization flags.
     fn serialize_with_mode<W: Write>(
@@ -155,6 +181,72 @@ pub trait CanonicalSerialize {
     fn uncompressed_size(&self) -> usize {
         self.serialized_size(Compress::No)
     }
+
+    /// Serializes `items` into `writer` with the given compression mode.
+    ///
+    /// The output is identical to `items.serialize_with_mode(writer, compress)`,
+    /// but large slices are serialized in parallel and written in a few
+    /// large writes instead of one per element.
+    fn serialize_slice_with_mode<W: Write>(
+        items: &[Self],
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError>
+    where
+        Self: Sized + Sync,
+    {
+        bulk::serialize_slice(items, writer, compress)
+    }
+
+    /// Serializes `items` into `writer` using the compressed form if applicable.
+    fn serialize_slice_compressed<W: Write>(
+        items: &[Self],
+        writer: W,
+    ) -> Result<(), SerializationError>
+    where
+        Self: Sized + Sync,
+    {
+        Self::serialize_slice_with_mode(items, writer, Compress::Yes)
+    }
+
+    /// Serializes `items` into `writer` using the uncompressed form.
+    ///
+    /// If `Self` has a [`Self::raw_layout`], such as field elements and
+    /// curve points, the elements are copied as raw limbs, without
+    /// converting them out of their in-memory representation. This output
+    /// can only be read by [`CanonicalDeserialize::deserialize_vec_uncompressed`],
+    /// [`CanonicalDeserialize::deserialize_vec_uncompressed_unchecked`] or
+    /// [`CanonicalDeserialize::deserialize_vec_with_mode`] for the same type,
+    /// and not as a `Vec<Self>`. Otherwise, the output is that of
+    /// [`Self::serialize_slice_with_mode`].
+    fn serialize_slice_uncompressed<W: Write>(
+        items: &[Self],
+        writer: W,
+    ) -> Result<(), SerializationError>
+    where
+        Self: Sized + Sync,
+    {
+        bulk::serialize_slice_raw(items, writer)
+    }
+
+    /// Returns the layout of `Self` as raw `u64` limbs, if its in-memory
+    /// representation can be copied as is.
+    ///
+    /// This is `None` by default. Implementing it, together with
+    /// [`Self::write_raw_limbs`] and [`CanonicalDeserialize::read_raw_limbs`],
+    /// lets [`Self::serialize_slice_uncompressed`] and
+    /// [`CanonicalDeserialize::deserialize_vec_uncompressed_unchecked`] copy
+    /// large slices of `Self` without converting their elements.
+    fn raw_layout() -> Option<RawLayout>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Writes the raw limbs of `self` into `limbs`, whose length is that of
+    /// [`Self::raw_layout`]. This is only called if the layout is `Some`.
+    fn write_raw_limbs(&self, _limbs: &mut [u64]) {}
 }
 
 /// Deserializer in little endian format.
@@ -173,6 +265,72 @@ pub trait CanonicalSerialize {
 ///     b: (u64, (u64, u64)),
 /// }
 /// ```
+///
+/// Fields can be customized with the attributes described in
+/// [`CanonicalSerialize`]:
+/// ```
+/// use ark_serialize::*;
+///
+/// mod as_u8 {
+///     use ark_serialize::*;
+///
+///     pub fn serialize_with_mode<W: Write>(
+///         x: &u64,
+///         writer: W,
+///         compress: Compress,
+///     ) -> Result<(), SerializationError> {
+///         (*x as u8).serialize_with_mode(writer, compress)
+///     }
+///
+///     pub fn serialized_size(_: &u64, _: Compress) -> usize {
+///         1
+///     }
+///
+///     pub fn deserialize_with_mode<R: Read>(
+///         reader: R,
+///         compress: Compress,
+///         validate: Validate,
+///     ) -> Result<u64, SerializationError> {
+///         u8::deserialize_with_mode(reader, compress, validate).map(u64::from)
+///     }
+/// }
+///
+/// fn is_even(x: &u64) -> Result<(), SerializationError> {
+///     (x % 2 == 0).then_some(()).ok_or(SerializationError::InvalidData)
+/// }
+///
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use ark_test_curves::{bls12_381::G1Affine, AffineRepr};
+///
+/// #[derive(CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq)]
+/// struct TestStruct {
+///     #[ark(validate = "is_even")]
+///     a: u64,
+///     #[ark(with = "as_u8")]
+///     b: u64,
+///     #[ark(compress = "no")]
+///     point: G1Affine,
+///     #[ark(skip)]
+///     cache: Option<u64>,
+/// }
+///
+/// let point = G1Affine::generator();
+/// let value = TestStruct { a: 2, b: 3, point, cache: Some(5) };
+/// let mut bytes = Vec::new();
+/// value.serialize_compressed(&mut bytes).unwrap();
+/// assert_eq!(bytes.len(), 8 + 1 + point.uncompressed_size());
+/// assert_eq!(bytes.len(), value.compressed_size());
+/// let deserialized = TestStruct::deserialize_compressed(&bytes[..]).unwrap();
+/// assert_eq!(deserialized, TestStruct { cache: None, ..value });
+///
+/// let odd = TestStruct { a: 1, b: 3, point, cache: None };
+/// let mut bytes = Vec::new();
+/// odd.serialize_compressed(&mut bytes).unwrap();
+/// assert!(TestStruct::deserialize_compressed(&bytes[..]).is_err());
+/// assert!(TestStruct::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+/// # }
+/// ```
 pub trait CanonicalDeserialize: Valid + Sized {
     /// The general deserialize method that takes in customization flags.
     fn deserialize_with_mode<R: Read>(
@@ -219,6 +377,183 @@ pub trait CanonicalDeserialize: Valid + Sized {
     fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
         Self::deserialize_with_mode(reader, Compress::No, Validate::No)
     }
+
+    /// Returns the size in bytes of the serialized version of any `Self` with
+    /// the given compression mode, or `None` if it depends on the value.
+    ///
+    /// This is `None` by default. Implementing it allows the bulk methods,
+    /// such as [`Self::deserialize_vec_with_mode`] and [`LazySlice`], to
+    /// decode elements independently of each other.
+    fn fixed_serialized_size(_compress: Compress) -> Option<usize> {
+        None
+    }
+
+    /// Reads a `Vec<Self>` from `reader`, as serialized by
+    /// `Vec::<Self>::serialize_with_mode` or
+    /// [`CanonicalSerialize::serialize_slice_with_mode`], or, if `compress` is
+    /// `Compress::No`, by [`CanonicalSerialize::serialize_slice_uncompressed`].
+    ///
+    /// If [`Self::fixed_serialized_size`] is known, large chunks of elements
+    /// are read at once, then decoded (e.g. decompressed) and validated in
+    /// parallel. Otherwise, this is `Vec::<Self>::deserialize_with_mode`.
+    /// Raw limbs are copied back into the elements, and are rejected unless
+    /// they were written with the [`CanonicalSerialize::raw_layout`] of
+    /// `Self`.
+    fn deserialize_vec_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Vec<Self>, SerializationError>
+    where
+        Self: Send,
+    {
+        bulk::deserialize_vec(reader, compress, validate)
+    }
+
+    /// Deserializes, without validating them, the consecutive elements
+    /// serialized in `bytes` with the compression mode `compress`, each of
+    /// size [`Self::fixed_serialized_size`].
+    ///
+    /// This is used by the bulk methods, such as
+    /// [`Self::deserialize_vec_with_mode`] and [`LazySlice::to_vec`]. By
+    /// default, the elements are deserialized independently of each other, in
+    /// parallel. Types whose deserialization can share work between elements,
+    /// such as the decompression of curve points, override this method.
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<Self>, SerializationError>
+    where
+        Self: Send,
+    {
+        bulk::decode_chunk_unchecked(bytes, compress)
+    }
+
+    /// Reads a value from its raw limbs, as written by
+    /// [`CanonicalSerialize::write_raw_limbs`], without checking it beyond
+    /// what its representation requires (e.g. that field elements are
+    /// reduced).
+    ///
+    /// The caller must have checked that `limbs` were written with
+    /// [`Self::expected_raw_layout`], once for a whole slice. Returns `None`
+    /// if `limbs` do not represent a value. This is always `None` by default.
+    fn read_raw_limbs(_limbs: &[u64]) -> Option<Self> {
+        None
+    }
+
+    /// The layout of the raw limbs read by [`Self::read_raw_limbs`], which is
+    /// the [`CanonicalSerialize::raw_layout`] of `Self`. This is `None` by
+    /// default, and must be overridden together with `read_raw_limbs`.
+    fn expected_raw_layout() -> Option<RawLayout> {
+        None
+    }
+
+    /// Reads a `Vec<Self>` from `reader` using the compressed form if
+    /// applicable. Performs validation if applicable.
+    fn deserialize_vec_compressed<R: Read>(reader: R) -> Result<Vec<Self>, SerializationError>
+    where
+        Self: Send,
+    {
+        Self::deserialize_vec_with_mode(reader, Compress::Yes, Validate::Yes)
+    }
+
+    /// Reads a `Vec<Self>` from `reader` using the compressed form if
+    /// applicable, without validating the deserialized values.
+    fn deserialize_vec_compressed_unchecked<R: Read>(
+        reader: R,
+    ) -> Result<Vec<Self>, SerializationError>
+    where
+        Self: Send,
+    {
+        Self::deserialize_vec_with_mode(reader, Compress::Yes, Validate::No)
+    }
+
+    /// Reads a `Vec<Self>` from `reader` using the uncompressed form.
+    /// Performs validation if applicable.
+    fn deserialize_vec_uncompressed<R: Read>(reader: R) -> Result<Vec<Self>, SerializationError>
+    where
+        Self: Send,
+    {
+        Self::deserialize_vec_with_mode(reader, Compress::No, Validate::Yes)
+    }
+
+    /// Reads a `Vec<Self>` from `reader` using the uncompressed form, without
+    /// validating the deserialized values.
+    ///
+    /// This is the fastest way to load large trusted tables, such as the
+    /// powers of tau of a structured reference string.
+    fn deserialize_vec_uncompressed_unchecked<R: Read>(
+        reader: R,
+    ) -> Result<Vec<Self>, SerializationError>
+    where
+        Self: Send,
+    {
+        Self::deserialize_vec_with_mode(reader, Compress::No, Validate::No)
+    }
+
+    /// Deserializes `Self` like [`Self::deserialize_with_mode`], while
+    /// consuming `limits`. Returns [`SerializationError::LimitExceeded`] as
+    /// soon as one of the limits is exceeded.
+    ///
+    /// The default implementation only bounds the number of bytes read: a
+    /// manual implementation of [`Self::deserialize_with_mode`] that does
+    /// not override this method never consumes `elements` or `depth`, and
+    /// reads collections it contains without checking their length prefixes
+    /// against `limits`. This is enough for fixed-size types such as field
+    /// elements and curve points.
+    ///
+    /// Implementations for collections, tuples, `Option` and derived types
+    /// additionally bound the number of elements and the nesting depth, by
+    /// deserializing their contents with `deserialize_with_limits`. Manual
+    /// implementations for types containing collections should do the same,
+    /// and can implement [`Self::deserialize_with_mode`] by calling this
+    /// method with [`DeserializeLimits::unlimited`] to keep a single code path.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_serialize::*;
+    ///
+    /// #[derive(CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq)]
+    /// struct Proof {
+    ///     openings: Vec<u64>,
+    /// }
+    ///
+    /// let mut bytes = Vec::new();
+    /// u64::MAX.serialize_compressed(&mut bytes).unwrap();
+    /// let limits = DeserializeLimits::new(1 << 10, 1 << 16, 8);
+    /// assert!(matches!(
+    ///     Proof::deserialize_compressed_with_limits(&bytes[..], limits),
+    ///     Err(SerializationError::LimitExceeded)
+    /// ));
+    /// ```
+    fn deserialize_with_limits<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+        limits: &mut DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        limits.bounded(reader, |reader| {
+            Self::deserialize_with_mode(reader, compress, validate)
+        })
+    }
+
+    /// Reads `Self` from `reader` using the compressed form if applicable, within `limits`.
+    /// Performs validation if applicable.
+    fn deserialize_compressed_with_limits<R: Read>(
+        reader: R,
+        mut limits: DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_limits(reader, Compress::Yes, Validate::Yes, &mut limits)
+    }
+
+    /// Reads `Self` from `reader` using the uncompressed form, within `limits`. Performs
+    /// validation if applicable.
+    fn deserialize_uncompressed_with_limits<R: Read>(
+        reader: R,
+        mut limits: DeserializeLimits,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_limits(reader, Compress::No, Validate::Yes, &mut limits)
+    }
 }
 
 /// Serializer in little endian format allowing to encode flags.
@@ -0,0 +1,111 @@
+use crate::SerializationError;
+use ark_std::io::{self, Read};
+
+/// Caps on the resources that deserializing an untrusted input may consume.
+///
+/// The fields are budgets that are decreased as the input is read by
+/// [`CanonicalDeserialize::deserialize_with_limits`](crate::CanonicalDeserialize::deserialize_with_limits):
+/// once a budget is exhausted, deserialization fails with
+/// [`SerializationError::LimitExceeded`] instead of reading further or
+/// allocating memory for the advertised number of elements.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// The remaining number of elements that all variable-length collections
+    /// (sequences, maps and strings) may contain in total.
+    pub elements: usize,
+    /// The remaining number of bytes that may be read.
+    pub bytes: usize,
+    /// The remaining nesting depth of collections and derived types.
+    pub depth: usize,
+}
+
+impl DeserializeLimits {
+    /// Returns limits allowing at most `elements` collection elements,
+    /// `bytes` bytes and a nesting depth of `depth`.
+    pub const fn new(elements: usize, bytes: usize, depth: usize) -> Self {
+        Self {
+            elements,
+            bytes,
+            depth,
+        }
+    }
+
+    /// Returns limits that never get exceeded.
+    pub const fn unlimited() -> Self {
+        Self::new(usize::MAX, usize::MAX, usize::MAX)
+    }
+
+    /// Consumes the budget for a collection of `len` elements, and returns
+    /// `len` as a `usize`.
+    ///
+    /// This should be called with the length prefix of a collection before
+    /// deserializing its elements.
+    pub fn take_elements(&mut self, len: u64) -> Result<usize, SerializationError> {
+        match usize::try_from(len) {
+            Ok(len) if len <= self.elements => {
+                self.elements -= len;
+                Ok(len)
+            },
+            _ => Err(SerializationError::LimitExceeded),
+        }
+    }
+
+    /// Runs `f` one nesting level deeper.
+    pub fn nested<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, SerializationError>,
+    ) -> Result<T, SerializationError> {
+        if self.depth == 0 {
+            return Err(SerializationError::LimitExceeded);
+        }
+        self.depth -= 1;
+        let result = f(self);
+        self.depth += 1;
+        result
+    }
+
+    /// Runs `f` with a reader that fails once the byte budget is exhausted,
+    /// and consumes the bytes read by `f`.
+    ///
+    /// This is how types that do not override
+    /// [`deserialize_with_limits`](crate::CanonicalDeserialize::deserialize_with_limits)
+    /// are bounded.
+    pub fn bounded<R: Read, T>(
+        &mut self,
+        reader: R,
+        f: impl FnOnce(&mut BoundedReader<R>) -> Result<T, SerializationError>,
+    ) -> Result<T, SerializationError> {
+        let mut reader = BoundedReader {
+            inner: reader,
+            remaining: self.bytes,
+            exceeded: false,
+        };
+        let result = f(&mut reader);
+        self.bytes = reader.remaining;
+        if reader.exceeded {
+            return Err(SerializationError::LimitExceeded);
+        }
+        result
+    }
+}
+
+/// A reader that reads from an inner reader within the byte budget of some
+/// [`DeserializeLimits`], see [`DeserializeLimits::bounded`].
+pub struct BoundedReader<R> {
+    inner: R,
+    remaining: usize,
+    exceeded: bool,
+}
+
+impl<R: Read> Read for BoundedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining == 0 && !buf.is_empty() {
+            self.exceeded = true;
+            return Err(io::ErrorKind::Other.into());
+        }
+        let len = buf.len().min(self.remaining);
+        let read = self.inner.read(&mut buf[..len])?;
+        self.remaining -= read;
+        Ok(read)
+    }
+}
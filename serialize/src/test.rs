@@ -91,6 +91,11 @@ fn test_serialize<T: PartialEq + core::fmt::Debug + CanonicalSerialize + Canonic
                 .unwrap();
             let de = T::deserialize_with_mode(&serialized[..], compress, validate).unwrap();
             assert_eq!(data, de);
+            let mut limits = DeserializeLimits::unlimited();
+            let de = T::deserialize_with_limits(&serialized[..], compress, validate, &mut limits)
+                .unwrap();
+            assert_eq!(data, de);
+            assert_eq!(limits.bytes, usize::MAX - serialized.len());
             #[cfg(feature = "serde")]
             {
                 let serde_serialized = serde_ser(compress, validate).unwrap();
@@ -380,3 +385,430 @@ fn test_serialize_macro() {
 
     assert_eq!(tuple_bytes, macro_bytes);
 }
+
+#[test]
+fn test_deserialize_limits() {
+    let data = vec![vec![1u64, 2, 3], vec![4, 5]];
+    let mut bytes = Vec::new();
+    data.serialize_compressed(&mut bytes).unwrap();
+
+    let ok = DeserializeLimits::new(7, bytes.len(), 2);
+    assert_eq!(
+        <Vec<Vec<u64>>>::deserialize_compressed_with_limits(&bytes[..], ok).unwrap(),
+        data
+    );
+    for limits in [
+        DeserializeLimits { elements: 6, ..ok },
+        DeserializeLimits {
+            bytes: bytes.len() - 1,
+            ..ok
+        },
+        DeserializeLimits { depth: 1, ..ok },
+    ] {
+        assert!(matches!(
+            <Vec<Vec<u64>>>::deserialize_compressed_with_limits(&bytes[..], limits),
+            Err(SerializationError::LimitExceeded)
+        ));
+    }
+
+    // A huge length prefix is rejected before reading any element, even for
+    // elements that take no space.
+    let mut bytes = Vec::new();
+    u64::MAX.serialize_compressed(&mut bytes).unwrap();
+    let limits = DeserializeLimits::new(1 << 20, 1 << 20, 4);
+    assert!(matches!(
+        <Vec<()>>::deserialize_compressed_with_limits(&bytes[..], limits),
+        Err(SerializationError::LimitExceeded)
+    ));
+    assert!(matches!(
+        <BTreeMap<u64, u8>>::deserialize_compressed_with_limits(&bytes[..], limits),
+        Err(SerializationError::LimitExceeded)
+    ));
+    assert!(matches!(
+        String::deserialize_compressed_with_limits(&bytes[..], limits),
+        Err(SerializationError::LimitExceeded)
+    ));
+
+    // Types that do not override `deserialize_with_limits` are bounded in bytes.
+    let mut bytes = Vec::new();
+    BigUint::from(u128::MAX)
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    let mut limits = DeserializeLimits::new(0, bytes.len() - 1, 0);
+    assert!(matches!(
+        BigUint::deserialize_with_limits(&bytes[..], Compress::Yes, Validate::Yes, &mut limits),
+        Err(SerializationError::LimitExceeded)
+    ));
+
+    // ... and only in bytes: a manual implementation that does not override
+    // `deserialize_with_limits` neither consumes elements nor depth, even if
+    // it contains a collection.
+    #[derive(Debug, PartialEq)]
+    struct Manual(Vec<u64>);
+
+    impl Valid for Manual {
+        fn check(&self) -> Result<(), SerializationError> {
+            Ok(())
+        }
+    }
+
+    impl CanonicalDeserialize for Manual {
+        fn deserialize_with_mode<R: Read>(
+            reader: R,
+            compress: Compress,
+            validate: Validate,
+        ) -> Result<Self, SerializationError> {
+            Vec::deserialize_with_mode(reader, compress, validate).map(Self)
+        }
+    }
+
+    let mut bytes = Vec::new();
+    vec![1u64, 2, 3].serialize_compressed(&mut bytes).unwrap();
+    let mut limits = DeserializeLimits::new(0, bytes.len(), 0);
+    assert_eq!(
+        Manual::deserialize_with_limits(&bytes[..], Compress::Yes, Validate::Yes, &mut limits)
+            .unwrap(),
+        Manual(vec![1, 2, 3])
+    );
+    assert_eq!(limits, DeserializeLimits::new(0, 0, 0));
+    let mut limits = DeserializeLimits::new(0, bytes.len() - 1, 0);
+    assert!(matches!(
+        Manual::deserialize_with_limits(&bytes[..], Compress::Yes, Validate::Yes, &mut limits),
+        Err(SerializationError::LimitExceeded)
+    ));
+}
+
+#[test]
+fn test_bulk() {
+    let mut rng = ark_std::test_rng();
+    let items: Vec<(u64, [u8; 3])> = (0..5000)
+        .map(|_| (rng.next_u64(), [rng.next_u32() as u8; 3]))
+        .collect();
+    assert_eq!(
+        <(u64, [u8; 3])>::fixed_serialized_size(Compress::Yes),
+        Some(11)
+    );
+
+    let mut expected = Vec::new();
+    items.serialize_uncompressed(&mut expected).unwrap();
+    let mut bytes = Vec::new();
+    <(u64, [u8; 3])>::serialize_slice_uncompressed(&items, &mut bytes).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(
+        <(u64, [u8; 3])>::deserialize_vec_uncompressed_unchecked(&bytes[..]).unwrap(),
+        items
+    );
+    assert!(<(u64, [u8; 3])>::deserialize_vec_uncompressed(&bytes[..bytes.len() - 1]).is_err());
+
+    let view = LazySlice::<(u64, [u8; 3]), _>::new(&bytes[..], Compress::No).unwrap();
+    assert_eq!(view.len(), items.len());
+    assert_eq!(view.get(1234).unwrap(), items[1234]);
+    assert_eq!(view.to_vec().unwrap(), items);
+    assert!(LazySlice::<(u64, [u8; 3]), _>::new(&bytes[1..], Compress::No).is_err());
+
+    // Types without a fixed size are deserialized element by element.
+    let items = vec![vec![1u8], vec![2, 3]];
+    let mut bytes = Vec::new();
+    <Vec<u8>>::serialize_slice_compressed(&items, &mut bytes).unwrap();
+    assert_eq!(
+        <Vec<u8>>::deserialize_vec_compressed(&bytes[..]).unwrap(),
+        items
+    );
+    assert!(LazySlice::<Vec<u8>, _>::new(&bytes[..], Compress::Yes).is_err());
+
+    let items = vec![Dummy; 3];
+    let mut bytes = Vec::new();
+    Dummy::serialize_slice_uncompressed(&items, &mut bytes).unwrap();
+    assert_eq!(
+        Dummy::deserialize_vec_uncompressed(&bytes[..]).unwrap(),
+        items
+    );
+}
+
+#[test]
+fn test_envelope() {
+    use crate::envelope::*;
+
+    let envelope = Envelope::new::<()>("test::Counts");
+    let value = vec![1u8, 2];
+    let mut bytes = Vec::new();
+    envelope
+        .serialize(&value, &mut bytes, Compress::Yes, Validate::Yes)
+        .unwrap();
+    // The format is stable.
+    let expected = "41524b450100039cd90a28d350a59b0c00000000000000746573743a3a436f756e74730a0000000000000002000000000000000102fd035d1d";
+    assert_eq!(hex::encode(&bytes), expected);
+    assert_eq!(
+        envelope
+            .deserialize::<Vec<u8>, _>(&bytes[..], Validate::No)
+            .unwrap(),
+        value
+    );
+    assert_eq!(
+        EnvelopeHeader::read(&bytes[..]).unwrap(),
+        EnvelopeHeader {
+            version: ENVELOPE_VERSION,
+            compressed: true,
+            validated: true,
+            parameters_id: <()>::parameters_id(),
+            type_name: "test::Counts".into(),
+        }
+    );
+
+    let mut uncompressed = Vec::new();
+    envelope
+        .serialize(&value, &mut uncompressed, Compress::No, Validate::No)
+        .unwrap();
+    let header = EnvelopeHeader::read(&uncompressed[..]).unwrap();
+    assert!(!header.compressed && !header.validated);
+    assert_eq!(
+        envelope
+            .deserialize::<Vec<u8>, _>(&uncompressed[..], Validate::Yes)
+            .unwrap(),
+        value
+    );
+
+    let other = Envelope::new::<()>("test::Sizes");
+    assert!(matches!(
+        other.deserialize::<Vec<u8>, _>(&bytes[..], Validate::Yes),
+        Err(SerializationError::EnvelopeMismatch {
+            field: "type name",
+            ..
+        })
+    ));
+    let other = Envelope {
+        parameters_id: 1,
+        ..envelope
+    };
+    assert!(matches!(
+        other.deserialize::<Vec<u8>, _>(&bytes[..], Validate::Yes),
+        Err(SerializationError::EnvelopeMismatch {
+            field: "parameters id",
+            ..
+        })
+    ));
+
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert!(matches!(
+        envelope.deserialize::<Vec<u8>, _>(&corrupted[..], Validate::Yes),
+        Err(SerializationError::EnvelopeMismatch { field: "magic", .. })
+    ));
+    let mut corrupted = bytes.clone();
+    corrupted[4] = 2;
+    assert!(matches!(
+        envelope.deserialize::<Vec<u8>, _>(&corrupted[..], Validate::Yes),
+        Err(SerializationError::UnsupportedVersion(2))
+    ));
+    let mut corrupted = bytes.clone();
+    let last_payload_byte = bytes.len() - 5;
+    corrupted[last_payload_byte] ^= 1;
+    assert!(matches!(
+        envelope.deserialize::<Vec<u8>, _>(&corrupted[..], Validate::Yes),
+        Err(SerializationError::ChecksumMismatch)
+    ));
+    assert!(envelope
+        .deserialize::<Vec<u8>, _>(&bytes[..bytes.len() - 1], Validate::Yes)
+        .is_err());
+
+    // The payload must be consumed entirely.
+    assert!(matches!(
+        envelope.deserialize::<u8, _>(&bytes[..], Validate::Yes),
+        Err(SerializationError::InvalidData)
+    ));
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::*;
+
+    /// A `u64` that is invalid when zero.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct NonZero(u64);
+
+    impl Valid for NonZero {
+        fn check(&self) -> Result<(), SerializationError> {
+            (self.0 != 0)
+                .then_some(())
+                .ok_or(SerializationError::InvalidData)
+        }
+    }
+
+    impl CanonicalSerialize for NonZero {
+        fn serialize_with_mode<W: Write>(
+            &self,
+            writer: W,
+            compress: Compress,
+        ) -> Result<(), SerializationError> {
+            self.0.serialize_with_mode(writer, compress)
+        }
+
+        fn serialized_size(&self, compress: Compress) -> usize {
+            self.0.serialized_size(compress)
+        }
+    }
+
+    impl CanonicalDeserialize for NonZero {
+        fn deserialize_with_mode<R: Read>(
+            reader: R,
+            compress: Compress,
+            validate: Validate,
+        ) -> Result<Self, SerializationError> {
+            let value = Self(u64::deserialize_with_mode(reader, compress, Validate::No)?);
+            if validate == Validate::Yes {
+                value.check()?;
+            }
+            Ok(value)
+        }
+    }
+
+    fn is_even(x: &u64) -> Result<(), SerializationError> {
+        (x % 2 == 0)
+            .then_some(())
+            .ok_or(SerializationError::InvalidData)
+    }
+
+    fn seven() -> u64 {
+        7
+    }
+
+    #[derive(CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq)]
+    struct Tuple(
+        u64,
+        #[ark(skip)] Vec<u8>,
+        (NonZero, Option<u32>),
+        #[ark(validate = "is_even")] u64,
+    );
+
+    #[test]
+    fn test_tuple_struct() {
+        let value = Tuple(1, vec![2, 3], (NonZero(4), Some(5)), 6);
+        let mut bytes = Vec::new();
+        value.serialize_compressed(&mut bytes).unwrap();
+        let mut expected = Vec::new();
+        (1u64, (NonZero(4), Some(5u32)), 6u64)
+            .serialize_compressed(&mut expected)
+            .unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(bytes.len(), value.compressed_size());
+        assert_eq!(
+            Tuple::deserialize_compressed(&bytes[..]).unwrap(),
+            Tuple(1, vec![], (NonZero(4), Some(5)), 6)
+        );
+
+        // Both the fields' own checks and `validate` apply to tuple fields.
+        for invalid in [
+            Tuple(1, vec![], (NonZero(0), None), 6),
+            Tuple(1, vec![], (NonZero(4), None), 7),
+        ] {
+            let mut bytes = Vec::new();
+            invalid.serialize_compressed(&mut bytes).unwrap();
+            assert!(Tuple::deserialize_compressed(&bytes[..]).is_err());
+            assert_eq!(
+                Tuple::deserialize_compressed_unchecked(&bytes[..]).unwrap(),
+                invalid
+            );
+        }
+    }
+
+    #[derive(CanonicalSerialize, CanonicalDeserialize, Debug, PartialEq)]
+    struct WithSkipped {
+        a: u64,
+        #[ark(skip)]
+        cache: Vec<u64>,
+        #[ark(skip, default = "seven")]
+        b: u64,
+        #[ark(skip)]
+        c: NonZero,
+    }
+
+    #[test]
+    fn test_skipped_fields_with_defaults() {
+        let value = WithSkipped {
+            a: 1,
+            cache: vec![2, 3],
+            b: 4,
+            c: NonZero(5),
+        };
+        let mut bytes = Vec::new();
+        value.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes, 1u64.to_le_bytes());
+        assert_eq!(bytes.len(), value.compressed_size());
+
+        // Skipped fields are deserialized from their default, and are not
+        // checked even if their default is invalid.
+        let expected = WithSkipped {
+            a: 1,
+            cache: vec![],
+            b: 7,
+            c: NonZero(0),
+        };
+        assert_eq!(
+            WithSkipped::deserialize_compressed(&bytes[..]).unwrap(),
+            expected
+        );
+        assert!(expected.check().is_ok());
+        let mut limits = DeserializeLimits::unlimited();
+        assert_eq!(
+            WithSkipped::deserialize_with_limits(
+                &bytes[..],
+                Compress::Yes,
+                Validate::Yes,
+                &mut limits
+            )
+            .unwrap(),
+            expected
+        );
+    }
+
+    #[derive(CanonicalSerialize, CanonicalDeserialize, Clone, Debug, PartialEq)]
+    struct Validated {
+        x: NonZero,
+        #[ark(validate = "is_even")]
+        y: u64,
+    }
+
+    #[test]
+    fn test_batch_check_with_validate() {
+        let valid = Validated {
+            x: NonZero(1),
+            y: 2,
+        };
+        const _: () = assert!(!Validated::TRIVIAL_CHECK);
+        assert!(Validated::batch_check([valid.clone(), valid.clone()].iter()).is_ok());
+
+        // A `Vec` checks its elements with `batch_check`, which runs both the
+        // fields' own checks and their `validate` function.
+        for invalid in [
+            Validated {
+                x: NonZero(0),
+                y: 2,
+            },
+            Validated {
+                x: NonZero(1),
+                y: 3,
+            },
+        ] {
+            let values = vec![valid.clone(), invalid.clone(), valid.clone()];
+            assert!(Validated::batch_check(values.iter()).is_err());
+            let mut bytes = Vec::new();
+            values.serialize_compressed(&mut bytes).unwrap();
+            assert!(matches!(
+                <Vec<Validated>>::deserialize_compressed(&bytes[..]),
+                Err(SerializationError::InvalidData)
+            ));
+            assert_eq!(
+                <Vec<Validated>>::deserialize_compressed_unchecked(&bytes[..]).unwrap(),
+                values
+            );
+            let mut limits = DeserializeLimits::unlimited();
+            assert!(<Vec<Validated>>::deserialize_with_limits(
+                &bytes[..],
+                Compress::Yes,
+                Validate::Yes,
+                &mut limits
+            )
+            .is_err());
+        }
+    }
+}
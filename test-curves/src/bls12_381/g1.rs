@@ -1,11 +1,14 @@
 use crate::bls12_381::{g1_swu_iso, Fq, Fr};
 use ark_ec::{
+    bls12::Bls12Config,
     hashing::curve_maps::wb::{IsogenyMap, WBConfig},
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
     short_weierstrass::{self, Affine, Projective},
 };
 use ark_ff::{BigInt, MontFp, PrimeField, Zero};
+use ark_serialize::{Compress, SerializationError};
+use ark_std::vec::Vec;
 
 pub type G1Affine = Affine<Config>;
 pub type G1Projective = Projective<Config>;
@@ -48,6 +51,18 @@ impl short_weierstrass::SWCurveConfig for Config {
         GLVConfig::glv_mul_projective(*p, s)
     }
 
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        super::Config::is_in_g1_assuming_on_curve(p)
+    }
+
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<G1Affine>, SerializationError> {
+        short_weierstrass::batch_deserialize_chunk_unchecked(bytes, compress)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G1Affine) -> G1Affine {
         // Using the effective cofactor, as explained in
@@ -9,6 +9,8 @@ use ark_ec::{
     AffineRepr, CurveGroup, PrimeGroup,
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, Zero};
+use ark_serialize::{Compress, SerializationError};
+use ark_std::vec::Vec;
 
 pub type G2Affine = bls12::G2Affine<crate::bls12_381::Config>;
 pub type G2Projective = bls12::G2Projective<crate::bls12_381::Config>;
@@ -73,6 +75,13 @@ impl short_weierstrass::SWCurveConfig for Config {
         x_times_point.eq(&p_times_point)
     }
 
+    fn deserialize_chunk_unchecked(
+        bytes: &[u8],
+        compress: Compress,
+    ) -> Result<Vec<G2Affine>, SerializationError> {
+        short_weierstrass::batch_deserialize_chunk_unchecked(bytes, compress)
+    }
+
     #[inline]
     fn clear_cofactor(p: &G2Affine) -> G2Affine {
         // Based on Section 4.1 of https://eprint.iacr.org/2017/419.pdf
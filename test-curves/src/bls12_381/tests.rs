@@ -16,6 +16,10 @@ test_group!(g1; G1Projective; sw);
 #[cfg(feature = "bls12_381_curve")]
 test_group!(g2; G2Projective; sw);
 #[cfg(feature = "bls12_381_curve")]
+test_group!(g1_complete; G1Projective; complete);
+#[cfg(feature = "bls12_381_curve")]
+test_group!(g2_complete; G2Projective; complete);
+#[cfg(feature = "bls12_381_curve")]
 test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_381>; msm);
 #[cfg(feature = "bls12_381_curve")]
 test_group!(glv; G1Projective; glv);
@@ -25,3 +29,110 @@ test_pairing!(pairing; crate::bls12_381::Bls12_381);
 test_h2c!(g1_h2c; "./src/testdata"; "BLS12381G1"; crate::bls12_381::g1::Config; crate::bls12_381::Fq; crate::bls12_381::Fq; 1);
 #[cfg(feature = "bls12_381_curve")]
 test_h2c!(g2_hc2; "./src/testdata"; "BLS12381G2"; crate::bls12_381::g2::Config; crate::bls12_381::Fq2; crate::bls12_381::Fq; 2);
+#[cfg(feature = "bls12_381_curve")]
+test_h2c!(encode; sw; g1_encode; "../curves/bls12_381/src/curves/tests"; "BLS12381G1"; crate::bls12_381::g1::Config; crate::bls12_381::Fq; crate::bls12_381::Fq; 1; ark_ec::hashing::curve_maps::wb::WBMap<crate::bls12_381::g1::Config>; "SSWU"; ark_algebra_test_templates::Sha256, 128; "SHA-256");
+#[cfg(feature = "bls12_381_curve")]
+test_constant_time_map!(g1_ct_map; crate::bls12_381::G1Projective; ark_ec::hashing::curve_maps::wb::ConstantTimeWBMap<crate::bls12_381::g1::Config>; ark_ec::hashing::curve_maps::wb::WBMap<crate::bls12_381::g1::Config>);
+#[cfg(feature = "bls12_381_curve")]
+test_constant_time_map!(g2_ct_map; crate::bls12_381::G2Projective; ark_ec::hashing::curve_maps::wb::ConstantTimeWBMap<crate::bls12_381::g2::Config>; ark_ec::hashing::curve_maps::wb::WBMap<crate::bls12_381::g2::Config>);
+
+// The cofactors of G1 and G2 are divisible by 3 and 13, too small for random
+// linear combinations to check the subgroup, so that points are checked one
+// by one.
+#[cfg(feature = "bls12_381_curve")]
+#[test]
+fn batch_subgroup_check_rounds() {
+    use ark_ec::short_weierstrass::batch_subgroup_check_rounds;
+
+    assert_eq!(
+        batch_subgroup_check_rounds::<crate::bls12_381::g1::Config>(),
+        None
+    );
+    assert_eq!(
+        batch_subgroup_check_rounds::<crate::bls12_381::g2::Config>(),
+        None
+    );
+}
+
+// `hash_to_field` of RFC 9380 into `Fr`, with expand_message_xmd and SHA-256.
+#[cfg(feature = "bls12_381_curve")]
+#[test]
+fn hash_to_scalar() {
+    use ark_ec::hashing::hash_to_scalar;
+    use ark_ff::{field_hashers::DefaultFieldHasher, MontFp};
+
+    let dst = b"HashToScalar-QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256";
+    for (msg, want) in [
+        (
+            &b""[..],
+            MontFp!(
+                "26876541546781536831351311597921158350509646575907978824619105604659029203999"
+            ),
+        ),
+        (
+            &b"abc"[..],
+            MontFp!("6324784105709659144912497579233736341617950678729310094013101859633562877440"),
+        ),
+    ] {
+        let got: Fr = hash_to_scalar::<
+            G1Projective,
+            DefaultFieldHasher<ark_algebra_test_templates::Sha256, 128>,
+        >(dst, msg);
+        assert_eq!(got, want);
+    }
+}
+
+#[cfg(feature = "bls12_381_curve")]
+mod envelope {
+    use crate::bls12_381::*;
+    use ark_ec::AffineRepr;
+    use ark_serialize::{
+        envelope::{Envelope, ParametersId},
+        Compress, SerializationError, Validate,
+    };
+    use ark_std::{vec, vec::Vec};
+
+    #[test]
+    fn parameters_ids() {
+        let ids = [
+            Fq::parameters_id(),
+            Fr::parameters_id(),
+            Fq2::parameters_id(),
+            Fq6::parameters_id(),
+            Fq12::parameters_id(),
+            G1Affine::parameters_id(),
+            G2Affine::parameters_id(),
+        ];
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(G1Projective::parameters_id(), G1Affine::parameters_id());
+        assert_eq!(G2Projective::parameters_id(), G2Affine::parameters_id());
+    }
+
+    #[test]
+    fn envelope_roundtrip() {
+        let points = vec![G1Affine::generator(), G1Affine::zero()];
+        let envelope = Envelope::new::<G1Affine>("points");
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            envelope
+                .serialize(&points, &mut bytes, compress, Validate::Yes)
+                .unwrap();
+            let read: Vec<G1Affine> = envelope.deserialize(&bytes[..], Validate::Yes).unwrap();
+            assert_eq!(read, points);
+
+            // Points of another curve are rejected, even with the same type name.
+            let other = Envelope::new::<G2Affine>("points");
+            assert!(matches!(
+                other.deserialize::<Vec<G2Affine>, _>(&bytes[..], Validate::Yes),
+                Err(SerializationError::EnvelopeMismatch {
+                    field: "parameters id",
+                    ..
+                })
+            ));
+        }
+    }
+}
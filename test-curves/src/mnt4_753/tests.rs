@@ -4,3 +4,4 @@ use ark_algebra_test_templates::{test_field, test_group};
 test_field!(fq; Fq; mont_prime_field);
 test_field!(fr; Fr; mont_prime_field);
 test_group!(g1; G1Projective);
+test_group!(g1_complete; G1Projective; complete);
@@ -1,5 +1,6 @@
 use crate::secp256k1::{Fq, Fr};
 use ark_ec::{
+    encoding::Sec1Config,
     models::CurveConfig,
     short_weierstrass::{Affine, Projective, SWCurveConfig},
 };
@@ -41,6 +42,8 @@ impl SWCurveConfig for Config {
     type ZeroFlag = ();
 }
 
+impl Sec1Config for Config {}
+
 /// G_GENERATOR_X = 55066263022277343669578718895168534326250603453777594175500187360389116729240
 pub const G_GENERATOR_X: Fq =
     MontFp!("55066263022277343669578718895168534326250603453777594175500187360389116729240");
@@ -4,3 +4,84 @@ use ark_algebra_test_templates::{test_field, test_group};
 test_field!(fq; Fq; mont_prime_field);
 test_field!(fr; Fr; mont_prime_field);
 test_group!(g1; G1Projective);
+test_group!(g1_complete; G1Projective; complete);
+
+mod encoding {
+    use crate::secp256k1::{G1Affine, G1Projective};
+    use ark_ec::{
+        encoding::{Encoding, Sec1Compressed, Sec1Uncompressed},
+        AffineRepr, CurveGroup, PrimeGroup,
+    };
+    use ark_std::{vec, Zero};
+
+    // The affine coordinates of `G`, `2G` and `3G`.
+    const MULTIPLES: [(&str, &str); 3] = [
+        (
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        ),
+        (
+            "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+            "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A",
+        ),
+        (
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+        ),
+    ];
+
+    #[test]
+    fn sec1_test_vectors() {
+        let mut e = G1Projective::zero();
+        for (x, y) in MULTIPLES {
+            e += G1Projective::generator();
+            let point = e.into_affine();
+            let (x, y) = (hex::decode(x).unwrap(), hex::decode(y).unwrap());
+
+            let tag = if y[31] & 1 == 1 { 0x03 } else { 0x02 };
+            let compressed = [&[tag][..], &x].concat();
+            let mut encoded = vec![];
+            Encoding::<Sec1Compressed>::encode(&point, &mut encoded).unwrap();
+            assert_eq!(encoded, compressed);
+            assert_eq!(
+                <G1Affine as Encoding<Sec1Compressed>>::decode(&compressed[..]).unwrap(),
+                point
+            );
+
+            let uncompressed = [&[0x04][..], &x, &y].concat();
+            let mut encoded = vec![];
+            Encoding::<Sec1Uncompressed>::encode(&point, &mut encoded).unwrap();
+            assert_eq!(encoded, uncompressed);
+            assert_eq!(
+                <G1Affine as Encoding<Sec1Uncompressed>>::decode(&uncompressed[..]).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn sec1_infinity_and_invalid_encodings() {
+        let mut encoded = vec![];
+        Encoding::<Sec1Compressed>::encode(&G1Affine::zero(), &mut encoded).unwrap();
+        assert_eq!(encoded, [0x00]);
+        assert!(
+            <G1Affine as Encoding<Sec1Uncompressed>>::decode(&[0x00][..])
+                .unwrap()
+                .is_zero()
+        );
+
+        let mut encoded = vec![];
+        Encoding::<Sec1Uncompressed>::encode(&G1Affine::generator(), &mut encoded).unwrap();
+        // Hybrid and unknown tags are rejected.
+        encoded[0] = 0x06;
+        assert!(<G1Affine as Encoding<Sec1Uncompressed>>::decode(&encoded[..]).is_err());
+        // So are points off the curve.
+        encoded[0] = 0x04;
+        encoded[64] ^= 1;
+        assert!(<G1Affine as Encoding<Sec1Uncompressed>>::decode(&encoded[..]).is_err());
+        assert!(<G1Affine as Encoding<Sec1Uncompressed>>::decode_unchecked(&encoded[..]).is_ok());
+        // And `x`-coordinates that are larger than the modulus.
+        let invalid = [&[0x02][..], &[0xff; 32]].concat();
+        assert!(<G1Affine as Encoding<Sec1Compressed>>::decode(&invalid[..]).is_err());
+    }
+}
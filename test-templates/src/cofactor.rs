@@ -0,0 +1,33 @@
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_std::{rand::Rng, UniformRand};
+
+/// Samples a point of the curve, which need not be in the prime order
+/// subgroup.
+pub fn sample_unchecked<P: SWCurveConfig>() -> Affine<P> {
+    let mut rng = ark_std::test_rng();
+    loop {
+        let x = P::BaseField::rand(&mut rng);
+        let greatest = rng.gen();
+
+        if let Some(p) = Affine::get_point_from_x_unchecked(x, greatest) {
+            return p;
+        }
+    }
+}
+
+/// Checks that `P::clear_cofactor` is the multiplication by `h_eff`, given
+/// as little-endian limbs, and maps points of the curve to the prime order
+/// subgroup.
+pub fn cofactor_clearing<P: SWCurveConfig>(h_eff: &[u64]) {
+    const SAMPLES: usize = 10;
+    for _ in 0..SAMPLES {
+        let p: Affine<P> = sample_unchecked();
+        let optimised = p.clear_cofactor();
+        let naive = P::mul_affine(&p, h_eff);
+        assert_eq!(optimised.into_group(), naive);
+        assert!(optimised.is_in_correct_subgroup_assuming_on_curve());
+    }
+}
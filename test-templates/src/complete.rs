@@ -0,0 +1,117 @@
+use ark_ec::{
+    scalar_mul::variable_base::VariableBaseMSM,
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AdditiveGroup, AffineRepr, CurveConfig, CurveGroup, PrimeGroup,
+};
+use ark_ff::{PrimeField, UniformRand};
+use ark_std::{marker::PhantomData, test_rng, vec::*, Zero};
+
+/// The curve of `P`, for which `Projective` uses the complete formulae.
+pub struct CompleteConfig<P>(PhantomData<P>);
+
+impl<P: SWCurveConfig> CurveConfig for CompleteConfig<P> {
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+
+    const COFACTOR: &'static [u64] = P::COFACTOR;
+    const COFACTOR_INV: Self::ScalarField = P::COFACTOR_INV;
+}
+
+impl<P: SWCurveConfig> SWCurveConfig for CompleteConfig<P> {
+    const COEFF_A: Self::BaseField = P::COEFF_A;
+    const COEFF_B: Self::BaseField = P::COEFF_B;
+    const GENERATOR: Affine<Self> = Affine::new_unchecked(P::GENERATOR.x, P::GENERATOR.y);
+    const COMPLETE_FORMULAS: bool = true;
+
+    type ZeroFlag = bool;
+
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        P::mul_by_a(elem)
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(item: &Affine<Self>) -> bool {
+        to_jacobian(item).is_in_correct_subgroup_assuming_on_curve()
+    }
+}
+
+fn to_jacobian<P: SWCurveConfig>(p: &Affine<CompleteConfig<P>>) -> Affine<P> {
+    p.xy()
+        .map_or_else(Affine::identity, |(x, y)| Affine::new_unchecked(x, y))
+}
+
+fn to_complete<P: SWCurveConfig>(p: &Affine<P>) -> Affine<CompleteConfig<P>> {
+    p.xy()
+        .map_or_else(Affine::identity, |(x, y)| Affine::new_unchecked(x, y))
+}
+
+/// Checks the complete formulae of `Projective<CompleteConfig<P>>` against
+/// the Jacobian formulae of `Projective<P>`, including on the exceptional
+/// inputs of the latter: the point at infinity, and equal and opposite
+/// points.
+pub fn complete_formulas<P: SWCurveConfig>() {
+    let mut rng = test_rng();
+    let zero = Projective::<P>::zero();
+    let to_group = |p: Projective<P>| to_complete(&p.into_affine()).into_group();
+    let from_group = |p: Projective<CompleteConfig<P>>| to_jacobian(&p.into_affine());
+
+    assert!(Projective::<CompleteConfig<P>>::zero().is_zero());
+    assert_eq!(from_group(to_group(zero)), Affine::identity());
+    for _ in 0..100 {
+        let a = Projective::<P>::rand(&mut rng);
+        let b = Projective::<P>::rand(&mut rng);
+        assert_eq!(from_group(to_group(a)), a);
+        assert_eq!(
+            Projective::<CompleteConfig<P>>::normalize_batch(&[to_group(a)]),
+            [to_complete(&a.into_affine())]
+        );
+
+        // Rescaling the homogeneous coordinates does not change the point.
+        let lambda = P::BaseField::rand(&mut rng);
+        let c = to_group(a);
+        let scaled = Projective::<CompleteConfig<P>>::new_unchecked(
+            c.x * lambda,
+            c.y * lambda,
+            c.z * lambda,
+        );
+        assert_eq!(scaled, c);
+
+        for (p, q) in [(a, b), (a, a), (a, -a), (a, zero), (zero, a), (zero, zero)] {
+            let sum = p + q;
+            assert_eq!(from_group(to_group(p) + to_group(q)), sum);
+            assert_eq!(from_group(scaled + to_group(q)), a + q);
+            assert_eq!(from_group(to_group(p) + to_complete(&q.into_affine())), sum);
+            assert_eq!(from_group(to_group(p) - to_group(q)), p - q);
+            assert_eq!(from_group(to_group(p).double()), p.double());
+        }
+        assert_eq!(from_group(-to_group(a)), -a);
+        assert_eq!(from_group(scaled.double()), a.double());
+
+        let k = P::ScalarField::rand(&mut rng);
+        assert_eq!(from_group(to_group(a) * k), a * k);
+        assert_eq!(from_group(to_complete(&a.into_affine()) * k), a * k);
+        assert!(to_group(a).mul_bigint(P::ScalarField::MODULUS).is_zero());
+    }
+
+    let points = (0..100)
+        .map(|i| match i % 10 {
+            0 => zero,
+            _ => Projective::<P>::rand(&mut rng),
+        })
+        .collect::<Vec<_>>();
+    let complete_points = points.iter().copied().map(to_group).collect::<Vec<_>>();
+    let affine = Projective::normalize_batch(&points);
+    let complete_affine = Projective::normalize_batch(&complete_points);
+    assert_eq!(
+        complete_affine.iter().map(to_jacobian).collect::<Vec<_>>(),
+        affine
+    );
+
+    let scalars = (0..100)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        from_group(Projective::msm(&complete_affine, &scalars).unwrap()),
+        Projective::msm(&affine, &scalars).unwrap()
+    );
+}
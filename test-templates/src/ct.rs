@@ -0,0 +1,48 @@
+/// Tests that a straight-line map to curve, built on
+/// [`ark_ff::ConstantTimeField`], maps every element, including the
+/// exceptional cases of the maps, to the same point as its branching
+/// counterpart, and checks the operations of `ConstantTimeField` against
+/// those of `Field`.
+#[macro_export]
+macro_rules! test_constant_time_map {
+    ($mod_name: ident; $group: ty; $ct_map: ty; $vt_map: ty) => {
+        mod $mod_name {
+            use ark_ec::{hashing::map_to_curve_hasher::MapToCurve, CurveGroup};
+            use ark_ff::{ConstantTimeField, Field, One, UniformRand, Zero};
+
+            type BaseField = <$group as CurveGroup>::BaseField;
+
+            #[test]
+            fn agrees_with_variable_time_map() {
+                <$ct_map as MapToCurve<$group>>::check_parameters().unwrap();
+                let mut rng = ark_std::test_rng();
+                let elements = (0..100).map(|_| BaseField::rand(&mut rng)).chain([
+                    BaseField::zero(),
+                    BaseField::one(),
+                    -BaseField::one(),
+                ]);
+                for element in elements {
+                    assert_eq!(
+                        <$ct_map as MapToCurve<$group>>::map_to_curve(element).unwrap(),
+                        <$vt_map as MapToCurve<$group>>::map_to_curve(element).unwrap(),
+                    );
+                }
+            }
+
+            #[test]
+            fn ct_field_operations() {
+                let mut rng = ark_std::test_rng();
+                for _ in 0..20 {
+                    let (a, b) = (BaseField::rand(&mut rng), BaseField::rand(&mut rng));
+                    assert_eq!(a.ct_inverse(), a.inverse().unwrap());
+                    assert_eq!(BaseField::ct_select(&a, &b, true), a);
+                    assert_eq!(BaseField::ct_select(&a, &b, false), b);
+                    assert!(a.ct_eq(&a) && !a.ct_eq(&b));
+                    assert_eq!(a.ct_sgn0(), ark_ec::hashing::curve_maps::parity(&a));
+                }
+                assert!(BaseField::zero().ct_is_zero());
+                assert_eq!(BaseField::zero().ct_inverse(), BaseField::zero());
+            }
+        }
+    };
+}
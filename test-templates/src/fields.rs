@@ -93,9 +93,30 @@ macro_rules! __test_field {
                         buffer_bit_byte_size(<$field as Field>::BasePrimeField::MODULUS_BIT_SIZE as usize).1 *
                         (<$field>::extension_degree() as usize);
                     assert_eq!(buffer_size, buf_size);
+                    assert_eq!(<$field>::fixed_serialized_size(compress), Some(buf_size));
 
                     let mut rng = ark_std::test_rng();
 
+                    {
+                        let items = (0..ITERATIONS).map(|_| <$field>::rand(&mut rng)).collect::<Vec<_>>();
+                        let mut expected = vec![];
+                        items.serialize_with_mode(&mut expected, compress).unwrap();
+                        let mut serialized = vec![];
+                        <$field>::serialize_slice_with_mode(&items, &mut serialized, compress).unwrap();
+                        assert_eq!(serialized, expected);
+                        let b = <$field>::deserialize_vec_with_mode(&serialized[..], compress, validate).unwrap();
+                        assert_eq!(items, b);
+
+                        // Uncompressed slices may be copied as raw limbs.
+                        let mut raw = vec![];
+                        <$field>::serialize_slice_uncompressed(&items, &mut raw).unwrap();
+                        let layout = <$field>::raw_layout().unwrap();
+                        assert_eq!(raw.len(), 32 + items.len() * layout.limbs * 8);
+                        let b = <$field>::deserialize_vec_with_mode(&raw[..], Compress::No, validate).unwrap();
+                        assert_eq!(items, b);
+                        assert!(<$field>::deserialize_vec_with_mode(&raw[..], Compress::Yes, validate).is_err());
+                    }
+
                     for _ in 0..ITERATIONS {
                         let a = <$field>::rand(&mut rng);
                         {
@@ -354,6 +375,19 @@ macro_rules! __test_field {
                     let b = a.square();
                     assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
                 }
+
+                let elems: Vec<_> = (0..ITERATIONS)
+                    .map(|i| match i % 3 {
+                        0 => <$field>::rand(rng).square(),
+                        1 => <$field>::rand(rng),
+                        _ => <$field>::zero(),
+                    })
+                    .collect();
+                let sqrts = <$field>::batch_sqrt(&elems);
+                assert_eq!(sqrts.len(), elems.len());
+                for (elem, sqrt) in elems.iter().zip(sqrts) {
+                    assert_eq!(sqrt, elem.sqrt());
+                }
             }
         }
 
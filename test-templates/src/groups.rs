@@ -152,6 +152,21 @@ macro_rules! __test_group {
                         }
                     }
 
+                    // Test that the bulk methods agree with the serialization of
+                    // vectors.
+                    {
+                        assert_eq!(<$group>::fixed_serialized_size(compress), Some(buf_size));
+                        let mut items = (0..ITERATIONS).map(|_| <$group>::rand(&mut rng)).collect::<Vec<_>>();
+                        items.push(<$group>::zero());
+                        let mut expected = vec![];
+                        items.serialize_with_mode(&mut expected, compress).unwrap();
+                        let mut serialized = vec![];
+                        <$group>::serialize_slice_with_mode(&items, &mut serialized, compress).unwrap();
+                        assert_eq!(serialized, expected);
+                        let b = <$group>::deserialize_vec_with_mode(&serialized[..], compress, validate).unwrap();
+                        assert_eq!(items, b);
+                    }
+
                     // Test that serializing and deserializing the identity element
                     // works.
                     {
@@ -269,6 +284,33 @@ macro_rules! __test_group {
             }
         }
 
+        #[test]
+        fn test_raw_limbs() {
+            let rng = &mut ark_std::test_rng();
+            let mut points = (0..ITERATIONS).map(|_| Affine::rand(rng)).collect::<Vec<_>>();
+            points.push(Affine::zero());
+            let mut raw = vec![];
+            Affine::serialize_slice_uncompressed(&points, &mut raw).unwrap();
+            assert_eq!(Affine::deserialize_vec_uncompressed(&raw[..]).unwrap(), points);
+            assert_eq!(Affine::deserialize_vec_uncompressed_unchecked(&raw[..]).unwrap(), points);
+
+            if let Some(layout) = Affine::raw_layout() {
+                assert_eq!(raw.len(), 32 + points.len() * layout.limbs * 8);
+                // Raw limbs are only read back as points of the same curve.
+                assert!(Affine::deserialize_vec_compressed(&raw[..]).is_err());
+                assert!(BaseField::deserialize_vec_uncompressed(&raw[..]).is_err());
+                assert!(Affine::deserialize_vec_uncompressed(&raw[..raw.len() - 1]).is_err());
+
+                // Checked deserialization still validates the points.
+                let mut invalid = points[..2].to_vec();
+                invalid[1] = Affine::new_unchecked(invalid[1].x().unwrap(), invalid[1].x().unwrap());
+                let mut raw = vec![];
+                Affine::serialize_slice_uncompressed(&invalid, &mut raw).unwrap();
+                assert!(Affine::deserialize_vec_uncompressed(&raw[..]).is_err());
+                assert_eq!(Affine::deserialize_vec_uncompressed_unchecked(&raw[..]).unwrap(), invalid);
+            }
+        }
+
         #[test]
         fn test_mixed_addition() {
             let rng = &mut ark_std::test_rng();
@@ -358,6 +400,87 @@ macro_rules! __test_group {
                 }
             }
         }
+
+        #[test]
+        fn test_sw_subgroup_membership() {
+            let mut rng = &mut ark_std::test_rng();
+            let modulus = <ScalarField as PrimeField>::MODULUS;
+            let generator = <$group>::generator();
+
+            let mut points = vec![Affine::zero(), generator.into_affine()];
+            let mut outside = Vec::new();
+            for _ in 0..20 {
+                // A point of the curve, which is in the subgroup only if the
+                // cofactor is one, the point [r]p whose order divides the
+                // cofactor, and its sum with a point of the subgroup.
+                let p = loop {
+                    let x = BaseField::rand(rng);
+                    if let Some(p) = Affine::get_point_from_x_unchecked(x, bool::rand(rng)) {
+                        break p;
+                    }
+                };
+                let r_p = p.mul_bigint(modulus);
+                let shifted = r_p + generator * ScalarField::rand(rng);
+                if !r_p.is_zero() {
+                    outside.extend([r_p.into_affine(), shifted.into_affine()]);
+                }
+                points.extend([p, r_p.into_affine(), shifted.into_affine()]);
+                points.push(<$group>::rand(rng).into_affine());
+            }
+            assert_eq!(outside.is_empty(), <Config as CurveConfig>::cofactor_is_one());
+
+            for p in points {
+                assert_eq!(
+                    p.is_in_correct_subgroup_assuming_on_curve(),
+                    p.mul_bigint(modulus).is_zero(),
+                );
+            }
+            for p in outside {
+                assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+            }
+        }
+
+        #[test]
+        fn test_sw_batch_methods() {
+            let mut rng = &mut ark_std::test_rng();
+
+            let xs = (0..ITERATIONS).map(|_| BaseField::rand(rng)).collect::<Vec<_>>();
+            let ys = Affine::batch_get_ys_from_x_unchecked(&xs);
+            for (x, ys) in xs.iter().zip(ys) {
+                assert_eq!(ys, Affine::get_ys_from_x_unchecked(*x));
+            }
+            let xs = xs.iter().map(|x| (*x, bool::rand(rng))).collect::<Vec<_>>();
+            let points = Affine::batch_get_points_from_x_unchecked(&xs);
+            for ((x, greatest), point) in xs.iter().zip(points) {
+                assert_eq!(point, Affine::get_point_from_x_unchecked(*x, *greatest));
+            }
+
+            // Enough points for the subgroup check to take random linear
+            // combinations of them.
+            let mut points = (0..64).map(|_| Affine::rand(rng)).collect::<Vec<_>>();
+            points.push(Affine::zero());
+            assert!(Config::batch_is_in_correct_subgroup_assuming_on_curve(&points));
+            assert!(points.check().is_ok());
+
+            if !<Config as CurveConfig>::cofactor_is_one() {
+                let outside = (0u64..)
+                    .filter_map(|i| Affine::get_point_from_x_unchecked(BaseField::from(i), false))
+                    .find(|p| !p.is_in_correct_subgroup_assuming_on_curve())
+                    .unwrap();
+                for i in [0, 17, points.len() - 1] {
+                    let mut points = points.clone();
+                    points[i] = outside;
+                    assert!(!Config::batch_is_in_correct_subgroup_assuming_on_curve(&points));
+                    assert!(points.check().is_err());
+                }
+            }
+
+            let mut not_on_curve = points[3];
+            not_on_curve.y.double_in_place();
+            assert!(!not_on_curve.is_on_curve());
+            points[3] = not_on_curve;
+            assert!(points.check().is_err());
+        }
     };
     ($group:ty; te) => {
         $crate::__test_group!($group; curve);
@@ -410,6 +533,55 @@ macro_rules! __test_group {
             assert_eq!(a, <Config as MontCurveConfig>::COEFF_A);
             assert_eq!(b, <Config as MontCurveConfig>::COEFF_B);
         }
+
+        #[test]
+        fn test_montgomery_ladder() {
+            use ark_ec::montgomery::{MontgomeryAffine, XZProjective};
+            let mut rng = test_rng();
+
+            let zero = <$group>::zero().into_affine();
+            assert_eq!(MontgomeryAffine::<Config>::from_te(&zero), None);
+            assert!(XZProjective::<Config>::from(zero).is_zero());
+            for _ in 0..ITERATIONS {
+                let p = <$group>::rand(&mut rng).into_affine();
+                let k = <Config as CurveConfig>::ScalarField::rand(&mut rng).into_bigint();
+                let kp = (p * <Config as CurveConfig>::ScalarField::from_bigint(k).unwrap()).into_affine();
+
+                let mont = MontgomeryAffine::<Config>::from_te(&p).unwrap();
+                assert!(mont.is_on_curve());
+                assert_eq!(mont.to_te(), Some(p));
+
+                let xz = XZProjective::<Config>::from(p);
+                assert_eq!(xz, XZProjective::from(mont));
+                assert_eq!(xz.double(), XZProjective::from((p + p).into_affine()));
+                let q = <$group>::rand(&mut rng).into_affine();
+                assert_eq!(
+                    xz.differential_add(&XZProjective::from(q), &XZProjective::from((p - q).into_affine())),
+                    XZProjective::from((p + q).into_affine()),
+                );
+
+                let (r0, r1) = xz.ladder(k);
+                assert_eq!(r0, XZProjective::from(kp));
+                assert_eq!(r1, XZProjective::from((kp + p).into_affine()));
+                assert_eq!(mont.mul_bigint(k).and_then(|r| r.to_te()), Some(kp));
+            }
+            let p = <$group>::generator().into_affine();
+            let mont = MontgomeryAffine::<Config>::from_te(&p).unwrap();
+            let order = <<Config as CurveConfig>::ScalarField as PrimeField>::MODULUS;
+            assert!(XZProjective::from(mont).mul_bigint(order).is_zero());
+            assert_eq!(mont.mul_bigint(order), None);
+            let mut order_minus_one = order;
+            order_minus_one.sub_with_borrow(&1u64.into());
+            assert_eq!(mont.mul_bigint(order_minus_one).and_then(|r| r.to_te()), Some(-p));
+        }
+    };
+    ($group:ty; complete) => {
+        $crate::__test_group!(ark_ec::short_weierstrass::Projective<$crate::complete::CompleteConfig<<$group as CurveGroup>::Config>>; sw);
+
+        #[test]
+        fn test_complete_formulas() {
+            $crate::complete::complete_formulas::<<$group as CurveGroup>::Config>();
+        }
     };
     ($group:ty; glv) => {
         type Config = <$group as CurveGroup>::Config;
@@ -4,20 +4,41 @@ pub use hex::decode;
 use serde_json;
 pub use serde_json::from_reader;
 use sha2;
-pub use sha2::Sha256;
+pub use sha2::{Sha256, Sha384, Sha512};
 
+/// Tests a hash-to-curve suite against the test vectors in
+/// `{test_path}/{test_name}_XMD-{hash_name}_{map_name}_RO_.json`, in the
+/// format of the vectors of RFC 9380.
+///
+/// The points of the suite are given in the model of `$group`, `sw` or `te`,
+/// or, with `mont`, on the Montgomery curve of a twisted Edwards `$group`.
+/// The suite defaults to the `WBMap` and SHA-256 with a 128-bit security
+/// parameter.
+///
+/// Prefixed with `encode;`, it tests the nonuniform encoding against the
+/// `_NU_` vectors instead.
 #[macro_export]
 macro_rules! test_h2c {
     ($mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal) => {
+        $crate::test_h2c!($mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; ark_ec::hashing::curve_maps::wb::WBMap<$group>; "SSWU");
+    };
+    ($mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; $map: ty; $map_name: literal) => {
+        $crate::test_h2c!(sw; $mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; $map; $map_name; $crate::Sha256, 128; "SHA-256");
+    };
+    (encode; $model: ident; $mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; $map: ty; $map_name: literal; $hash: ty, $sec_param: literal; $hash_name: literal) => {
+        $crate::test_h2c!(@suite "NU", 1; $model; $mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; $map; $map_name; $hash, $sec_param; $hash_name);
+    };
+    ($model: ident; $mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; $map: ty; $map_name: literal; $hash: ty, $sec_param: literal; $hash_name: literal) => {
+        $crate::test_h2c!(@suite "RO", 2; $model; $mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; $map; $map_name; $hash, $sec_param; $hash_name);
+    };
+    (@suite $suffix: literal, $count: literal; $model: ident; $mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; $map: ty; $map_name: literal; $hash: ty, $sec_param: literal; $hash_name: literal) => {
         mod $mod_name {
             use ark_ff::PrimeField;
 
             extern crate std;
-            use ark_ec::{
-                hashing::{
-                    curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
-                },
-                short_weierstrass::{Affine, Projective},
+            use ark_ec::hashing::{
+                map_to_curve_hasher::{MapToCurve, MapToCurveBasedHasher},
+                HashToCurve,
             };
             use ark_ff::{
                 field_hashers::{DefaultFieldHasher, HashToField},
@@ -29,30 +50,39 @@ macro_rules! test_h2c {
                 fs::{read_dir, File},
                 io::BufReader,
             };
-            use $crate::{decode, Sha256};
+            use $crate::decode;
 
             use $crate::json::SuiteVector;
+
+            type Projective = $crate::__h2c_projective!($model; $group);
+
             #[test]
             fn test_h2c() {
-                let filename = format!("{}/{}_XMD-SHA-256_SSWU_RO_.json", $test_path, $test_name);
+                let filename = format!(
+                    "{}/{}_XMD-{}_{}_{}_.json",
+                    $test_path, $test_name, $hash_name, $map_name, $suffix
+                );
 
                 let file = File::open(filename).unwrap();
                 let data: SuiteVector = $crate::from_reader(BufReader::new(file)).unwrap();
 
-                assert_eq!(data.hash, "sha256");
+                assert_eq!(data.hash, $hash_name.replace("-", "").to_lowercase());
+                assert_eq!(data.map.name, $map_name);
+                assert_eq!(data.random_oracle, $suffix == "RO");
+                <$map as MapToCurve<Projective>>::check_parameters().unwrap();
                 let dst = data.dst.as_bytes();
                 let hasher;
                 let g1_mapper = MapToCurveBasedHasher::<
-                    Projective<$group>,
-                    DefaultFieldHasher<Sha256, 128>,
-                    WBMap<$group>,
+                    Projective,
+                    DefaultFieldHasher<$hash, $sec_param>,
+                    $map,
                 >::new(dst)
                 .unwrap();
-                hasher = <DefaultFieldHasher<Sha256, 128> as HashToField<$field>>::new(dst);
+                hasher = <DefaultFieldHasher<$hash, $sec_param> as HashToField<$field>>::new(dst);
 
                 for v in data.vectors.iter() {
                     // first, hash-to-field tests
-                    let got: [$base_prime_field; { 2 * $m }] =
+                    let got: [$base_prime_field; { $count * $m }] =
                         hasher.hash_to_field(&v.msg.as_bytes());
                     let want: Vec<$base_prime_field> =
                         v.u.iter().map(read_fq_vec).flatten().collect();
@@ -61,10 +91,17 @@ macro_rules! test_h2c {
                     // then, test curve points
                     let x = read_fq_vec(&v.p.x);
                     let y = read_fq_vec(&v.p.y);
-                    let got = g1_mapper.hash(&v.msg.as_bytes()).unwrap();
-                    let want = Affine::<$group>::new_unchecked(
+                    let got = if data.random_oracle {
+                        g1_mapper.hash(&v.msg.as_bytes()).unwrap()
+                    } else {
+                        g1_mapper.encode(&v.msg.as_bytes()).unwrap()
+                    };
+                    let want = $crate::__h2c_affine!(
+                        $model;
+                        $group;
+                        $field;
                         <$field>::from_base_prime_field_elems(x).unwrap(),
-                        <$field>::from_base_prime_field_elems(y).unwrap(),
+                        <$field>::from_base_prime_field_elems(y).unwrap()
                     );
                     assert!(got.is_on_curve());
                     assert!(want.is_on_curve());
@@ -84,3 +121,36 @@ macro_rules! test_h2c {
         }
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __h2c_projective {
+    (sw; $group: ty) => {
+        ark_ec::short_weierstrass::Projective<$group>
+    };
+    (te; $group: ty) => {
+        ark_ec::twisted_edwards::Projective<$group>
+    };
+    (mont; $group: ty) => {
+        ark_ec::twisted_edwards::Projective<$group>
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __h2c_affine {
+    (sw; $group: ty; $field: ty; $x: expr, $y: expr) => {
+        ark_ec::short_weierstrass::Affine::<$group>::new_unchecked($x, $y)
+    };
+    (te; $group: ty; $field: ty; $x: expr, $y: expr) => {
+        ark_ec::twisted_edwards::Affine::<$group>::new_unchecked($x, $y)
+    };
+    // The rational map from the Montgomery curve `B * t^2 = s^3 + A * s^2 + s`
+    // to the twisted Edwards curve with `a = (A + 2) / B` and
+    // `d = (A - 2) / B`, from RFC 9380, Appendix D.1.
+    (mont; $group: ty; $field: ty; $x: expr, $y: expr) => {{
+        let (s, t) = ($x, $y);
+        let one = <$field as ark_ff::One>::one();
+        ark_ec::twisted_edwards::Affine::<$group>::new_unchecked(s / t, (s - one) / (s + one))
+    }};
+}
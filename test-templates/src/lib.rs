@@ -2,6 +2,10 @@
 pub mod groups;
 #[macro_use]
 pub mod fields;
+#[macro_use]
+pub mod ct;
+pub mod cofactor;
+pub mod complete;
 pub mod glv;
 pub mod msm;
 #[macro_use]
@@ -62,6 +62,38 @@ macro_rules! test_pairing {
                     assert!(gt.cyclotomic_exp(r).is_one());
                 }
             }
+
+            #[test]
+            fn test_target_group_membership() {
+                use ark_serialize::Valid;
+
+                let rng = &mut test_rng();
+                let r = <$Pairing as Pairing>::ScalarField::MODULUS;
+                let k = <$Pairing as Pairing>::TargetField::extension_degree() as usize;
+                let is_valid = |f| PairingOutput::<$Pairing>(f).check().is_ok();
+
+                let zero = <$Pairing as Pairing>::TargetField::zero();
+                let one = <$Pairing as Pairing>::TargetField::one();
+                assert!(!is_valid(zero));
+                assert!(is_valid(one));
+                for _ in 0..10 {
+                    let f = <$Pairing as Pairing>::TargetField::rand(rng);
+                    // f^((p^(k/2) - 1)(p^(k/6) + 1)) is in the cyclotomic
+                    // subgroup, but not in the target group.
+                    let g = f.frobenius_map(k / 2) * f.inverse().unwrap();
+                    let g = g.frobenius_map(k / 6) * g;
+                    let gt = <$Pairing>::pairing(
+                        <$Pairing as Pairing>::G1::rand(rng),
+                        <$Pairing as Pairing>::G2::rand(rng),
+                    )
+                    .0;
+                    for (e, expected) in [(f, false), (g, false), (gt, true), (gt * g, false)] {
+                        assert_eq!(e.pow(r).is_one(), expected);
+                        assert_eq!(is_valid(e), expected);
+                        assert_eq!(<$Pairing as Pairing>::is_in_target_group(&e), expected);
+                    }
+                }
+            }
         }
     };
 }